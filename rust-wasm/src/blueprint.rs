@@ -0,0 +1,239 @@
+use wasm_bindgen::prelude::*;
+use crate::nodes::connector_manager::record::Record;
+use crate::nodes::connector_manager::value::ConnectorType;
+use crate::nodes::io::export::{ExportFormat, ExportOptions, export_output};
+use crate::nodes::io::json::parse_json;
+use crate::nodes::node_manager::Manager;
+use crate::nodes::registry::{create_graph_node, create_node};
+use crate::nodes::statistics::resampling::StatisticGraph;
+
+/// Options of a node as a record, an empty text is no options.
+fn get_options(options: &str) -> Option<Record> {
+    if options.trim().is_empty() {
+        return Some(Record::new());
+    }
+
+    match parse_json(options) {
+        Ok(ConnectorType::Record(record)) => Some(record),
+        _ => None,
+    }
+}
+
+/// A blueprint as seen from JS, nodes are created by kind from the node registry.\
+/// Values cross the boundary as JSON text, see `create_node` for the kinds and their options.
+///
+/// # Example
+/// ```js
+/// const blueprint = new Blueprint();
+/// const add = blueprint.add_node("Binary", '{"Operation": "Add"}');
+///
+/// blueprint.set_input(add, 0, "2");
+/// blueprint.evaluate(add);
+/// ```
+#[wasm_bindgen]
+pub struct Blueprint {
+    manager: Manager,
+}
+
+impl Default for Blueprint {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[wasm_bindgen]
+impl Blueprint {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Blueprint {
+        Blueprint {
+            manager: Manager::new(),
+        }
+    }
+
+    /// Index of the new node, `undefined` when the kind is unknown or the options are missing.
+    pub fn add_node(&mut self, kind: &str, options: &str) -> Option<usize> {
+        let node = create_node(kind, &get_options(options)?)?;
+
+        Some(self.manager.add_node(node))
+    }
+
+    /// Adds a node computed by the `graph` blueprint, which is moved into the node.\
+    /// `inputs` are the `(node, slot)` pairs of the graph inputs written one after the other.
+    pub fn add_graph_node(&mut self, kind: &str, options: &str, graph: Blueprint, inputs: Vec<usize>, output_node: usize, output_slot: usize) -> Option<usize> {
+        let inputs = inputs.chunks_exact(2).map(|pair| (pair[0], pair[1])).collect();
+        let graph = StatisticGraph::new(graph.manager, inputs, (output_node, output_slot));
+        let node = create_graph_node(kind, &get_options(options)?, graph)?;
+
+        Some(self.manager.add_node(node))
+    }
+
+    pub fn get_node_count(&self) -> usize {
+        self.manager.get_node_count()
+    }
+
+    pub fn connect(&mut self, output_node: usize, output_slot: usize, input_node: usize, input_slot: usize) {
+        self.manager.connect(output_node, output_slot, input_node, input_slot);
+    }
+
+    pub fn disconnect(&mut self, node: usize, input_slot: usize) {
+        self.manager.disconnect(node, input_slot);
+    }
+
+    pub fn get_seed(&self) -> u64 {
+        self.manager.get_seed()
+    }
+
+    pub fn set_seed(&mut self, seed: u64) {
+        self.manager.set_seed(seed);
+    }
+
+    /// Sets the value of an unconnected input from JSON text, returns false when the text isn't JSON.
+    pub fn set_input(&mut self, node: usize, slot: usize, value: &str) -> bool {
+        match parse_json(value) {
+            Ok(value) => {
+                self.manager.set_input_value(node, slot, value);
+                true
+            },
+            Err(_) => false,
+        }
+    }
+
+    /// Internal state of a node, import nodes keep the bytes of their file.
+    pub fn get_node_state(&self, node: usize) -> Vec<u8> {
+        self.manager.get_node_state(node)
+    }
+
+    /// Restores the state of a node, for import nodes this is the file read in JS, e.g. from a file input or a drop.
+    pub fn set_node_state(&mut self, node: usize, state: &[u8]) -> bool {
+        self.manager.set_node_state(node, state)
+    }
+
+    /// Merges the state of a node of the same kind from another blueprint, e.g. an accumulator
+    /// over another partition of the data computed in a worker.
+    pub fn merge_node_state(&mut self, node: usize, state: &[u8]) -> bool {
+        self.manager.merge_node_state(node, state)
+    }
+
+    /// Evaluates a node and everything it depends on.
+    pub fn evaluate(&mut self, node: usize) {
+        self.manager.evaluate(node);
+    }
+
+    /// File holding the last computed value of an output, to be downloaded from JS.\
+    /// `format` is `Csv`, `Json`, `Lines`, `Arrow` or `ArrowStream`, `undefined` is returned for other formats.
+    pub fn export_output(&self, node: usize, slot: usize, format: &str, delimiter: char, null_value: &str, decimals: Option<usize>) -> Option<Vec<u8>> {
+        let formats = [ExportFormat::Csv, ExportFormat::Json, ExportFormat::Lines, ExportFormat::Arrow, ExportFormat::ArrowStream];
+        let format = formats.into_iter().find(|candidate| format!("{:?}", candidate) == format)?;
+
+        Some(export_output(&self.manager, node, slot, format, &ExportOptions::new(delimiter, null_value, decimals)))
+    }
+}
+
+#[cfg(test)]
+mod blueprint_test {
+    use super::*;
+    use crate::helper::testing::as_table;
+
+    #[test]
+    fn test_blueprint() {
+        let mut blueprint = Blueprint::new();
+        let add = blueprint.add_node("Binary", r#"{"Operation": "Add"}"#).unwrap();
+        let multiply = blueprint.add_node("Binary", r#"{"Operation": "Multiply"}"#).unwrap();
+
+        assert!(blueprint.set_input(add, 0, "2"));
+        assert!(blueprint.set_input(add, 1, "[3, 4]"));
+        assert!(!blueprint.set_input(add, 1, "[3,"));
+        blueprint.connect(add, 0, multiply, 0);
+        assert!(blueprint.set_input(multiply, 1, "10"));
+        blueprint.evaluate(multiply);
+
+        assert_eq!(blueprint.manager.get_output(multiply, 0), ConnectorType::new(vec![50, 60]));
+        assert_eq!(blueprint.add_node("Binary", "{"), None);
+        assert_eq!(blueprint.add_node("Clamp", ""), Some(2));
+        assert_eq!(blueprint.get_node_count(), 3);
+    }
+
+    #[test]
+    fn test_import() {
+        let mut blueprint = Blueprint::new();
+        let import = blueprint.add_node("CsvImport", "").unwrap();
+        let file = b"Name,Count\na,1\nb,2\n";
+
+        assert!(blueprint.set_node_state(import, file));
+        assert_eq!(blueprint.get_node_state(import), file);
+        blueprint.evaluate(import);
+
+        let table = as_table(&blueprint.manager.get_output(import, 0));
+
+        assert_eq!(table.get_column_names(), vec!["Name", "Count"]);
+        assert_eq!(table.get_column("Count").unwrap().get_values(), [ConnectorType::new(1), ConnectorType::new(2)]);
+        assert!(!blueprint.set_node_state(5, file));
+    }
+
+    #[test]
+    fn test_merge_state() {
+        let mut worker = Blueprint::new();
+        let partition = worker.add_node("Accumulator", r#"{"Accumulator": "Extrema"}"#).unwrap();
+        let mut blueprint = Blueprint::new();
+        let extrema = blueprint.add_node("Accumulator", r#"{"Accumulator": "Extrema"}"#).unwrap();
+
+        worker.set_input(partition, 0, "[7, 9]");
+        worker.evaluate(partition);
+        blueprint.set_input(extrema, 0, "[3, 5]");
+        blueprint.evaluate(extrema);
+
+        assert!(blueprint.merge_node_state(extrema, &worker.get_node_state(partition)));
+        blueprint.evaluate(extrema);
+
+        assert_eq!(blueprint.export_output(extrema, 1, "Json", ',', "", None), Some(b"9".to_vec()));
+
+        let clamp = blueprint.add_node("Clamp", "").unwrap();
+
+        assert!(!blueprint.merge_node_state(clamp, &[]));
+    }
+
+    #[test]
+    fn test_export() {
+        let mut blueprint = Blueprint::new();
+        let add = blueprint.add_node("Binary", r#"{"Operation": "Add"}"#).unwrap();
+
+        blueprint.set_input(add, 0, "[1.5, null]");
+        blueprint.set_input(add, 1, "1");
+        blueprint.evaluate(add);
+
+        assert_eq!(blueprint.export_output(add, 0, "Csv", ';', "NA", Some(2)), Some(b"Value\n2.50\nNA\n".to_vec()));
+        assert_eq!(blueprint.export_output(add, 0, "Json", ',', "", None), Some(b"[2.5,null]".to_vec()));
+        assert_eq!(blueprint.export_output(add, 0, "Xml", ',', "", None), None);
+    }
+
+    #[test]
+    fn test_sqlite() {
+        let connection = rusqlite::Connection::open_in_memory().unwrap();
+
+        connection.execute_batch("CREATE TABLE scores (name TEXT, score INTEGER); INSERT INTO scores VALUES ('a', 3), ('b', 5);").unwrap();
+
+        let mut blueprint = Blueprint::new();
+        let import = blueprint.add_node("SqliteImport", "").unwrap();
+
+        assert!(blueprint.set_node_state(import, &connection.serialize("main").unwrap()));
+        assert!(blueprint.set_input(import, 0, r#""SELECT name, score * 2 AS double FROM scores""#));
+        blueprint.evaluate(import);
+
+        assert_eq!(blueprint.export_output(import, 0, "Csv", ',', "", None), Some(b"name,double\na,6\nb,10\n".to_vec()));
+        assert_eq!(blueprint.export_output(import, 1, "Json", ',', "", None), Some(br#"["scores"]"#.to_vec()));
+    }
+
+    #[test]
+    fn test_graph_node() {
+        let mut graph = Blueprint::new();
+        let median = graph.add_node("Statistic", r#"{"Statistic": "Median"}"#).unwrap();
+        let mut blueprint = Blueprint::new();
+        let bootstrap = blueprint.add_graph_node("Bootstrap", "", graph, vec![median, 0], median, 0).unwrap();
+
+        assert!(blueprint.set_input(bootstrap, 0, "[1, 2, 3, 4, 5]"));
+        blueprint.evaluate(bootstrap);
+
+        assert!(matches!(blueprint.manager.get_output(bootstrap, 0), ConnectorType::Float(_)));
+        assert_eq!(blueprint.add_graph_node("Binary", r#"{"Operation": "Add"}"#, Blueprint::new(), Vec::new(), 0, 0), None);
+    }
+}
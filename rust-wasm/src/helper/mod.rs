@@ -1 +1,5 @@
-pub mod types;
\ No newline at end of file
+pub mod bytes;
+pub mod random;
+#[cfg(test)]
+pub mod testing;
+pub mod types;
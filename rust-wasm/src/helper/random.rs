@@ -0,0 +1,111 @@
+/// Seeded pseudo random number generator (xoshiro256**).\
+/// Produces the same sequence on every platform, so seeded results can be reproduced.
+///
+/// # Example
+/// ```
+/// let mut random = Random::new(42);
+/// let value = random.next_f64();
+///
+/// assert!((0.0..1.0).contains(&value));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Random {
+    state: [u64; 4],
+}
+
+/// splitmix64 step, used to expand seeds into generator states.
+pub fn split_mix(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e3779b97f4a7c15);
+
+    let mut value = *state;
+
+    value = (value ^ (value >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94d049bb133111eb);
+    value ^ (value >> 31)
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Random {
+        let mut seed = seed;
+
+        Random {
+            state: [split_mix(&mut seed), split_mix(&mut seed), split_mix(&mut seed), split_mix(&mut seed)],
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let shifted = self.state[1] << 17;
+
+        self.state[2] ^= self.state[0];
+        self.state[3] ^= self.state[1];
+        self.state[1] ^= self.state[2];
+        self.state[0] ^= self.state[3];
+        self.state[2] ^= shifted;
+        self.state[3] = self.state[3].rotate_left(45);
+
+        result
+    }
+
+    /// Uniform in `[0, 1)`, with 53 bits of precision.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Uniform in `(0, 1)`, safe to take the logarithm of.
+    pub fn next_open_f64(&mut self) -> f64 {
+        ((self.next_u64() >> 12) as f64 + 0.5) / (1u64 << 52) as f64
+    }
+
+    /// Uniform integer in `[0, bound)`, without modulo bias. Zero when `bound` is zero.
+    pub fn next_below(&mut self, bound: u64) -> u64 {
+        if bound == 0 {
+            return 0;
+        }
+
+        // Lemire's multiply and reject
+        loop {
+            let product = self.next_u64() as u128 * bound as u128;
+
+            if (product as u64) >= bound.wrapping_neg() % bound {
+                return (product >> 64) as u64;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod random_test {
+    use super::*;
+
+    #[test]
+    fn test_reproducible() {
+        let mut first = Random::new(7);
+        let mut second = Random::new(7);
+        let mut other = Random::new(8);
+
+        let values: Vec<u64> = (0..4).map(|_| first.next_u64()).collect();
+
+        assert_eq!(values, (0..4).map(|_| second.next_u64()).collect::<Vec<u64>>());
+        assert_ne!(values, (0..4).map(|_| other.next_u64()).collect::<Vec<u64>>());
+    }
+
+    #[test]
+    fn test_ranges() {
+        let mut random = Random::new(0);
+        let mut counts = [0; 4];
+
+        for _ in 0..4000 {
+            let value = random.next_f64();
+            let open = random.next_open_f64();
+
+            assert!((0.0..1.0).contains(&value));
+            assert!(open > 0.0 && open < 1.0);
+
+            counts[random.next_below(4) as usize] += 1;
+        }
+
+        assert!(counts.iter().all(|count| (900..1100).contains(count)), "{:?} should be close to uniform", counts);
+        assert_eq!(random.next_below(0), 0);
+    }
+}
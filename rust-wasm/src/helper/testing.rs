@@ -0,0 +1,22 @@
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::table::Table;
+use crate::nodes::connector_manager::value::ConnectorType;
+use crate::nodes::node_manager::Manager;
+
+/// `Null` value, shorter to write in expected values.
+pub fn null() -> ConnectorType {
+    ConnectorType::Null(Null::new())
+}
+
+/// Table of a value, panics on other values.
+pub fn as_table(value: &ConnectorType) -> Table {
+    match value {
+        ConnectorType::Table(table) => table.clone(),
+        value => panic!("{:?} should be a table", value),
+    }
+}
+
+/// Evaluates a node and reads its first output as a table.
+pub fn evaluate_table(manager: &mut Manager, node: usize) -> Table {
+    as_table(&manager.evaluate(node)[0])
+}
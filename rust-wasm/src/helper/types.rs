@@ -19,6 +19,12 @@ macro_rules! null_eq {
 #[derive(Debug, Clone)]
 pub struct Null {}
 
+impl Default for Null {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Null {
     pub fn new() -> Null {
         Null {}
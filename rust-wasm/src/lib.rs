@@ -1,4 +1,5 @@
 use wasm_bindgen::prelude::*;
+mod blueprint;
 mod helper;
 mod nodes;
 
@@ -1,6 +1,10 @@
+#[cfg(test)]
 mod test;
 pub mod edge;
 pub mod value;
+pub mod table;
+pub mod record;
+pub mod datetime;
 pub mod connector;
 use crate::helper::types::Null;
 use self::connector::Connector;
@@ -37,6 +41,12 @@ pub struct Manager {
     connectors: Vec<Connector>,
 }
 
+impl Default for Manager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Manager {
     pub fn new() -> Manager {
         Manager {
@@ -67,7 +77,7 @@ impl Manager {
                 match connector {
                     Connector::Input(_, connected, connector_type) => {
                         match connected {
-                            Some(connected) => self.get_connector(*connected).unwrap().get_value().get_variant(connector_type.clone()),
+                            Some(connected) => self.get_connector(*connected).unwrap().get_value().get_variant(*connector_type),
                             None => ConnectorType::new(0),
                         }
                     },
@@ -82,10 +92,6 @@ impl Manager {
         self.connectors.get(index)
     }
 
-    pub fn get_connector_mut(&mut self, index: usize) -> Option<&mut Connector> {
-        self.connectors.get_mut(index)
-    }
-
     pub fn get_two_connectors(&self, index1: usize, index2: usize) -> (&Connector, &Connector) {
         let (index1, index2) = if index1 < index2 {
             (index1, index2)
@@ -127,18 +133,6 @@ impl Manager {
         index
     }
 
-    pub fn delete_connector(&mut self, index: usize) {
-        // delete edge
-        self.disconnect(index);
-        self.connectors.remove(index);
-
-        for connector in &mut self.connectors {
-            if connector.get_index() > index {
-                connector.set_index(index - 1);
-            }
-        }
-    }
-
     pub fn connect(&mut self, input_index: usize, output_index: usize) {
         let (input, output) = self.get_two_connectors(input_index, output_index);
         let (mut input_index, mut output_index) = (input.get_index(), output.get_index());
@@ -195,16 +189,12 @@ impl Manager {
         if connector.has_edge() {
             match connector.is_output() {
                 true => {
-                    for input in self.connectors.iter_mut().filter(|connector| (connector.is_input() || connector.get_index() == index)){
-                        if input.get_index() != index {
-                            input.remove_edge();
-                        } else {
-                            input.remove_edge();
-                        }
+                    for input in self.connectors.iter_mut().filter(|connector| connector.get_index() == index || (connector.is_input() && connector.get_connected().contains(&index))) {
+                        input.remove_edge();
                     }
                 },
                 false => {
-                    let edge = connector.get_connected().first().unwrap().clone();
+                    let edge = *connector.get_connected().first().unwrap();
                     let edge = self.get_connector(edge).unwrap().get_edge().unwrap();
     
                     // If we are input, so check if only 1 connection, if so disconnect edge on both ends
@@ -15,10 +15,6 @@ impl ConnectorInfo {
         }
     }
 
-    pub fn set_index(&mut self, index: usize) {
-        self.index = index;
-    }
-
     pub fn get_index(&self) -> usize {
         self.index
     }
@@ -61,24 +57,17 @@ impl Connector {
         }
     }
 
-    pub fn set_index(&mut self, index: usize) {
-        match self {
-            Connector::Input(info, _, _) => info.index = index,
-            Connector::Output(info, _, _, _) => info.index = index,
-        }
-    }
-
     pub fn get_index(&self) -> usize {
         match self {
-            Connector::Input(info, _, _) => info.index,
-            Connector::Output(info, _, _, _) => info.index,
+            Connector::Input(info, _, _) => info.get_index(),
+            Connector::Output(info, _, _, _) => info.get_index(),
         }
     }
 
     pub fn get_type(&self) -> i32 {
         match self {
-            Connector::Input(_, _, connector_type) => connector_type.clone(),
-            Connector::Output(_, _, _, connector_type) => connector_type.clone(),
+            Connector::Input(_, _, connector_type) => *connector_type,
+            Connector::Output(_, _, _, connector_type) => *connector_type,
         }
     }
 
@@ -87,9 +76,9 @@ impl Connector {
         T: Into<ConnectorType>
     {
         match self {
-            Connector::Output(info, _, value, connector_type) => {
+            Connector::Output(_, _, value, connector_type) => {
                 let new_val = new_val.into();
-                *value = new_val.get_variant(connector_type.clone());
+                *value = new_val.get_variant(*connector_type);
             },
             _ => panic!("Cannot set value for input connector"),
         }
@@ -104,8 +93,8 @@ impl Connector {
 
     pub fn get_name(&self) -> String {
         match self {
-            Connector::Input(info, _, _) => info.name.clone(),
-            Connector::Output(info, _, _, _) => info.name.clone(),
+            Connector::Input(info, _, _) => info.get_name(),
+            Connector::Output(info, _, _, _) => info.get_name(),
         }
     }
 
@@ -155,22 +144,9 @@ impl Connector {
         }
     }
 
-    pub fn disconnect_from_edge(&mut self, input: usize) {
-        match self {
-            Connector::Output(info, edge, _, _) => {
-                if let Some(edge) = edge {
-                    if edge.has_connection(input) {
-                        edge.remove_input(input);
-                    }
-                }
-            },
-            _ => panic!("Cannot disconnect from edge for input connector as outputs own the edges"),
-        }
-    }
-
     pub fn get_connected(&self) -> Vec<usize> {
         match self {
-            Connector::Input(_, connected, _) => connected.into_iter().map(|x| *x).collect(),
+            Connector::Input(_, connected, _) => connected.iter().copied().collect(),
             Connector::Output(_, edge, _, _) => {
                 match edge {
                     Some(edge) => edge.get_input(),
@@ -179,35 +155,6 @@ impl Connector {
             },
         }
     }
-
-    pub fn get_connected_to(&self, connection: usize) -> Option<usize> {
-        match self {
-            Connector::Input(_, connected, _) => {
-                match connected {
-                    Some(connected) => {
-                        if *connected == connection {
-                            Some(*connected)
-                        } else {
-                            None
-                        }
-                    },
-                    None => None,
-                }
-            },
-            Connector::Output(_, edge, _, _) => {
-                match edge {
-                    Some(edge) => {
-                        if edge.get_input()[0] == connection {
-                            Some(edge.get_input()[0])
-                        } else {
-                            None
-                        }
-                    },
-                    None => None,
-                }
-            },
-        }
-    }
 }
 
 impl PartialEq for Connector {
@@ -234,8 +181,8 @@ impl Clone for ConnectorInfo {
 impl Clone for Connector {
     fn clone(&self) -> Self {
         match self {
-            Connector::Input(info, edge, connector_type) => Connector::Input(info.clone(), edge.clone(), connector_type.clone()),
-            Connector::Output(info, edges, value, connector_type) => Connector::Output(info.clone(), edges.clone(), value.clone(), connector_type.clone()),
+            Connector::Input(info, edge, connector_type) => Connector::Input(info.clone(), *edge, *connector_type),
+            Connector::Output(info, edges, value, connector_type) => Connector::Output(info.clone(), edges.clone(), value.clone(), *connector_type),
         }
     }
 }
@@ -0,0 +1,330 @@
+use std::fmt;
+
+const SECOND: i64 = 1000;
+const MINUTE: i64 = 60 * SECOND;
+const HOUR: i64 = 60 * MINUTE;
+const DAY: i64 = 24 * HOUR;
+
+const MONTHS: [&str; 12] = ["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"];
+const WEEKDAYS: [&str; 7] = ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"];
+
+/// Point in time, stored as milliseconds since the Unix epoch in UTC.\
+/// Parsed from and displayed as ISO 8601, for example `2024-03-01T12:30:00Z`.
+///
+/// # Example
+/// ```
+/// let date = DateTime::parse("2024-03-01 12:30").unwrap();
+///
+/// assert_eq!(date.get_parts(), (2024, 3, 1, 12, 30, 0, 0));
+/// assert_eq!(date.to_string(), "2024-03-01T12:30:00Z");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct DateTime {
+    milliseconds: i64,
+}
+
+/// Days since the epoch of a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Proleptic Gregorian date of a number of days since the epoch.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+
+    (year_of_era + era * 400 + if month <= 2 { 1 } else { 0 }, month, day)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl DateTime {
+    pub fn new(milliseconds: i64) -> DateTime {
+        DateTime {
+            milliseconds,
+        }
+    }
+
+    /// `None` when a part is out of range, such as the 30th of February.
+    pub fn from_parts(year: i64, month: i64, day: i64, hour: i64, minute: i64, second: i64, millisecond: i64) -> Option<DateTime> {
+        let valid = (1..=12).contains(&month)
+            && (1..=days_in_month(year, month)).contains(&day)
+            && (0..24).contains(&hour)
+            && (0..60).contains(&minute)
+            && (0..60).contains(&second)
+            && (0..1000).contains(&millisecond);
+
+        match valid {
+            true => Some(DateTime::new(days_from_civil(year, month, day) * DAY + hour * HOUR + minute * MINUTE + second * SECOND + millisecond)),
+            false => None,
+        }
+    }
+
+    pub fn get_milliseconds(&self) -> i64 {
+        self.milliseconds
+    }
+
+    /// Seconds since the epoch, with the milliseconds as fraction.
+    pub fn get_seconds(&self) -> f64 {
+        self.milliseconds as f64 / SECOND as f64
+    }
+
+    /// Year, month, day, hour, minute, second and millisecond.
+    pub fn get_parts(&self) -> (i64, i64, i64, i64, i64, i64, i64) {
+        let (year, month, day) = civil_from_days(self.milliseconds.div_euclid(DAY));
+        let time = self.milliseconds.rem_euclid(DAY);
+
+        (year, month, day, time / HOUR, time % HOUR / MINUTE, time % MINUTE / SECOND, time % SECOND)
+    }
+
+    /// Day of the week, from 0 for Monday to 6 for Sunday.
+    pub fn get_weekday(&self) -> i64 {
+        (self.milliseconds.div_euclid(DAY) + 3).rem_euclid(7)
+    }
+
+    /// Formats the date with `strftime` directives: `%Y` year, `%y` two digit year, `%m` month, `%d` day,
+    /// `%j` day of the year, `%H` hour, `%I` hour on 12, `%p` AM or PM, `%M` minute, `%S` second, `%L` millisecond,
+    /// `%b` and `%B` month name, `%a` and `%A` weekday name and `%%` a percent sign.
+    /// Other characters are copied as they are.
+    pub fn format(&self, pattern: &str) -> String {
+        let (year, month, day, hour, minute, second, millisecond) = self.get_parts();
+        let mut result = String::new();
+        let mut characters = pattern.chars();
+
+        while let Some(character) = characters.next() {
+            if character != '%' {
+                result.push(character);
+                continue;
+            }
+
+            match characters.next() {
+                Some('Y') => result.push_str(&format!("{:04}", year)),
+                Some('y') => result.push_str(&format!("{:02}", year.rem_euclid(100))),
+                Some('m') => result.push_str(&format!("{:02}", month)),
+                Some('d') => result.push_str(&format!("{:02}", day)),
+                Some('j') => result.push_str(&format!("{:03}", days_from_civil(year, month, day) - days_from_civil(year, 1, 1) + 1)),
+                Some('H') => result.push_str(&format!("{:02}", hour)),
+                Some('I') => result.push_str(&format!("{:02}", (hour + 11) % 12 + 1)),
+                Some('p') => result.push_str(if hour < 12 { "AM" } else { "PM" }),
+                Some('M') => result.push_str(&format!("{:02}", minute)),
+                Some('S') => result.push_str(&format!("{:02}", second)),
+                Some('L') => result.push_str(&format!("{:03}", millisecond)),
+                Some('b') => result.push_str(&MONTHS[month as usize - 1][..3]),
+                Some('B') => result.push_str(MONTHS[month as usize - 1]),
+                Some('a') => result.push_str(&WEEKDAYS[self.get_weekday() as usize][..3]),
+                Some('A') => result.push_str(WEEKDAYS[self.get_weekday() as usize]),
+                Some('%') => result.push('%'),
+                Some(other) => {
+                    result.push('%');
+                    result.push(other);
+                },
+                None => result.push('%'),
+            }
+        }
+
+        result
+    }
+
+    /// Start of the interval of `milliseconds` containing the date, intervals are counted from the epoch.
+    pub fn floor(&self, milliseconds: i64) -> DateTime {
+        match milliseconds > 0 {
+            true => DateTime::new(self.milliseconds.div_euclid(milliseconds) * milliseconds),
+            false => *self,
+        }
+    }
+
+    /// Parses an ISO 8601 date with an optional time and offset, such as `2024-03-01`,
+    /// `2024-03-01 12:30` or `2024-03-01T12:30:15.250+02:00`. Dates without offset are in UTC.
+    pub fn parse(text: &str) -> Option<DateTime> {
+        let text = text.trim();
+        let number = |range: std::ops::Range<usize>| -> Option<i64> {
+            let digits = text.get(range)?;
+
+            match digits.bytes().all(|byte| byte.is_ascii_digit()) {
+                true => digits.parse().ok(),
+                false => None,
+            }
+        };
+
+        if text.get(4..5)? != "-" || text.get(7..8)? != "-" {
+            return None;
+        }
+
+        let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+
+        if text.len() == 10 {
+            return DateTime::from_parts(year, month, day, 0, 0, 0, 0);
+        }
+
+        if !matches!(text.get(10..11)?, "T" | "t" | " ") || text.get(13..14)? != ":" {
+            return None;
+        }
+
+        let (hour, minute) = (number(11..13)?, number(14..16)?);
+        let mut position = 16;
+        let mut second = 0;
+        let mut millisecond = 0;
+
+        if text.get(position..position + 1) == Some(":") {
+            second = number(position + 1..position + 3)?;
+            position += 3;
+
+            if text.get(position..position + 1) == Some(".") {
+                let digits = text[position + 1..].bytes().take_while(|byte| byte.is_ascii_digit()).count();
+
+                // Only milliseconds are kept, further digits are truncated
+                millisecond = format!("{:0<3}", &text[position + 1..position + 1 + digits.min(3)]).parse().ok()?;
+                position += 1 + digits;
+            }
+        }
+
+        let offset = match text.get(position..)? {
+            "" | "Z" | "z" => 0,
+            zone => {
+                let sign = match zone.get(0..1)? {
+                    "+" => 1,
+                    "-" => -1,
+                    _ => return None,
+                };
+                let zone = zone[1..].replace(':', "");
+
+                if zone.len() != 4 || !zone.bytes().all(|byte| byte.is_ascii_digit()) {
+                    return None;
+                }
+
+                sign * (zone[0..2].parse::<i64>().ok()? * HOUR + zone[2..4].parse::<i64>().ok()? * MINUTE)
+            },
+        };
+
+        DateTime::from_parts(year, month, day, hour, minute, second, millisecond).map(|date| DateTime::new(date.milliseconds - offset))
+    }
+}
+
+/// Parses a duration such as `15s`, `1h30m` or `7d` into milliseconds.\
+/// Units are `ms`, `s`, `m`, `h`, `d` and `w`, a plain number is a number of seconds.
+pub fn parse_duration(text: &str) -> Option<i64> {
+    let text = text.trim();
+
+    if let Ok(seconds) = text.parse::<f64>() {
+        return Some((seconds * SECOND as f64).round() as i64);
+    }
+
+    let mut rest = text;
+    let mut total = 0.0;
+
+    while !rest.is_empty() {
+        let digits = rest.find(|character: char| !(character.is_ascii_digit() || character == '.')).unwrap_or(rest.len());
+        let value: f64 = rest[..digits].parse().ok()?;
+        let unit_length = rest[digits..].find(|character: char| !character.is_ascii_alphabetic()).unwrap_or(rest.len() - digits);
+
+        let unit = match &rest[digits..digits + unit_length] {
+            "ms" => 1,
+            "s" => SECOND,
+            "m" => MINUTE,
+            "h" => HOUR,
+            "d" => DAY,
+            "w" => 7 * DAY,
+            _ => return None,
+        };
+
+        total += value * unit as f64;
+        rest = rest[digits + unit_length..].trim_start();
+    }
+
+    match text.is_empty() {
+        true => None,
+        false => Some(total.round() as i64),
+    }
+}
+
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (year, month, day, hour, minute, second, millisecond) = self.get_parts();
+
+        write!(f, "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}", year, month, day, hour, minute, second)?;
+
+        if millisecond > 0 {
+            write!(f, ".{:03}", millisecond)?;
+        }
+
+        write!(f, "Z")
+    }
+}
+
+#[cfg(test)]
+mod datetime_test {
+    use super::*;
+
+    #[test]
+    fn test_parts() {
+        let date = DateTime::from_parts(2024, 2, 29, 23, 59, 59, 999).unwrap();
+
+        assert_eq!(DateTime::from_parts(1970, 1, 1, 0, 0, 0, 0), Some(DateTime::new(0)));
+        assert_eq!(date.get_parts(), (2024, 2, 29, 23, 59, 59, 999));
+        assert_eq!(DateTime::new(-1).get_parts(), (1969, 12, 31, 23, 59, 59, 999));
+        assert_eq!(DateTime::from_parts(2023, 2, 29, 0, 0, 0, 0), None);
+        assert_eq!(DateTime::from_parts(2000, 3, 1, 0, 0, 0, 0).unwrap().get_seconds(), 951_868_800.0);
+    }
+
+    #[test]
+    fn test_parse() {
+        let date = DateTime::from_parts(2024, 3, 1, 12, 30, 15, 250).unwrap();
+
+        assert_eq!(DateTime::parse("2024-03-01T12:30:15.250Z"), Some(date));
+        assert_eq!(DateTime::parse("2024-03-01t12:30:15.2504"), Some(date));
+        assert_eq!(DateTime::parse("2024-03-01T14:30:15.25+02:00"), Some(date));
+        assert_eq!(DateTime::parse("2024-03-01 07:30:15.25-0500"), Some(date));
+        assert_eq!(DateTime::parse("2024-03-01 12:30"), DateTime::from_parts(2024, 3, 1, 12, 30, 0, 0));
+        assert_eq!(DateTime::parse("2024-03-01"), DateTime::from_parts(2024, 3, 1, 0, 0, 0, 0));
+        assert_eq!(DateTime::parse("2024-13-01"), None);
+        assert_eq!(DateTime::parse("2024-03-01X12:30"), None);
+        assert_eq!(DateTime::parse("March 1st"), None);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(DateTime::new(0).to_string(), "1970-01-01T00:00:00Z");
+        assert_eq!(DateTime::parse("2024-03-01T12:30:15.250Z").unwrap().to_string(), "2024-03-01T12:30:15.250Z");
+    }
+
+    #[test]
+    fn test_format() {
+        let date = DateTime::parse("2024-03-01T15:04:05.250Z").unwrap();
+
+        assert_eq!(date.get_weekday(), 4);
+        assert_eq!(date.format("%d/%m/%y %I:%M %p"), "01/03/24 03:04 PM");
+        assert_eq!(date.format("%A %e %B %Y, day %j, %H:%M:%S.%L 100%%"), "Friday %e March 2024, day 061, 15:04:05.250 100%");
+        assert_eq!(DateTime::new(0).format("%a %b"), "Thu Jan");
+    }
+
+    #[test]
+    fn test_duration() {
+        assert_eq!(parse_duration("1h30m"), Some(90 * MINUTE));
+        assert_eq!(parse_duration("2d 12h"), Some(60 * HOUR));
+        assert_eq!(parse_duration("250ms"), Some(250));
+        assert_eq!(parse_duration("1.5"), Some(1500));
+        assert_eq!(parse_duration("3 fortnights"), None);
+        assert_eq!(parse_duration(""), None);
+        assert_eq!(DateTime::new(DAY + HOUR).floor(DAY), DateTime::new(DAY));
+        assert_eq!(DateTime::new(-HOUR).floor(DAY), DateTime::new(-DAY));
+    }
+}
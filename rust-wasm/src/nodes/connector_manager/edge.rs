@@ -11,10 +11,6 @@ impl ConnectorEdge {
         }
     }
 
-    pub fn has_connection(&self, connector: usize) -> bool {
-        self.output == connector || self.input.contains(&connector)
-    }
-
     pub fn get_input(&self) -> Vec<usize> {
         self.input.clone()
     }
@@ -28,18 +24,12 @@ impl ConnectorEdge {
             self.input.push(input);
         }
     }
-
-    pub fn remove_input(&mut self, input: usize) {
-        if self.input.contains(&input) {
-            self.input.retain(|&x| x != input);
-        }
-    }
 }
 
 impl Clone for ConnectorEdge {
     fn clone(&self) -> ConnectorEdge {
         ConnectorEdge {
-            output: self.output.clone(),
+            output: self.output,
             input: self.input.clone(),
         }
     }
@@ -0,0 +1,88 @@
+use std::fmt;
+use super::value::ConnectorType;
+
+/// Named values kept in insertion order, such as the results of a statistical test.
+///
+/// # Example
+/// ```
+/// let mut record = Record::new();
+///
+/// record.set_field("Statistic", 2.5);
+/// record.set_field("P-Value", 0.03);
+///
+/// assert_eq!(record.get_field("Statistic"), Some(&ConnectorType::new(2.5)));
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Default)]
+pub struct Record {
+    fields: Vec<(String, ConnectorType)>,
+}
+
+impl Record {
+    pub fn new() -> Record {
+        Record {
+            fields: Vec::new(),
+        }
+    }
+
+    /// Sets the value of a field, new fields are added at the end.
+    pub fn set_field<T>(&mut self, name: &str, value: T)
+    where
+        T: Into<ConnectorType>
+    {
+        let value = value.into();
+
+        match self.fields.iter_mut().find(|field| field.0 == name) {
+            Some(field) => field.1 = value,
+            None => self.fields.push((name.to_string(), value)),
+        }
+    }
+
+    pub fn get_field(&self, name: &str) -> Option<&ConnectorType> {
+        self.fields.iter().find(|field| field.0 == name).map(|field| &field.1)
+    }
+
+    pub fn get_fields(&self) -> &[(String, ConnectorType)] {
+        &self.fields
+    }
+
+    pub fn get_field_names(&self) -> Vec<String> {
+        self.fields.iter().map(|field| field.0.clone()).collect()
+    }
+
+    pub fn get_values(&self) -> Vec<ConnectorType> {
+        self.fields.iter().map(|field| field.1.clone()).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{")?;
+
+        for (index, (name, value)) in self.fields.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{}: {}", name, value)?;
+        }
+
+        write!(f, "}}")
+    }
+}
+
+impl From<Vec<ConnectorType>> for Record {
+    /// Fields of a list are named after their index.
+    fn from(values: Vec<ConnectorType>) -> Self {
+        Record {
+            fields: values.into_iter().enumerate().map(|(index, value)| (index.to_string(), value)).collect(),
+        }
+    }
+}
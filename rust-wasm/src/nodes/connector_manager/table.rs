@@ -0,0 +1,225 @@
+use std::fmt;
+use crate::helper::types::Null;
+use super::record::Record;
+use super::value::{ConnectorType, ConnectorTypeId};
+
+/// A named column of values sharing one connector type.\
+/// Values are converted to the column type on insertion, except `Null` which marks a missing value.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct Column {
+    name: String,
+    connector_type: i32,
+    values: Vec<ConnectorType>,
+}
+
+impl Column {
+    pub fn new<T>(name: &str, connector_type: i32, values: Vec<T>) -> Column
+    where
+        T: Into<ConnectorType>
+    {
+        let values = values.into_iter().map(|value| {
+            let value: ConnectorType = value.into();
+
+            match value {
+                ConnectorType::Null(_) => value,
+                value => value.get_variant(connector_type),
+            }
+        }).collect();
+
+        Column {
+            name: name.to_string(),
+            connector_type,
+            values,
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn set_name(&mut self, name: &str) {
+        self.name = name.to_string();
+    }
+
+    pub fn get_type(&self) -> i32 {
+        self.connector_type
+    }
+
+    pub fn get_values(&self) -> &[ConnectorType] {
+        &self.values
+    }
+
+    pub fn get_value(&self, row: usize) -> Option<&ConnectorType> {
+        self.values.get(row)
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn into_values(self) -> Vec<ConnectorType> {
+        self.values
+    }
+}
+
+/// Rows of values stored as equally long columns.
+///
+/// # Example
+/// ```
+/// let table = Table::from_columns(vec![
+///     Column::new("Name", ConnectorTypeId::STRING, vec!["a", "b"]),
+///     Column::new("Score", ConnectorTypeId::FLOAT, vec![1.5, 2.0]),
+/// ]).unwrap();
+///
+/// assert_eq!(table.get_row_count(), 2);
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Default)]
+pub struct Table {
+    columns: Vec<Column>,
+}
+
+impl Table {
+    pub fn new() -> Table {
+        Table {
+            columns: Vec::new(),
+        }
+    }
+
+    /// Returns `None` when the columns have different lengths.
+    pub fn from_columns(columns: Vec<Column>) -> Option<Table> {
+        let mut table = Table::new();
+
+        for column in columns {
+            if !table.add_column(column) {
+                return None;
+            }
+        }
+
+        Some(table)
+    }
+
+    /// Builds a table from rows, missing cells are `Null` and extra cells are dropped.
+    pub fn from_rows(names: &[&str], types: &[i32], rows: Vec<Vec<ConnectorType>>) -> Table {
+        let mut columns: Vec<Vec<ConnectorType>> = names.iter().map(|_| Vec::with_capacity(rows.len())).collect();
+
+        for mut row in rows {
+            row.resize(names.len(), ConnectorType::Null(Null::new()));
+
+            for (column, value) in columns.iter_mut().zip(row) {
+                column.push(value);
+            }
+        }
+
+        Table {
+            columns: names.iter().zip(types).zip(columns).map(|((name, connector_type), values)| Column::new(name, *connector_type, values)).collect(),
+        }
+    }
+
+    /// Appends a column, returns false when its length doesn't match the other columns.
+    pub fn add_column(&mut self, column: Column) -> bool {
+        if !self.columns.is_empty() && column.len() != self.get_row_count() {
+            return false;
+        }
+
+        self.columns.push(column);
+
+        true
+    }
+
+    pub fn get_columns(&self) -> &[Column] {
+        &self.columns
+    }
+
+    pub fn into_columns(self) -> Vec<Column> {
+        self.columns
+    }
+
+    pub fn get_column(&self, name: &str) -> Option<&Column> {
+        self.columns.iter().find(|column| column.name == name)
+    }
+
+    pub fn get_column_index(&self, name: &str) -> Option<usize> {
+        self.columns.iter().position(|column| column.name == name)
+    }
+
+    pub fn get_column_names(&self) -> Vec<String> {
+        self.columns.iter().map(|column| column.get_name()).collect()
+    }
+
+    pub fn get_column_count(&self) -> usize {
+        self.columns.len()
+    }
+
+    pub fn get_row_count(&self) -> usize {
+        self.columns.first().map(|column| column.len()).unwrap_or(0)
+    }
+
+    pub fn get_row(&self, row: usize) -> Option<Vec<ConnectorType>> {
+        self.columns.iter().map(|column| column.get_value(row).cloned()).collect()
+    }
+
+    pub fn get_rows(&self) -> Vec<Vec<ConnectorType>> {
+        (0..self.get_row_count()).filter_map(|row| self.get_row(row)).collect()
+    }
+
+    /// Table of the rows at the indices, in that order. Indices can repeat and out of range indices are skipped.
+    pub fn take_rows(&self, rows: &[usize]) -> Table {
+        let rows: Vec<usize> = rows.iter().copied().filter(|row| *row < self.get_row_count()).collect();
+
+        Table {
+            columns: self.columns.iter().map(|column| Column {
+                name: column.name.clone(),
+                connector_type: column.connector_type,
+                values: rows.iter().map(|row| column.values[*row].clone()).collect(),
+            }).collect(),
+        }
+    }
+
+    /// A row as a record with a field per column.
+    pub fn get_record(&self, row: usize) -> Option<Record> {
+        let mut record = Record::new();
+
+        for column in &self.columns {
+            record.set_field(&column.name, column.get_value(row)?.clone());
+        }
+
+        Some(record)
+    }
+}
+
+impl fmt::Display for Table {
+    /// Column names on the first line, then one line per row.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.get_column_names().join(", "))?;
+
+        for row in self.get_rows() {
+            let row: Vec<String> = row.iter().map(|value| value.to_string()).collect();
+
+            write!(f, "\n{}", row.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<Vec<ConnectorType>> for Table {
+    /// A list becomes a single `Value` column.
+    fn from(values: Vec<ConnectorType>) -> Self {
+        Table {
+            columns: vec![Column::new("Value", ConnectorTypeId::ANY, values)],
+        }
+    }
+}
+
+impl From<Record> for Table {
+    /// A record becomes a single row with a column per field.
+    fn from(record: Record) -> Self {
+        Table {
+            columns: record.get_fields().iter().map(|(name, value)| Column::new(name, ConnectorTypeId::ANY, vec![value.clone()])).collect(),
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use super::Manager;
-use super::value::{ConnectorType, ConnectorTypeId};
+use super::value::ConnectorTypeId;
 
 #[cfg(test)]
 mod tests {
@@ -25,7 +25,7 @@ mod tests {
 
         // Edge check
         assert_eq!(manager.get_connector(connector1).unwrap().get_connected().first().unwrap(), &connector2, "Connector 1 should be connected to connector 2");
-        assert_eq!(manager.get_connector(connector2).unwrap().get_edge().is_none(), false,  "Connector 2 should have edge to connector 1");
+        assert!(manager.get_connector(connector2).unwrap().get_edge().is_some(), "Connector 2 should have edge to connector 1");
 
         println!("Connector 1 edge: {:?}", manager.get_connector(connector1).unwrap().get_connected());
         println!("Connector 2 edge: {:?}", manager.get_connector(connector2).unwrap().get_edge());
@@ -36,7 +36,7 @@ mod tests {
         assert_eq!(manager.get_value(connector1), 0, "Connector 2 should have value 0");
 
         assert_eq!(manager.get_connector(connector1).unwrap().get_connected().first(), None, "Connector 1 should be disconnected");
-        assert_eq!(manager.get_connector(connector2).unwrap().get_edge().is_none(), true,  "Connector 2 should be disconnected");
+        assert!(manager.get_connector(connector2).unwrap().get_edge().is_none(), "Connector 2 should be disconnected");
 
         println!("Connector 1 edge: {:?}", manager.get_connector(connector1).unwrap().get_connected());
         println!("Connector 2 edge: {:?}", manager.get_connector(connector2).unwrap().get_edge());
@@ -54,8 +54,8 @@ mod tests {
         manager.connect(connector1, connector2);
 
         // Check if edge exist
-        assert_eq!(manager.get_connector(connector1).unwrap().get_edge().is_none(), true, "Connector 1 should be disconnected");
-        assert_eq!(manager.get_connector(connector2).unwrap().get_edge().is_none(), true,  "Connector 2 should be disconnected");
+        assert!(manager.get_connector(connector1).unwrap().get_edge().is_none(), "Connector 1 should be disconnected");
+        assert!(manager.get_connector(connector2).unwrap().get_edge().is_none(), "Connector 2 should be disconnected");
     }
 
     #[test]
@@ -70,8 +70,8 @@ mod tests {
         manager.connect(connector1, connector2);
 
         // Check if edge exist
-        assert_eq!(manager.get_connector(connector1).unwrap().get_connected().first().is_none(), true, "Connector 1 should be disconnected");
-        assert_eq!(manager.get_connector(connector2).unwrap().get_connected().first().is_none(), true,  "Connector 2 should be disconnected");
+        assert!(manager.get_connector(connector1).unwrap().get_connected().is_empty(), "Connector 1 should be disconnected");
+        assert!(manager.get_connector(connector2).unwrap().get_connected().is_empty(), "Connector 2 should be disconnected");
     }
 
     #[test]
@@ -80,7 +80,7 @@ mod tests {
         
         // Create connectors
         let connector1 = manager.add_connector("connector1".to_string(), 0, ConnectorTypeId::INT, false);
-        let connector2 = manager.add_connector("connector2".to_string(), 3.14, ConnectorTypeId::FLOAT, true);
+        let connector2 = manager.add_connector("connector2".to_string(), 3.25, ConnectorTypeId::FLOAT, true);
         let connector3 = manager.add_connector("connector3".to_string(), "Hello, World!", ConnectorTypeId::STRING, true);
         let connector4 = manager.add_connector("connector4".to_string(), false, ConnectorTypeId::BOOL, true);
         let connector5 = manager.add_connector("connector5".to_string(), true, ConnectorTypeId::BOOL, true);
@@ -192,6 +192,28 @@ mod tests {
 
         assert_eq!(manager.get_value(connector1), 15, "Connector 1 should have value 15");
         assert_eq!(manager.get_value(connector3), 15, "Connector 3 should have value 15");
-        assert_eq!(manager.get_connector(connector2).unwrap().get_connected().first().is_none(), true, "Connector 2 should be disconnected");
+        assert!(manager.get_connector(connector2).unwrap().get_connected().is_empty(), "Connector 2 should be disconnected");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn io_disconnection_keeps_other_edges() {
+        let mut manager = Manager::new();
+
+        // Create two separate connections
+        let connector1 = manager.add_connector("connector1".to_string(), 0, ConnectorTypeId::INT, false);
+        let connector2 = manager.add_connector("connector2".to_string(), 5, ConnectorTypeId::INT, true);
+        let connector3 = manager.add_connector("connector3".to_string(), 10, ConnectorTypeId::INT, false);
+        let connector4 = manager.add_connector("connector4".to_string(), 15, ConnectorTypeId::INT, true);
+
+        manager.connect(connector1, connector2);
+        manager.connect(connector3, connector4);
+
+        // Disconnecting one output leaves the other connection in place
+        manager.disconnect(connector2);
+
+        assert!(manager.get_connector(connector1).unwrap().get_connected().is_empty(), "Connector 1 should be disconnected");
+        assert!(manager.get_connector(connector2).unwrap().get_edge().is_none(), "Connector 2 should be disconnected");
+        assert_eq!(manager.get_connector(connector3).unwrap().get_connected().first(), Some(&connector4), "Connector 3 should stay connected");
+        assert!(manager.get_connector(connector4).unwrap().get_edge().is_some(), "Connector 4 should stay connected");
+    }
+}
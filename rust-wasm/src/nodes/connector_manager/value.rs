@@ -1,5 +1,8 @@
 use std::{fmt, ops::{Add, Sub, Mul, Div, Rem, AddAssign, SubAssign, DivAssign, MulAssign, RemAssign}, cmp::Ordering};
 use crate::helper::types::Null;
+use super::datetime::DateTime;
+use super::record::Record;
+use super::table::Table;
 
 // Macro
 macro_rules! impl_arithmetic {
@@ -8,10 +11,20 @@ macro_rules! impl_arithmetic {
             type Output = ConnectorType;
 
             fn $method(self, other: Self) -> Self::Output {
-                match (self.clone(), other) {
+                match (self, other) {
                     (ConnectorType::Integer(lhs), ConnectorType::Integer(rhs)) => ConnectorType::Integer(lhs $operator rhs),
                     (ConnectorType::Float(lhs), ConnectorType::Float(rhs)) => ConnectorType::Float(lhs $operator rhs),
-                    _ => panic!("Unsupported operation: {} for {:?}", stringify!($method), self),
+                    (ConnectorType::Null(_), _) | (_, ConnectorType::Null(_)) => ConnectorType::Null(Null::new()),
+                    (ConnectorType::List(lhs), ConnectorType::List(rhs)) => {
+                        if lhs.len() != rhs.len() {
+                            panic!("Unsupported operation: {} for lists of length {} and {}", stringify!($method), lhs.len(), rhs.len());
+                        }
+
+                        ConnectorType::List(lhs.into_iter().zip(rhs).map(|(lhs, rhs)| lhs $operator rhs).collect())
+                    },
+                    (ConnectorType::List(lhs), rhs) => ConnectorType::List(lhs.into_iter().map(|lhs| lhs $operator rhs.clone()).collect()),
+                    (lhs, ConnectorType::List(rhs)) => ConnectorType::List(rhs.into_iter().map(|rhs| lhs.clone() $operator rhs).collect()),
+                    (lhs, _) => panic!("Unsupported operation: {} for {:?}", stringify!($method), lhs),
                 }
             }
         }
@@ -22,6 +35,7 @@ macro_rules! impl_arithmetic {
             fn $method(self, other: i32) -> Self::Output {
                 match self {
                     ConnectorType::Integer(value) => ConnectorType::Integer(value $operator other),
+                    ConnectorType::List(values) => ConnectorType::List(values.into_iter().map(|value| value $operator other).collect()),
                     _ => panic!("Unsupported operation: {} for {:?}", stringify!($method), self),
                 }
             }
@@ -33,6 +47,7 @@ macro_rules! impl_arithmetic {
             fn $method(self, other: f32) -> Self::Output {
                 match self {
                     ConnectorType::Float(value) => ConnectorType::Float(value $operator other),
+                    ConnectorType::List(values) => ConnectorType::List(values.into_iter().map(|value| value $operator other).collect()),
                     _ => panic!("Unsupported operation: {} for {:?}", stringify!($method), self),
                 }
             }
@@ -44,6 +59,7 @@ macro_rules! impl_arithmetic {
             fn $method(self, other: ConnectorType) -> Self::Output {
                 match other {
                     ConnectorType::Integer(value) => ConnectorType::Integer(self $operator value),
+                    ConnectorType::List(values) => ConnectorType::List(values.into_iter().map(|value| self $operator value).collect()),
                     _ => panic!("Unsupported operation: {} for {:?}", stringify!($method), other),
                 }
             }
@@ -55,6 +71,7 @@ macro_rules! impl_arithmetic {
             fn $method(self, other: ConnectorType) -> Self::Output {
                 match other {
                     ConnectorType::Float(value) => ConnectorType::Float(self $operator value),
+                    ConnectorType::List(values) => ConnectorType::List(values.into_iter().map(|value| self $operator value).collect()),
                     _ => panic!("Unsupported operation: {} for {:?}", stringify!($method), other),
                 }
             }
@@ -66,10 +83,24 @@ macro_rules! impl_assign {
     ($trait:ident, $method:ident, $operator:tt) => {
         impl $trait<ConnectorType> for ConnectorType {
             fn $method(&mut self, other: Self) {
-                match (self.clone(), other) {
-                    (ConnectorType::Integer(mut lhs), ConnectorType::Integer(rhs)) => lhs $operator rhs,
-                    (ConnectorType::Float(mut lhs), ConnectorType::Float(rhs)) => lhs $operator rhs,
-                    _ => panic!("Unsupported operation: {} for {:?}", stringify!($method), self),
+                match (self, other) {
+                    (ConnectorType::Integer(lhs), ConnectorType::Integer(rhs)) => *lhs $operator rhs,
+                    (ConnectorType::Float(lhs), ConnectorType::Float(rhs)) => *lhs $operator rhs,
+                    (ConnectorType::List(lhs), ConnectorType::List(rhs)) => {
+                        if lhs.len() != rhs.len() {
+                            panic!("Unsupported operation: {} for lists of length {} and {}", stringify!($method), lhs.len(), rhs.len());
+                        }
+
+                        for (lhs, rhs) in lhs.iter_mut().zip(rhs) {
+                            *lhs $operator rhs;
+                        }
+                    },
+                    (ConnectorType::List(lhs), rhs) => {
+                        for lhs in lhs.iter_mut() {
+                            *lhs $operator rhs.clone();
+                        }
+                    },
+                    (lhs, _) => panic!("Unsupported operation: {} for {:?}", stringify!($method), lhs),
                 }
             }
         }
@@ -78,6 +109,11 @@ macro_rules! impl_assign {
             fn $method(&mut self, other: i32) {
                 match self {
                     ConnectorType::Integer(value) => *value $operator other,
+                    ConnectorType::List(values) => {
+                        for value in values.iter_mut() {
+                            *value $operator other;
+                        }
+                    },
                     _ => panic!("Unsupported operation: {} for {:?}", stringify!($method), self),
                 }
             }
@@ -87,6 +123,11 @@ macro_rules! impl_assign {
             fn $method(&mut self, other: f32) {
                 match self {
                     ConnectorType::Float(value) => *value $operator other,
+                    ConnectorType::List(values) => {
+                        for value in values.iter_mut() {
+                            *value $operator other;
+                        }
+                    },
                     _ => panic!("Unsupported operation: {} for {:?}", stringify!($method), self),
                 }
             }
@@ -167,16 +208,22 @@ macro_rules! impl_to_connectortype {
 }
 
 macro_rules! type_matcher {
-    ($value:expr, $type:expr, $expressions:expr) => {{
+    ($value:expr, $type:expr, [$($expressions:expr),*]) => {{
         let index = match $type {
             ConnectorTypeId::INT => 0,
             ConnectorTypeId::FLOAT => 1,
             ConnectorTypeId::STRING => 2,
             ConnectorTypeId::BOOL => 3,
+            ConnectorTypeId::LIST => 4,
+            ConnectorTypeId::TABLE => 5,
+            ConnectorTypeId::RECORD => 6,
+            ConnectorTypeId::DATETIME => 7,
             ConnectorTypeId::NULL => return ConnectorType::Null(Null::new()),
             _ => panic!("Unsupported conversion from {:?} to {:?}", $value, $type),
         };
-        $expressions[index].clone()
+        // Only the selected conversion is evaluated
+        let expressions: &[&dyn Fn() -> ConnectorType] = &[$(&|| $expressions),*];
+        expressions[index]()
     }};
 }
 
@@ -188,7 +235,11 @@ macro_rules! exp_conversion {
                     ConnectorType::Integer(value.clone()),
                     ConnectorType::Float(value.clone() as f32),
                     ConnectorType::String(value.to_string()),
-                    ConnectorType::Boolean(value.clone() != 0)
+                    ConnectorType::Boolean(value.clone() != 0),
+                    ConnectorType::List(vec![ConnectorType::Integer(value.clone())]),
+                    ConnectorType::Table(Table::from(vec![ConnectorType::Integer(value.clone())])),
+                    ConnectorType::Record(Record::from(vec![ConnectorType::Integer(value.clone())])),
+                    ConnectorType::DateTime(DateTime::new(*value as i64 * 1000))
                 ])
             },
             ConnectorType::Float(value) => {
@@ -196,7 +247,11 @@ macro_rules! exp_conversion {
                     ConnectorType::Integer(value.clone() as i32),
                     ConnectorType::Float(value.clone()),
                     ConnectorType::String(value.to_string()),
-                    ConnectorType::Boolean(value.clone() != 0.0)
+                    ConnectorType::Boolean(value.clone() != 0.0),
+                    ConnectorType::List(vec![ConnectorType::Float(value.clone())]),
+                    ConnectorType::Table(Table::from(vec![ConnectorType::Float(value.clone())])),
+                    ConnectorType::Record(Record::from(vec![ConnectorType::Float(value.clone())])),
+                    ConnectorType::DateTime(DateTime::new((*value as f64 * 1000.0).round() as i64))
                 ])
             },
             ConnectorType::String(value) => {
@@ -204,7 +259,11 @@ macro_rules! exp_conversion {
                     ConnectorType::Integer(value.parse::<i32>().unwrap_or(0)),
                     ConnectorType::Float(value.parse::<f32>().unwrap_or(0.0)),
                     ConnectorType::String(value.clone()),
-                    ConnectorType::Boolean(value.parse::<bool>().unwrap_or(false))
+                    ConnectorType::Boolean(value.parse::<bool>().unwrap_or(false)),
+                    ConnectorType::List(vec![ConnectorType::String(value.clone())]),
+                    ConnectorType::Table(Table::from(vec![ConnectorType::String(value.clone())])),
+                    ConnectorType::Record(Record::from(vec![ConnectorType::String(value.clone())])),
+                    ConnectorType::DateTime(DateTime::parse(value).unwrap_or_default())
                 ])
             },
             ConnectorType::Boolean(value) => {
@@ -212,7 +271,11 @@ macro_rules! exp_conversion {
                     ConnectorType::Integer(if value.clone() { 1 } else { 0 }),
                     ConnectorType::Float(if value.clone() { 1.0 } else { 0.0 }),
                     ConnectorType::String(value.to_string()),
-                    ConnectorType::Boolean(value.clone())
+                    ConnectorType::Boolean(value.clone()),
+                    ConnectorType::List(vec![ConnectorType::Boolean(value.clone())]),
+                    ConnectorType::Table(Table::from(vec![ConnectorType::Boolean(value.clone())])),
+                    ConnectorType::Record(Record::from(vec![ConnectorType::Boolean(value.clone())])),
+                    ConnectorType::DateTime(DateTime::default())
                 ])
             },
             ConnectorType::Null(_) => {
@@ -220,7 +283,65 @@ macro_rules! exp_conversion {
                     ConnectorType::Integer(0),
                     ConnectorType::Float(0.0),
                     ConnectorType::String("".to_string()),
-                    ConnectorType::Boolean(false)
+                    ConnectorType::Boolean(false),
+                    ConnectorType::List(Vec::new()),
+                    ConnectorType::Table(Table::new()),
+                    ConnectorType::Record(Record::new()),
+                    ConnectorType::DateTime(DateTime::default())
+                ])
+            },
+            ConnectorType::List(values) => {
+                let first = values.first().cloned().unwrap_or(ConnectorType::Null(Null::new()));
+
+                type_matcher!(values, $type, [
+                    first.get_variant(ConnectorTypeId::INT),
+                    first.get_variant(ConnectorTypeId::FLOAT),
+                    ConnectorType::String($variant.to_string()),
+                    ConnectorType::Boolean(!values.is_empty()),
+                    ConnectorType::List(values.clone()),
+                    ConnectorType::Table(Table::from(values.clone())),
+                    ConnectorType::Record(Record::from(values.clone())),
+                    first.get_variant(ConnectorTypeId::DATETIME)
+                ])
+            },
+            ConnectorType::Table(table) => {
+                let first = table.get_row(0).and_then(|row| row.into_iter().next()).unwrap_or(ConnectorType::Null(Null::new()));
+
+                type_matcher!(table, $type, [
+                    first.get_variant(ConnectorTypeId::INT),
+                    first.get_variant(ConnectorTypeId::FLOAT),
+                    ConnectorType::String(table.to_string()),
+                    ConnectorType::Boolean(table.get_row_count() > 0),
+                    ConnectorType::List(table.get_rows().into_iter().map(ConnectorType::List).collect()),
+                    ConnectorType::Table(table.clone()),
+                    ConnectorType::Record(table.get_record(0).unwrap_or_default()),
+                    first.get_variant(ConnectorTypeId::DATETIME)
+                ])
+            },
+            ConnectorType::Record(record) => {
+                let first = record.get_values().into_iter().next().unwrap_or(ConnectorType::Null(Null::new()));
+
+                type_matcher!(record, $type, [
+                    first.get_variant(ConnectorTypeId::INT),
+                    first.get_variant(ConnectorTypeId::FLOAT),
+                    ConnectorType::String(record.to_string()),
+                    ConnectorType::Boolean(!record.is_empty()),
+                    ConnectorType::List(record.get_values()),
+                    ConnectorType::Table(Table::from(record.clone())),
+                    ConnectorType::Record(record.clone()),
+                    first.get_variant(ConnectorTypeId::DATETIME)
+                ])
+            },
+            ConnectorType::DateTime(value) => {
+                type_matcher!(value, $type, [
+                    ConnectorType::Integer(value.get_milliseconds().div_euclid(1000).clamp(i32::MIN as i64, i32::MAX as i64) as i32),
+                    ConnectorType::Float(value.get_seconds() as f32),
+                    ConnectorType::String(value.to_string()),
+                    ConnectorType::Boolean(value.get_milliseconds() != 0),
+                    ConnectorType::List(vec![ConnectorType::DateTime(*value)]),
+                    ConnectorType::Table(Table::from(vec![ConnectorType::DateTime(*value)])),
+                    ConnectorType::Record(Record::from(vec![ConnectorType::DateTime(*value)])),
+                    ConnectorType::DateTime(*value)
                 ])
             },
         }
@@ -246,8 +367,19 @@ macro_rules! exp_conversion {
 /// let string_type = ConnectorType::new("Hello, world!");
 /// let boolean_type = ConnectorType::new(true);
 /// let null_type = ConnectorType::new(crate::helper::types::Null::new());
+/// let list_type = ConnectorType::new(vec![1, 2, 3]);
 /// ```
 /// 
+/// Lists are operated on element-wise, so `List + Integer` adds the integer to every element
+/// and `List + List` adds elements pairwise.\
+/// Converting a list to a scalar type reads its first element.
+/// 
+/// Tables convert to a list of rows, and lists convert to a table with a single `Value` column.
+/// 
+/// Records convert to a table with a single row, and tables convert to a record of their first row.
+/// 
+/// Dates convert to and from numbers as seconds since the Unix epoch, and to and from strings as ISO 8601.
+/// 
 /// 
 /// # Adding a new type:
 /// To add more types, add a new variant to the enum `ConnectorType`, create a const identifier for it and implement the necessary traits.\
@@ -258,14 +390,14 @@ macro_rules! exp_conversion {
 /// // Update exp_conversion to include the new variant
 /// // Also updating the existing conversions to include the new variant if necessary
 /// macro_rules! type_matcher {
-///     ($value:expr, $type:expr, $expressions:expr) => {{
+///     ($value:expr, $type:expr, [$($expressions:expr),*]) => {{
 ///         let index = match $type {
 ///             ...,
-///             ConnectorTypeId::BOOL => 3,
-///             ConnectorTypeId::FOO => 4,
+///             ConnectorTypeId::TABLE => 5,
+///             ConnectorTypeId::FOO => 6,
 ///             ...
 ///         };
-///         $expressions[index].clone()
+///         ...
 ///     }};
 /// }
 /// macro_rules! exp_conversion {
@@ -369,7 +501,7 @@ macro_rules! exp_conversion {
 ///                value.into()
 ///             }
 /// ```
-/// - `type_matcher`: Matches the type to the corresponding index and returns the expression at that index.
+/// - `type_matcher`: Matches the type to the corresponding index and evaluates only the expression at that index.
 ///     - Parameters:
 ///         - `$value`: The value to match.
 ///         - `$type`: The type to match.
@@ -394,17 +526,43 @@ pub enum ConnectorType {
     Float(f32),
     String(String),
     Boolean(bool),
-    Null(Null)
+    Null(Null),
+    List(Vec<ConnectorType>),
+    Table(Table),
+    Record(Record),
+    DateTime(DateTime)
 }
 
 pub struct ConnectorTypeId {}
 
 impl ConnectorTypeId {
+    /// Connectors of this type pass values through without conversion.
+    pub const ANY: i32 = 0;
     pub const INT: i32 = 1;
     pub const FLOAT: i32 = 2;
     pub const STRING: i32 = 3;
     pub const BOOL: i32 = 4;
     pub const NULL: i32 = 5;
+    pub const LIST: i32 = 6;
+    pub const TABLE: i32 = 7;
+    pub const RECORD: i32 = 8;
+    pub const DATETIME: i32 = 9;
+
+    /// Name of a type for messages, such as `Integer`.
+    pub fn get_name(connector_type: i32) -> &'static str {
+        match connector_type {
+            ConnectorTypeId::INT => "Integer",
+            ConnectorTypeId::FLOAT => "Float",
+            ConnectorTypeId::STRING => "String",
+            ConnectorTypeId::BOOL => "Boolean",
+            ConnectorTypeId::NULL => "Null",
+            ConnectorTypeId::LIST => "List",
+            ConnectorTypeId::TABLE => "Table",
+            ConnectorTypeId::RECORD => "Record",
+            ConnectorTypeId::DATETIME => "DateTime",
+            _ => "Any",
+        }
+    }
 }
 
 impl ConnectorType {
@@ -422,12 +580,24 @@ impl ConnectorType {
             ConnectorType::String(_) => ConnectorTypeId::STRING,
             ConnectorType::Boolean(_) => ConnectorTypeId::BOOL,
             ConnectorType::Null(_) => ConnectorTypeId::NULL,
+            ConnectorType::List(_) => ConnectorTypeId::LIST,
+            ConnectorType::Table(_) => ConnectorTypeId::TABLE,
+            ConnectorType::Record(_) => ConnectorTypeId::RECORD,
+            ConnectorType::DateTime(_) => ConnectorTypeId::DATETIME,
         }
     }
 
     pub fn get_variant(&self, connector_type: i32) -> ConnectorType {
+        if connector_type == ConnectorTypeId::ANY {
+            return self.clone();
+        }
+
         exp_conversion!(connector_type, self)
     }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, ConnectorType::Null(_))
+    }
 }
 
 impl Eq for ConnectorType {}
@@ -446,6 +616,10 @@ impl Ord for ConnectorType {
             (ConnectorType::String(lhs), ConnectorType::String(rhs)) => lhs.cmp(rhs),
             (ConnectorType::Boolean(lhs), ConnectorType::Boolean(rhs)) => lhs.cmp(rhs),
             (ConnectorType::Null(_), ConnectorType::Null(_)) => Ordering::Equal,
+            (ConnectorType::List(lhs), ConnectorType::List(rhs)) => lhs.cmp(rhs),
+            (ConnectorType::Table(lhs), ConnectorType::Table(rhs)) => lhs.cmp(rhs),
+            (ConnectorType::Record(lhs), ConnectorType::Record(rhs)) => lhs.cmp(rhs),
+            (ConnectorType::DateTime(lhs), ConnectorType::DateTime(rhs)) => lhs.cmp(rhs),
             _ => Ordering::Equal,
         }
     }
@@ -459,6 +633,22 @@ impl fmt::Display for ConnectorType {
             ConnectorType::String(value) => write!(f, "{}", value),
             ConnectorType::Boolean(value) => write!(f, "{}", value),
             ConnectorType::Null(_) => write!(f, "Null"),
+            ConnectorType::List(values) => {
+                write!(f, "[")?;
+
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+
+                    write!(f, "{}", value)?;
+                }
+
+                write!(f, "]")
+            },
+            ConnectorType::Table(table) => write!(f, "{}", table),
+            ConnectorType::Record(record) => write!(f, "{}", record),
+            ConnectorType::DateTime(value) => write!(f, "{}", value),
         }
     }
 }
@@ -475,6 +665,19 @@ impl_to_connectortype!(f32, ConnectorType::Float);
 impl_to_connectortype!(String, ConnectorType::String);
 impl_to_connectortype!(&str, |value: &str| ConnectorType::String(value.to_string()));
 impl_to_connectortype!(bool, ConnectorType::Boolean);
+impl_to_connectortype!(Null, ConnectorType::Null);
+impl_to_connectortype!(Table, ConnectorType::Table);
+impl_to_connectortype!(Record, ConnectorType::Record);
+impl_to_connectortype!(DateTime, ConnectorType::DateTime);
+
+impl<T> From<Vec<T>> for ConnectorType
+where
+    T: Into<ConnectorType>
+{
+    fn from(values: Vec<T>) -> Self {
+        ConnectorType::List(values.into_iter().map(|value| value.into()).collect())
+    }
+}
 
 impl_assign!(AddAssign, add_assign, +=);
 impl_assign!(SubAssign, sub_assign, -=);
@@ -488,6 +691,10 @@ impl_partial_eq!(String, String);
 impl_partial_eq!(String, &str);
 impl_partial_eq!(Boolean, bool);
 impl_partial_eq!(Null, Null);
+impl_partial_eq!(List, Vec<ConnectorType>);
+impl_partial_eq!(Table, Table);
+impl_partial_eq!(Record, Record);
+impl_partial_eq!(DateTime, DateTime);
 
 impl_ordering!(Integer, i32);
 impl_ordering!(Float, f32);
@@ -516,7 +723,7 @@ mod value_test {
         assert_eq!(int, 5);
         assert_eq!(float, 5.0);
         assert_eq!(string, "Hello");
-        assert_eq!(boolean, true);
+        assert!(boolean);
 
         assert_eq!(int_type, 5);
         assert_eq!(float_type, 5.0);
@@ -740,13 +947,90 @@ mod value_test {
         assert!(float1 == float_inner, "Float equal comparison failed");
     }
 
+    #[test]
+    fn test_list() {
+        let ints = ConnectorType::new(vec![1, 2, 3]);
+        let floats = ConnectorType::new(vec![1.0, 2.0, 3.0]);
+
+        assert_eq!(ints.get_type(), ConnectorTypeId::LIST);
+        assert_eq!(ints.to_string(), "[1, 2, 3]");
+
+        assert_eq!(ints.clone() + 1, ConnectorType::new(vec![2, 3, 4]));
+        assert_eq!(10 - ints.clone(), ConnectorType::new(vec![9, 8, 7]));
+        assert_eq!(ints.clone() * ints.clone(), ConnectorType::new(vec![1, 4, 9]));
+        assert_eq!(floats.clone() / ConnectorType::new(2.0), ConnectorType::new(vec![0.5, 1.0, 1.5]));
+        assert_eq!(ints.clone() + ConnectorType::Null(Null::new()), Null::new());
+
+        let mut accumulator = ConnectorType::new(vec![1, 2, 3]);
+        accumulator += ConnectorType::new(vec![1, 1, 1]);
+        accumulator *= 2;
+
+        assert_eq!(accumulator, ConnectorType::new(vec![4, 6, 8]));
+
+        assert_eq!(ints.get_variant(ConnectorTypeId::INT), 1);
+        assert_eq!(ints.get_variant(ConnectorTypeId::FLOAT), 1.0);
+        assert_eq!(ints.get_variant(ConnectorTypeId::BOOL), true);
+        assert_eq!(ints.get_variant(ConnectorTypeId::ANY), ints);
+        assert_eq!(ConnectorType::new(5).get_variant(ConnectorTypeId::LIST), ConnectorType::new(vec![5]));
+        assert_eq!(ConnectorType::Null(Null::new()).get_variant(ConnectorTypeId::LIST), ConnectorType::List(Vec::new()));
+    }
+
+    #[test]
+    fn test_record() {
+        let mut record = Record::new();
+
+        record.set_field("Statistic", 2.5);
+        record.set_field("Degrees of Freedom", 4);
+        record.set_field("Statistic", 3.0);
+
+        let record_type = ConnectorType::new(record.clone());
+
+        assert_eq!(record_type.get_type(), ConnectorTypeId::RECORD);
+        assert_eq!(record_type.to_string(), "{Statistic: 3, Degrees of Freedom: 4}");
+        assert_eq!(record_type.get_variant(ConnectorTypeId::FLOAT), 3.0);
+        assert_eq!(record_type.get_variant(ConnectorTypeId::LIST), ConnectorType::new(vec![ConnectorType::new(3.0), ConnectorType::new(4)]));
+
+        let table = record_type.get_variant(ConnectorTypeId::TABLE);
+
+        assert_eq!(table.get_variant(ConnectorTypeId::RECORD), record);
+        assert_eq!(ConnectorType::new(vec![1, 2]).get_variant(ConnectorTypeId::RECORD).to_string(), "{0: 1, 1: 2}");
+        assert_eq!(ConnectorType::Null(Null::new()).get_variant(ConnectorTypeId::RECORD), Record::new());
+    }
+
+    #[test]
+    fn test_datetime() {
+        let date = DateTime::parse("2024-03-01T12:30:00Z").unwrap();
+        let date_type = ConnectorType::new(date);
+
+        assert_eq!(date_type.get_type(), ConnectorTypeId::DATETIME);
+        assert_eq!(date_type.to_string(), "2024-03-01T12:30:00Z");
+        assert_eq!(date_type.get_variant(ConnectorTypeId::INT), 1_709_296_200);
+        assert_eq!(ConnectorType::new(1_709_296_200).get_variant(ConnectorTypeId::DATETIME), date);
+        assert_eq!(ConnectorType::new("2024-03-01 12:30").get_variant(ConnectorTypeId::DATETIME), date);
+        assert_eq!(ConnectorType::new("Tomorrow").get_variant(ConnectorTypeId::DATETIME), DateTime::new(0));
+        assert_eq!(date_type.get_variant(ConnectorTypeId::STRING).get_variant(ConnectorTypeId::DATETIME), date);
+        assert!(ConnectorType::new(DateTime::new(0)) < date_type);
+    }
+
+    #[test]
+    fn test_assign_connector_type() {
+        let mut int1 = ConnectorType::new(5);
+        let mut float1 = ConnectorType::new(5.0);
+
+        int1 += ConnectorType::new(5);
+        float1 -= ConnectorType::new(2.5);
+
+        assert_eq!(int1, 10);
+        assert_eq!(float1, 2.5);
+    }
+
     // Fail tests
     #[test]
     #[should_panic]
     fn test_conversion_fail() {
         let int = 5;
         let int_type = ConnectorType::new(int);
-        let _ = int_type.get_variant(6);
+        let _ = int_type.get_variant(99);
     }
 
     #[test]
@@ -756,4 +1040,10 @@ mod value_test {
         let float = ConnectorType::new(5.0);
         let _ = int + float;
     }
+
+    #[test]
+    #[should_panic]
+    fn test_list_length_fail() {
+        let _ = ConnectorType::new(vec![1, 2]) + ConnectorType::new(vec![1, 2, 3]);
+    }
 }
\ No newline at end of file
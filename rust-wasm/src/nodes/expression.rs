@@ -0,0 +1,164 @@
+pub mod ast;
+pub mod check;
+pub mod evaluate;
+pub mod functions;
+pub mod lexer;
+pub mod parser;
+use std::collections::HashMap;
+use std::fmt;
+use crate::helper::types::Null;
+use super::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use super::node_manager::context::Context;
+use super::node_manager::node::{Node, Port};
+use ast::Expression;
+use parser::parse;
+
+/// Range of characters of a formula, `end` excluded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Span {
+    start: usize,
+    end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Span {
+        Span {
+            start,
+            end,
+        }
+    }
+
+    /// Smallest span covering both spans.
+    pub fn merge(&self, other: Span) -> Span {
+        Span::new(self.start.min(other.start), self.end.max(other.end))
+    }
+}
+
+/// An error in a formula, found while parsing or type checking it, with the span it is about.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExpressionError {
+    message: String,
+    span: Span,
+}
+
+impl ExpressionError {
+    pub fn new(message: &str, span: Span) -> ExpressionError {
+        ExpressionError {
+            message: message.to_string(),
+            span,
+        }
+    }
+
+    /// The message followed by the formula with the span underlined.
+    pub fn describe(&self, formula: &str) -> String {
+        let width = (self.span.end - self.span.start).max(1);
+
+        format!("{}\n{}\n{}{}", self, formula, " ".repeat(self.span.start), "^".repeat(width))
+    }
+}
+
+impl fmt::Display for ExpressionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}..{}", self.message, self.span.start, self.span.end)
+    }
+}
+
+/// Computes a formula such as `(a - mean) / sd * 100` from inputs named after its variables.\
+/// The formula is parsed when the node is created and has an input per variable, in the order they first appear.
+/// It is type checked against the types of the input values on every evaluation, errors give a `Null` result
+/// and are described in the `Error` output, which is empty otherwise.
+pub struct ExpressionNode {
+    formula: String,
+    expression: Result<Expression, ExpressionError>,
+}
+
+impl ExpressionNode {
+    pub fn new(formula: &str) -> ExpressionNode {
+        ExpressionNode {
+            formula: formula.to_string(),
+            expression: parse(formula),
+        }
+    }
+
+    /// Names of the variables of the formula, which are the names of the inputs.
+    pub fn get_variables(&self) -> Vec<String> {
+        match &self.expression {
+            Ok(expression) => expression.get_variables(),
+            Err(_) => Vec::new(),
+        }
+    }
+}
+
+impl Node for ExpressionNode {
+    fn get_name(&self) -> String {
+        "Expression".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        self.get_variables().iter().map(|name| Port::new(name, 0, ConnectorTypeId::ANY)).collect()
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Result", Null::new(), ConnectorTypeId::ANY),
+            Port::new("Error", "", ConnectorTypeId::STRING),
+        ]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let expression = match &self.expression {
+            Ok(expression) => expression,
+            Err(error) => return vec![ConnectorType::Null(Null::new()), ConnectorType::String(error.describe(&self.formula))],
+        };
+
+        let variables: HashMap<String, ConnectorType> = self.get_variables().into_iter().zip(context.get_inputs()).collect();
+        let types = variables.iter().map(|(name, value)| (name.clone(), value.get_type())).collect();
+
+        match expression.check(&types) {
+            Ok(_) => vec![expression.evaluate(&variables), ConnectorType::String(String::new())],
+            Err(error) => vec![ConnectorType::Null(Null::new()), ConnectorType::String(error.describe(&self.formula))],
+        }
+    }
+}
+
+#[cfg(test)]
+mod expression_test {
+    use super::*;
+    use crate::nodes::node_manager::Manager;
+
+    #[test]
+    fn test_describe() {
+        let error = ExpressionError::new("unknown function 'foo'", Span::new(4, 7));
+
+        assert_eq!(error.to_string(), "unknown function 'foo' at 4..7");
+        assert_eq!(error.describe("1 + foo(2)"), "unknown function 'foo' at 4..7\n1 + foo(2)\n    ^^^");
+    }
+
+    #[test]
+    fn test_node() {
+        let mut manager = Manager::new();
+        let node = manager.add_node(ExpressionNode::new("(a - mean) / sd * 100"));
+
+        assert_eq!(manager.get_node(node).unwrap().get_node().get_inputs()[2].get_name(), "sd");
+
+        manager.set_input_value(node, 0, vec![10, 30]);
+        manager.set_input_value(node, 1, 20);
+        manager.set_input_value(node, 2, 5.0);
+
+        assert_eq!(manager.evaluate(node), vec![ConnectorType::new(vec![-200.0, 200.0]), ConnectorType::new("")]);
+
+        manager.set_input_value(node, 1, "twenty");
+
+        assert_eq!(manager.evaluate(node)[1], ConnectorType::new("'-' can't be applied to List and String at 0..10\n(a - mean) / sd * 100\n^^^^^^^^^^"));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let mut manager = Manager::new();
+        let node = manager.add_node(ExpressionNode::new("a +* b"));
+
+        assert!(manager.get_node(node).unwrap().get_inputs().is_empty());
+        assert_eq!(manager.evaluate(node)[0], Null::new());
+        assert!(manager.evaluate(node)[1].to_string().starts_with("expected a value, found '*' at 3..4"));
+    }
+}
@@ -0,0 +1,120 @@
+use crate::nodes::connector_manager::value::ConnectorType;
+use super::Span;
+use super::functions::Function;
+
+/// Operators taking two operands.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BinaryOperator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Modulo,
+    Power,
+    Equal,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+    And,
+    Or,
+}
+
+impl BinaryOperator {
+    /// Symbol of the operator in formulas.
+    pub fn get_symbol(&self) -> &'static str {
+        match self {
+            BinaryOperator::Add => "+",
+            BinaryOperator::Subtract => "-",
+            BinaryOperator::Multiply => "*",
+            BinaryOperator::Divide => "/",
+            BinaryOperator::Modulo => "%",
+            BinaryOperator::Power => "^",
+            BinaryOperator::Equal => "==",
+            BinaryOperator::NotEqual => "!=",
+            BinaryOperator::Less => "<",
+            BinaryOperator::LessEqual => "<=",
+            BinaryOperator::Greater => ">",
+            BinaryOperator::GreaterEqual => ">=",
+            BinaryOperator::And => "and",
+            BinaryOperator::Or => "or",
+        }
+    }
+}
+
+/// Operators taking one operand.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UnaryOperator {
+    Negate,
+    Not,
+}
+
+impl UnaryOperator {
+    pub fn get_symbol(&self) -> &'static str {
+        match self {
+            UnaryOperator::Negate => "-",
+            UnaryOperator::Not => "not",
+        }
+    }
+}
+
+/// Kinds of nodes of the syntax tree.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExpressionKind {
+    Literal(ConnectorType),
+    Variable(String),
+    Unary(UnaryOperator, Box<Expression>),
+    Binary(BinaryOperator, Box<Expression>, Box<Expression>),
+    Call(Function, Vec<Expression>),
+}
+
+/// A node of the syntax tree of a formula, with the span of the formula it was parsed from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Expression {
+    kind: ExpressionKind,
+    span: Span,
+}
+
+impl Expression {
+    pub fn new(kind: ExpressionKind, span: Span) -> Expression {
+        Expression {
+            kind,
+            span,
+        }
+    }
+
+    pub fn get_kind(&self) -> &ExpressionKind {
+        &self.kind
+    }
+
+    pub fn get_span(&self) -> Span {
+        self.span
+    }
+
+    /// Names of the variables, in the order they first appear.
+    pub fn get_variables(&self) -> Vec<String> {
+        let mut variables = Vec::new();
+
+        self.collect_variables(&mut variables);
+
+        variables
+    }
+
+    fn collect_variables(&self, variables: &mut Vec<String>) {
+        match &self.kind {
+            ExpressionKind::Literal(_) => (),
+            ExpressionKind::Variable(name) => {
+                if !variables.contains(name) {
+                    variables.push(name.clone());
+                }
+            },
+            ExpressionKind::Unary(_, operand) => operand.collect_variables(variables),
+            ExpressionKind::Binary(_, lhs, rhs) => {
+                lhs.collect_variables(variables);
+                rhs.collect_variables(variables);
+            },
+            ExpressionKind::Call(_, arguments) => arguments.iter().for_each(|argument| argument.collect_variables(variables)),
+        }
+    }
+}
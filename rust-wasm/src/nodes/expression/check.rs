@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+use crate::nodes::connector_manager::value::ConnectorTypeId;
+use super::ExpressionError;
+use super::ast::{BinaryOperator, Expression, ExpressionKind, UnaryOperator};
+
+/// Types only known once evaluated, lists are checked element by element when evaluated.
+pub fn is_unknown(connector_type: i32) -> bool {
+    matches!(connector_type, ConnectorTypeId::ANY | ConnectorTypeId::NULL | ConnectorTypeId::LIST)
+}
+
+/// Types usable in arithmetic, booleans count as 0 and 1.
+pub fn is_numeric(connector_type: i32) -> bool {
+    matches!(connector_type, ConnectorTypeId::INT | ConnectorTypeId::FLOAT | ConnectorTypeId::BOOL)
+}
+
+/// Type of arithmetic on two numeric types, integers unless a float is involved.
+pub fn get_numeric_type(lhs: i32, rhs: i32) -> i32 {
+    match (lhs, rhs) {
+        (lhs, rhs) if is_unknown(lhs) || is_unknown(rhs) => ConnectorTypeId::ANY,
+        (ConnectorTypeId::FLOAT, _) | (_, ConnectorTypeId::FLOAT) => ConnectorTypeId::FLOAT,
+        _ => ConnectorTypeId::INT,
+    }
+}
+
+/// Type covering values of both types, `ANY` when they have nothing in common.
+pub fn get_common_type(lhs: i32, rhs: i32) -> i32 {
+    match (lhs, rhs) {
+        (lhs, rhs) if lhs == rhs => lhs,
+        (ConnectorTypeId::NULL, other) | (other, ConnectorTypeId::NULL) => other,
+        (lhs, rhs) if is_numeric(lhs) && is_numeric(rhs) => get_numeric_type(lhs, rhs),
+        _ => ConnectorTypeId::ANY,
+    }
+}
+
+fn is_comparable(lhs: i32, rhs: i32) -> bool {
+    is_unknown(lhs) || is_unknown(rhs) || lhs == rhs || (is_numeric(lhs) && is_numeric(rhs))
+}
+
+fn get_name(connector_type: i32) -> &'static str {
+    ConnectorTypeId::get_name(connector_type)
+}
+
+impl Expression {
+    /// Type of the value of the expression, given the types of the variables.\
+    /// Fails on operands and arguments of the wrong type, with the span of the culprit.
+    pub fn check(&self, types: &HashMap<String, i32>) -> Result<i32, ExpressionError> {
+        let span = self.get_span();
+
+        match self.get_kind() {
+            ExpressionKind::Literal(value) => Ok(value.get_type()),
+            ExpressionKind::Variable(name) => match types.get(name).copied() {
+                Some(ConnectorTypeId::TABLE | ConnectorTypeId::RECORD) => {
+                    Err(ExpressionError::new(&format!("'{}' is a {} and can't be used in a formula", name, get_name(types[name])), span))
+                },
+                Some(connector_type) => Ok(connector_type),
+                None => Err(ExpressionError::new(&format!("unknown variable '{}'", name), span)),
+            },
+            ExpressionKind::Unary(operator, operand) => {
+                let operand_type = operand.check(types)?;
+
+                match operator {
+                    _ if is_unknown(operand_type) => Ok(if *operator == UnaryOperator::Not { ConnectorTypeId::BOOL } else { ConnectorTypeId::ANY }),
+                    UnaryOperator::Negate if is_numeric(operand_type) => Ok(get_numeric_type(operand_type, ConnectorTypeId::INT)),
+                    UnaryOperator::Not if operand_type == ConnectorTypeId::BOOL => Ok(ConnectorTypeId::BOOL),
+                    _ => Err(ExpressionError::new(&format!("'{}' can't be applied to {}", operator.get_symbol(), get_name(operand_type)), span)),
+                }
+            },
+            ExpressionKind::Binary(operator, lhs, rhs) => {
+                let (lhs_type, rhs_type) = (lhs.check(types)?, rhs.check(types)?);
+                let mismatch = || ExpressionError::new(&format!("'{}' can't be applied to {} and {}", operator.get_symbol(), get_name(lhs_type), get_name(rhs_type)), span);
+
+                match operator {
+                    BinaryOperator::And | BinaryOperator::Or => {
+                        for (operand, operand_type) in [(lhs, lhs_type), (rhs, rhs_type)] {
+                            if !is_unknown(operand_type) && operand_type != ConnectorTypeId::BOOL {
+                                return Err(ExpressionError::new(&format!("'{}' expects Booleans, found {}", operator.get_symbol(), get_name(operand_type)), operand.get_span()));
+                            }
+                        }
+
+                        Ok(ConnectorTypeId::BOOL)
+                    },
+                    BinaryOperator::Equal | BinaryOperator::NotEqual | BinaryOperator::Less | BinaryOperator::LessEqual | BinaryOperator::Greater | BinaryOperator::GreaterEqual => {
+                        match is_comparable(lhs_type, rhs_type) {
+                            true => Ok(ConnectorTypeId::BOOL),
+                            false => Err(mismatch()),
+                        }
+                    },
+                    BinaryOperator::Add if lhs_type == ConnectorTypeId::STRING && rhs_type == ConnectorTypeId::STRING => Ok(ConnectorTypeId::STRING),
+                    _ if !(is_unknown(lhs_type) || is_numeric(lhs_type)) || !(is_unknown(rhs_type) || is_numeric(rhs_type)) => Err(mismatch()),
+                    BinaryOperator::Power => Ok(match get_numeric_type(lhs_type, rhs_type) {
+                        ConnectorTypeId::FLOAT => ConnectorTypeId::FLOAT,
+                        _ => ConnectorTypeId::ANY,
+                    }),
+                    _ => Ok(get_numeric_type(lhs_type, rhs_type)),
+                }
+            },
+            ExpressionKind::Call(function, arguments) => {
+                let argument_types = arguments.iter().map(|argument| argument.check(types)).collect::<Result<Vec<i32>, _>>()?;
+
+                function.get_type(&argument_types).map_err(|(index, expected)| {
+                    let message = format!("'{}' expects {}, found {}", function.get_name(), expected, get_name(argument_types[index]));
+
+                    ExpressionError::new(&message, arguments[index].get_span())
+                })
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod check_test {
+    use super::*;
+    use crate::nodes::expression::Span;
+    use crate::nodes::expression::parser::parse;
+
+    fn check(formula: &str) -> Result<i32, ExpressionError> {
+        let types = HashMap::from([
+            ("count".to_string(), ConnectorTypeId::INT),
+            ("price".to_string(), ConnectorTypeId::FLOAT),
+            ("name".to_string(), ConnectorTypeId::STRING),
+            ("values".to_string(), ConnectorTypeId::LIST),
+            ("table".to_string(), ConnectorTypeId::TABLE),
+        ]);
+
+        parse(formula).unwrap().check(&types)
+    }
+
+    #[test]
+    fn test_types() {
+        assert_eq!(check("count * 2 + 1"), Ok(ConnectorTypeId::INT));
+        assert_eq!(check("count * price"), Ok(ConnectorTypeId::FLOAT));
+        assert_eq!(check("name + 'x'"), Ok(ConnectorTypeId::STRING));
+        assert_eq!(check("values * 2"), Ok(ConnectorTypeId::ANY));
+        assert_eq!(check("count > 1 and not (price < 2)"), Ok(ConnectorTypeId::BOOL));
+        assert_eq!(check("if(count > 1, count, null)"), Ok(ConnectorTypeId::INT));
+        assert_eq!(check("len(values)"), Ok(ConnectorTypeId::INT));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(check("count - name"), Err(ExpressionError::new("'-' can't be applied to Integer and String", Span::new(0, 12))));
+        assert_eq!(check("count > 1 and price"), Err(ExpressionError::new("'and' expects Booleans, found Float", Span::new(14, 19))));
+        assert_eq!(check("abs(name)"), Err(ExpressionError::new("'abs' expects a number, found String", Span::new(4, 8))));
+        assert_eq!(check("name == 1"), Err(ExpressionError::new("'==' can't be applied to String and Integer", Span::new(0, 9))));
+        assert_eq!(check("table + 1").unwrap_err().span, Span::new(0, 5));
+        assert_eq!(check("other").unwrap_err().message, "unknown variable 'other'");
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::value::ConnectorType;
+use crate::nodes::logic::{Comparison, LogicOperation, not};
+use crate::nodes::math::{BinaryOperation, broadcast};
+use super::ast::{BinaryOperator, Expression, ExpressionKind, UnaryOperator};
+use super::functions::Function;
+
+fn apply_binary(operator: BinaryOperator, lhs: &ConnectorType, rhs: &ConnectorType) -> ConnectorType {
+    let arithmetic = |operation: BinaryOperation| operation.apply(lhs, rhs);
+
+    match operator {
+        BinaryOperator::Add => match (lhs, rhs) {
+            (ConnectorType::String(lhs), ConnectorType::String(rhs)) => ConnectorType::String(format!("{}{}", lhs, rhs)),
+            _ => arithmetic(BinaryOperation::Add),
+        },
+        BinaryOperator::Subtract => arithmetic(BinaryOperation::Subtract),
+        BinaryOperator::Multiply => arithmetic(BinaryOperation::Multiply),
+        BinaryOperator::Divide => arithmetic(BinaryOperation::Divide),
+        BinaryOperator::Modulo => arithmetic(BinaryOperation::Modulo),
+        BinaryOperator::Power => arithmetic(BinaryOperation::Power),
+        BinaryOperator::Equal => Comparison::Equal.apply(lhs, rhs),
+        BinaryOperator::NotEqual => Comparison::NotEqual.apply(lhs, rhs),
+        BinaryOperator::Less => Comparison::Less.apply(lhs, rhs),
+        BinaryOperator::LessEqual => Comparison::LessEqual.apply(lhs, rhs),
+        BinaryOperator::Greater => Comparison::Greater.apply(lhs, rhs),
+        BinaryOperator::GreaterEqual => Comparison::GreaterEqual.apply(lhs, rhs),
+        BinaryOperator::And => LogicOperation::And.apply(lhs, rhs),
+        BinaryOperator::Or => LogicOperation::Or.apply(lhs, rhs),
+    }
+}
+
+fn apply_unary(operator: UnaryOperator, operand: &ConnectorType) -> ConnectorType {
+    match (operator, operand) {
+        (UnaryOperator::Negate, ConnectorType::Float(value)) => ConnectorType::Float(-value),
+        (UnaryOperator::Negate, operand) => BinaryOperation::Subtract.apply(&ConnectorType::Integer(0), operand),
+        (UnaryOperator::Not, operand) => not(operand),
+    }
+}
+
+impl Expression {
+    /// Value of the expression for the values of the variables, missing variables are `Null`.\
+    /// Operators and functions apply to each element of list operands, lists of different lengths give `Null`.
+    /// Branches of `if`, `coalesce`, `and` and `or` that can't change a scalar result aren't evaluated.
+    pub fn evaluate(&self, variables: &HashMap<String, ConnectorType>) -> ConnectorType {
+        match self.get_kind() {
+            ExpressionKind::Literal(value) => value.clone(),
+            ExpressionKind::Variable(name) => variables.get(name).cloned().unwrap_or(ConnectorType::Null(Null::new())),
+            ExpressionKind::Unary(operator, operand) => broadcast(&[operand.evaluate(variables)], &|operands| apply_unary(*operator, &operands[0])),
+            ExpressionKind::Binary(operator, lhs, rhs) => {
+                let lhs = lhs.evaluate(variables);
+
+                match (operator, &lhs) {
+                    (BinaryOperator::And, ConnectorType::Boolean(false)) | (BinaryOperator::Or, ConnectorType::Boolean(true)) => lhs,
+                    _ => broadcast(&[lhs, rhs.evaluate(variables)], &|operands| apply_binary(*operator, &operands[0], &operands[1])),
+                }
+            },
+            ExpressionKind::Call(Function::If, arguments) => match arguments[0].evaluate(variables) {
+                ConnectorType::Boolean(true) => arguments[1].evaluate(variables),
+                ConnectorType::Boolean(false) => arguments[2].evaluate(variables),
+                condition @ ConnectorType::List(_) => {
+                    let operands = [condition, arguments[1].evaluate(variables), arguments[2].evaluate(variables)];
+
+                    broadcast(&operands, &|operands| Function::If.apply(operands))
+                },
+                _ => ConnectorType::Null(Null::new()),
+            },
+            ExpressionKind::Call(Function::Coalesce, arguments) => {
+                let mut values = Vec::new();
+
+                for argument in arguments {
+                    let value = argument.evaluate(variables);
+
+                    if !value.is_null() && !matches!(value, ConnectorType::List(_)) && values.iter().all(|value| !matches!(value, ConnectorType::List(_))) {
+                        return value;
+                    }
+
+                    values.push(value);
+                }
+
+                broadcast(&values, &|operands| Function::Coalesce.apply(operands))
+            },
+            ExpressionKind::Call(function, arguments) => {
+                let values: Vec<ConnectorType> = arguments.iter().map(|argument| argument.evaluate(variables)).collect();
+
+                match function.is_element_wise() {
+                    true => broadcast(&values, &|operands| function.apply(operands)),
+                    false => function.apply(&values),
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod evaluate_test {
+    use super::*;
+    use crate::nodes::expression::parser::parse;
+
+    fn evaluate(formula: &str) -> ConnectorType {
+        let variables = HashMap::from([
+            ("a".to_string(), ConnectorType::new(vec![10, 20, 30])),
+            ("mean".to_string(), ConnectorType::new(20)),
+            ("sd".to_string(), ConnectorType::new(10.0)),
+            ("name".to_string(), ConnectorType::new(" Ada ")),
+            ("missing".to_string(), ConnectorType::Null(Null::new())),
+        ]);
+
+        parse(formula).unwrap().evaluate(&variables)
+    }
+
+    #[test]
+    fn test_evaluate() {
+        assert_eq!(evaluate("(a - mean) / sd * 100"), ConnectorType::new(vec![-100.0, 0.0, 100.0]));
+        assert_eq!(evaluate("-2 ^ 2 + 7 % 4"), -1);
+        assert_eq!(evaluate("2 ^ 3 ^ 2"), 512);
+        assert_eq!(evaluate("a > mean"), ConnectorType::new(vec![false, false, true]));
+        assert_eq!(evaluate("1 < 1.5 and 'a' < 'b'"), true);
+        assert_eq!(evaluate("upper(trim(name)) + '!'"), "ADA!");
+        assert_eq!(evaluate("len(a) + len(name)"), 8);
+        assert_eq!(evaluate("round(sd / 3, 2)"), 3.33);
+        assert_eq!(evaluate("missing + 1"), Null::new());
+        assert_eq!(evaluate("missing or true"), true);
+        assert_eq!(evaluate("coalesce(missing, mean)"), 20);
+        assert_eq!(evaluate("if(a >= mean, 'high', 'low')"), ConnectorType::new(vec!["low", "high", "high"]));
+    }
+
+    #[test]
+    fn test_lazy() {
+        // Evaluating the other operands would give null or a list
+        assert_eq!(evaluate("if(mean > 1, 1, 1 / 0)"), 1);
+        assert_eq!(evaluate("false and a > 1"), false);
+        assert_eq!(evaluate("coalesce(mean, a)"), 20);
+    }
+}
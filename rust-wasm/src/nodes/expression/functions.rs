@@ -0,0 +1,255 @@
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::math::{BinaryOperation, UnaryOperation, to_float};
+use crate::nodes::text::basic::substring;
+use super::check::{get_common_type, get_numeric_type, is_numeric, is_unknown};
+
+/// Functions that can be called in a formula.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Function {
+    Abs,
+    /// Rounds to the nearest integer, or to a number of decimal digits given as second argument.
+    Round,
+    Floor,
+    Ceil,
+    Sqrt,
+    Exp,
+    Ln,
+    Log10,
+    Sin,
+    Cos,
+    Tan,
+    Pow,
+    Min,
+    Max,
+    /// `if(condition, then, else)`, only the taken branch is evaluated.
+    If,
+    /// First argument that isn't `Null`.
+    Coalesce,
+    IsNull,
+    /// Number of characters of a string, or of elements of a list.
+    Len,
+    /// `substr(text, start, length)`, from a 0-based start and to the end without length. A negative start counts from the end.
+    Substr,
+    Upper,
+    Lower,
+    Trim,
+    Concat,
+    Str,
+    Int,
+    Float,
+}
+
+const FUNCTIONS: [Function; 26] = [
+    Function::Abs, Function::Round, Function::Floor, Function::Ceil, Function::Sqrt, Function::Exp, Function::Ln, Function::Log10,
+    Function::Sin, Function::Cos, Function::Tan, Function::Pow, Function::Min, Function::Max, Function::If, Function::Coalesce,
+    Function::IsNull, Function::Len, Function::Substr, Function::Upper, Function::Lower, Function::Trim, Function::Concat,
+    Function::Str, Function::Int, Function::Float,
+];
+
+fn get_digits(value: &ConnectorType) -> Option<i32> {
+    to_float(value).filter(|value| value.is_finite()).map(|value| value as i32)
+}
+
+fn round(value: &ConnectorType, digits: &ConnectorType) -> ConnectorType {
+    let (number, digits) = match (to_float(value), get_digits(digits)) {
+        (Some(number), Some(digits)) => (number as f64, digits.clamp(-10, 10)),
+        _ => return ConnectorType::Null(Null::new()),
+    };
+
+    let factor = 10f64.powi(digits);
+    let rounded = (number * factor).round() / factor;
+
+    match value {
+        ConnectorType::Integer(_) | ConnectorType::Boolean(_) => ConnectorType::Integer(rounded as i32),
+        _ => ConnectorType::Float(rounded as f32),
+    }
+}
+
+fn get_substring(text: &ConnectorType, start: &ConnectorType, length: Option<&ConnectorType>) -> ConnectorType {
+    let (text, start) = match (text, get_digits(start)) {
+        (ConnectorType::String(text), Some(start)) => (text, start as i64),
+        _ => return ConnectorType::Null(Null::new()),
+    };
+
+    match length.map(get_digits) {
+        None => ConnectorType::String(substring(text, start, None)),
+        Some(Some(length)) => ConnectorType::String(substring(text, start, Some(length as i64))),
+        Some(None) => ConnectorType::Null(Null::new()),
+    }
+}
+
+impl Function {
+    /// Function called by a name, ignoring case.
+    pub fn from_name(name: &str) -> Option<Function> {
+        FUNCTIONS.iter().copied().find(|function| function.get_name().eq_ignore_ascii_case(name))
+    }
+
+    pub fn get_name(&self) -> String {
+        format!("{:?}", self).to_lowercase()
+    }
+
+    /// Least and most number of arguments, `None` when there is no limit.
+    pub fn get_arity(&self) -> (usize, Option<usize>) {
+        match self {
+            Function::Round => (1, Some(2)),
+            Function::Pow => (2, Some(2)),
+            Function::Min | Function::Max | Function::Coalesce | Function::Concat => (1, None),
+            Function::If => (3, Some(3)),
+            Function::Substr => (2, Some(3)),
+            _ => (1, Some(1)),
+        }
+    }
+
+    /// Whether the function is applied to each element of list arguments.
+    pub fn is_element_wise(&self) -> bool {
+        *self != Function::Len
+    }
+
+    /// Type of the result for the types of the arguments,
+    /// or the index of the first argument of the wrong type with the expected type.
+    pub fn get_type(&self, types: &[i32]) -> Result<i32, (usize, &'static str)> {
+        let expect = |index: usize, check: &dyn Fn(i32) -> bool, expected: &'static str| match is_unknown(types[index]) || check(types[index]) {
+            true => Ok(()),
+            false => Err((index, expected)),
+        };
+
+        match self {
+            Function::Abs | Function::Round | Function::Floor | Function::Ceil => {
+                for index in 0..types.len() {
+                    expect(index, &is_numeric, "a number")?;
+                }
+
+                Ok(get_numeric_type(types[0], ConnectorTypeId::INT))
+            },
+            Function::Sqrt | Function::Exp | Function::Ln | Function::Log10 | Function::Sin | Function::Cos | Function::Tan => {
+                expect(0, &is_numeric, "a number")?;
+                Ok(ConnectorTypeId::FLOAT)
+            },
+            Function::Pow | Function::Min | Function::Max => {
+                for index in 0..types.len() {
+                    expect(index, &is_numeric, "a number")?;
+                }
+
+                match self {
+                    // A negative integer exponent gives a float
+                    Function::Pow => Ok(match get_numeric_type(types[0], types[1]) {
+                        ConnectorTypeId::FLOAT => ConnectorTypeId::FLOAT,
+                        _ => ConnectorTypeId::ANY,
+                    }),
+                    _ => Ok(types.iter().fold(types[0], |result, argument| get_numeric_type(result, *argument))),
+                }
+            },
+            Function::If => {
+                expect(0, &|argument| argument == ConnectorTypeId::BOOL, "a Boolean")?;
+                Ok(get_common_type(types[1], types[2]))
+            },
+            Function::Coalesce => Ok(types.iter().fold(ConnectorTypeId::NULL, |result, argument| get_common_type(result, *argument))),
+            Function::IsNull => Ok(ConnectorTypeId::BOOL),
+            Function::Len => {
+                expect(0, &|argument| argument == ConnectorTypeId::STRING, "a String or a List")?;
+                Ok(ConnectorTypeId::INT)
+            },
+            Function::Substr => {
+                expect(0, &|argument| argument == ConnectorTypeId::STRING, "a String")?;
+
+                for index in 1..types.len() {
+                    expect(index, &is_numeric, "a number")?;
+                }
+
+                Ok(ConnectorTypeId::STRING)
+            },
+            Function::Upper | Function::Lower | Function::Trim => {
+                expect(0, &|argument| argument == ConnectorTypeId::STRING, "a String")?;
+                Ok(ConnectorTypeId::STRING)
+            },
+            Function::Concat | Function::Str => Ok(ConnectorTypeId::STRING),
+            Function::Int => Ok(ConnectorTypeId::INT),
+            Function::Float => Ok(ConnectorTypeId::FLOAT),
+        }
+    }
+
+    /// Applies the function to scalar arguments, or to lists for functions that aren't element-wise.\
+    /// Arguments of the wrong type produce `Null`.
+    pub fn apply(&self, arguments: &[ConnectorType]) -> ConnectorType {
+        let null = ConnectorType::Null(Null::new());
+        let unary = |operation: UnaryOperation| operation.apply(&arguments[0]);
+        let fold = |operation: BinaryOperation| arguments[1..].iter().fold(arguments[0].clone(), |result, argument| operation.apply(&result, argument));
+        let text = |transform: &dyn Fn(&str) -> String| match &arguments[0] {
+            ConnectorType::String(value) => ConnectorType::String(transform(value)),
+            _ => ConnectorType::Null(Null::new()),
+        };
+
+        match self {
+            Function::Abs => unary(UnaryOperation::Abs),
+            Function::Round if arguments.len() == 2 => round(&arguments[0], &arguments[1]),
+            Function::Round => unary(UnaryOperation::Round),
+            Function::Floor => unary(UnaryOperation::Floor),
+            Function::Ceil => unary(UnaryOperation::Ceil),
+            Function::Sqrt => unary(UnaryOperation::Sqrt),
+            Function::Exp => unary(UnaryOperation::Exp),
+            Function::Ln => unary(UnaryOperation::Log),
+            Function::Log10 => unary(UnaryOperation::Log10),
+            Function::Sin => unary(UnaryOperation::Sin),
+            Function::Cos => unary(UnaryOperation::Cos),
+            Function::Tan => unary(UnaryOperation::Tan),
+            Function::Pow => BinaryOperation::Power.apply(&arguments[0], &arguments[1]),
+            Function::Min => fold(BinaryOperation::Min),
+            Function::Max => fold(BinaryOperation::Max),
+            Function::If => match arguments[0] {
+                ConnectorType::Boolean(true) => arguments[1].clone(),
+                ConnectorType::Boolean(false) => arguments[2].clone(),
+                _ => null,
+            },
+            Function::Coalesce => arguments.iter().find(|argument| !argument.is_null()).cloned().unwrap_or(null),
+            Function::IsNull => ConnectorType::Boolean(arguments[0].is_null()),
+            Function::Len => match &arguments[0] {
+                ConnectorType::String(value) => ConnectorType::Integer(value.chars().count() as i32),
+                ConnectorType::List(values) => ConnectorType::Integer(values.len() as i32),
+                _ => null,
+            },
+            Function::Substr => get_substring(&arguments[0], &arguments[1], arguments.get(2)),
+            Function::Upper => text(&|value| value.to_uppercase()),
+            Function::Lower => text(&|value| value.to_lowercase()),
+            Function::Trim => text(&|value| value.trim().to_string()),
+            Function::Concat => ConnectorType::String(arguments.iter().filter(|argument| !argument.is_null()).map(|argument| argument.to_string()).collect()),
+            Function::Str | Function::Int | Function::Float if arguments[0].is_null() => null,
+            Function::Str => ConnectorType::String(arguments[0].to_string()),
+            Function::Int => arguments[0].get_variant(ConnectorTypeId::INT),
+            Function::Float => arguments[0].get_variant(ConnectorTypeId::FLOAT),
+        }
+    }
+}
+
+#[cfg(test)]
+mod functions_test {
+    use super::*;
+
+    fn apply(function: Function, arguments: Vec<ConnectorType>) -> ConnectorType {
+        function.apply(&arguments)
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_eq!(apply(Function::Round, vec![ConnectorType::new(2.346), ConnectorType::new(2)]), 2.35);
+        assert_eq!(apply(Function::Round, vec![ConnectorType::new(1250), ConnectorType::new(-2)]), 1300);
+        assert_eq!(apply(Function::Max, vec![ConnectorType::new(1), ConnectorType::new(4.5), ConnectorType::new(3)]), 4.5);
+        assert_eq!(apply(Function::Coalesce, vec![ConnectorType::Null(Null::new()), ConnectorType::new("b")]), "b");
+        assert_eq!(apply(Function::Len, vec![ConnectorType::new("héllo")]), 5);
+        assert_eq!(apply(Function::Substr, vec![ConnectorType::new("formula"), ConnectorType::new(-3)]), "ula");
+        assert_eq!(apply(Function::Substr, vec![ConnectorType::new("formula"), ConnectorType::new(1), ConnectorType::new(3)]), "orm");
+        assert_eq!(apply(Function::Concat, vec![ConnectorType::new("a"), ConnectorType::Null(Null::new()), ConnectorType::new(1)]), "a1");
+        assert_eq!(apply(Function::Upper, vec![ConnectorType::new(1)]), Null::new());
+        assert_eq!(apply(Function::Int, vec![ConnectorType::new("42")]), 42);
+    }
+
+    #[test]
+    fn test_type() {
+        assert_eq!(Function::Abs.get_type(&[ConnectorTypeId::INT]), Ok(ConnectorTypeId::INT));
+        assert_eq!(Function::Max.get_type(&[ConnectorTypeId::INT, ConnectorTypeId::FLOAT]), Ok(ConnectorTypeId::FLOAT));
+        assert_eq!(Function::If.get_type(&[ConnectorTypeId::BOOL, ConnectorTypeId::INT, ConnectorTypeId::NULL]), Ok(ConnectorTypeId::INT));
+        assert_eq!(Function::Substr.get_type(&[ConnectorTypeId::STRING, ConnectorTypeId::STRING]), Err((1, "a number")));
+        assert_eq!(Function::from_name("IsNull"), Some(Function::IsNull));
+        assert_eq!(Function::from_name("median"), None);
+    }
+}
@@ -0,0 +1,159 @@
+use super::{ExpressionError, Span};
+
+/// A token of a formula.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Token {
+    Integer(i32),
+    Float(f32),
+    String(String),
+    /// A name, either plain like `price` or quoted in backticks like `` `Unit Price` ``.
+    Name(String),
+    /// An operator or punctuation, such as `+`, `<=` or `(`.
+    Symbol(&'static str),
+    End,
+}
+
+impl Token {
+    /// Description of the token for messages.
+    pub fn describe(&self) -> String {
+        match self {
+            Token::Integer(value) => format!("number {}", value),
+            Token::Float(value) => format!("number {}", value),
+            Token::String(value) => format!("string \"{}\"", value),
+            Token::Name(name) => format!("name '{}'", name),
+            Token::Symbol(symbol) => format!("'{}'", symbol),
+            Token::End => "end of formula".to_string(),
+        }
+    }
+}
+
+const SYMBOLS: [&str; 18] = ["==", "!=", "<=", ">=", "&&", "||", "+", "-", "*", "/", "%", "^", "(", ")", ",", "<", ">", "!"];
+
+/// Splits a formula into tokens, ending with `Token::End`.\
+/// Spans count characters, not bytes.
+pub fn tokenize(formula: &str) -> Result<Vec<(Token, Span)>, ExpressionError> {
+    let characters: Vec<char> = formula.chars().collect();
+    let mut tokens = Vec::new();
+    let mut position = 0;
+
+    while position < characters.len() {
+        let start = position;
+        let character = characters[position];
+
+        if character.is_whitespace() {
+            position += 1;
+            continue;
+        }
+
+        let token = if character.is_ascii_digit() || (character == '.' && characters.get(position + 1).is_some_and(|next| next.is_ascii_digit())) {
+            let mut float = false;
+
+            while position < characters.len() && (characters[position].is_ascii_digit() || characters[position] == '.') {
+                float |= characters[position] == '.';
+                position += 1;
+            }
+
+            if position < characters.len() && matches!(characters[position], 'e' | 'E') {
+                let sign = matches!(characters.get(position + 1), Some('+' | '-')) as usize;
+
+                if characters.get(position + 1 + sign).is_some_and(|next| next.is_ascii_digit()) {
+                    float = true;
+                    position += 1 + sign;
+
+                    while position < characters.len() && characters[position].is_ascii_digit() {
+                        position += 1;
+                    }
+                }
+            }
+
+            let text: String = characters[start..position].iter().collect();
+            let span = Span::new(start, position);
+
+            match float {
+                false => Token::Integer(text.parse().map_err(|_| ExpressionError::new(&format!("{} is too large for an integer", text), span))?),
+                true => Token::Float(text.parse().map_err(|_| ExpressionError::new(&format!("'{}' isn't a number", text), span))?),
+            }
+        } else if character.is_alphabetic() || character == '_' {
+            while position < characters.len() && (characters[position].is_alphanumeric() || characters[position] == '_') {
+                position += 1;
+            }
+
+            Token::Name(characters[start..position].iter().collect())
+        } else if matches!(character, '"' | '\'' | '`') {
+            let mut text = String::new();
+
+            position += 1;
+
+            loop {
+                match characters.get(position) {
+                    None => {
+                        let what = if character == '`' { "quoted name" } else { "string" };
+
+                        return Err(ExpressionError::new(&format!("unterminated {}", what), Span::new(start, position)));
+                    },
+                    Some(next) if *next == character => break,
+                    Some('\\') if character != '`' => {
+                        position += 1;
+
+                        match characters.get(position) {
+                            Some('n') => text.push('\n'),
+                            Some('t') => text.push('\t'),
+                            Some(escaped) => text.push(*escaped),
+                            None => continue,
+                        }
+                    },
+                    Some(next) => text.push(*next),
+                }
+
+                position += 1;
+            }
+
+            position += 1;
+
+            match character {
+                '`' => Token::Name(text),
+                _ => Token::String(text),
+            }
+        } else {
+            let rest: String = characters[position..characters.len().min(position + 2)].iter().collect();
+
+            match SYMBOLS.iter().find(|symbol| rest.starts_with(**symbol)) {
+                Some(symbol) => {
+                    position += symbol.len();
+                    Token::Symbol(symbol)
+                },
+                None => return Err(ExpressionError::new(&format!("unexpected character '{}'", character), Span::new(start, start + 1))),
+            }
+        };
+
+        tokens.push((token, Span::new(start, position)));
+    }
+
+    tokens.push((Token::End, Span::new(characters.len(), characters.len())));
+
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod lexer_test {
+    use super::*;
+
+    fn get_tokens(formula: &str) -> Vec<Token> {
+        tokenize(formula).unwrap().into_iter().map(|(token, _)| token).collect()
+    }
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(get_tokens("a<=1.5e3"), vec![Token::Name("a".to_string()), Token::Symbol("<="), Token::Float(1500.0), Token::End]);
+        assert_eq!(get_tokens("`Unit Price` * 'it\\'s'"), vec![Token::Name("Unit Price".to_string()), Token::Symbol("*"), Token::String("it's".to_string()), Token::End]);
+        assert_eq!(get_tokens("f(2, .5)"), vec![Token::Name("f".to_string()), Token::Symbol("("), Token::Integer(2), Token::Symbol(","), Token::Float(0.5), Token::Symbol(")"), Token::End]);
+        assert_eq!(tokenize("é + 2").unwrap()[1].1, Span::new(2, 3));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(tokenize("a $ b").unwrap_err(), ExpressionError::new("unexpected character '$'", Span::new(2, 3)));
+        assert_eq!(tokenize("'abc").unwrap_err(), ExpressionError::new("unterminated string", Span::new(0, 4)));
+        assert_eq!(tokenize("99999999999").unwrap_err().span, Span::new(0, 11));
+    }
+}
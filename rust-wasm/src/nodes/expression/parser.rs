@@ -0,0 +1,340 @@
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::value::ConnectorType;
+use super::ast::{BinaryOperator, Expression, ExpressionKind, UnaryOperator};
+use super::functions::Function;
+use super::lexer::{Token, tokenize};
+use super::{ExpressionError, Span};
+
+/// Deepest nesting of parentheses, calls, unary operators and chained binary operators parsed,
+/// deeper formulas are rejected instead of overflowing the stack.
+const MAX_DEPTH: usize = 128;
+
+/// Recursive descent parser, from the lowest precedence to the highest:
+/// `or`, `and`, `not`, comparisons, `+ -`, `* / %`, unary `-`, `^` and calls.
+struct Parser {
+    tokens: Vec<(Token, Span)>,
+    position: usize,
+    depth: usize,
+}
+
+impl Parser {
+    /// Goes one level deeper into the syntax tree, failing at the token reached when it gets too deep.
+    fn enter(&mut self) -> Result<(), ExpressionError> {
+        match self.depth < MAX_DEPTH {
+            true => {
+                self.depth += 1;
+                Ok(())
+            },
+            false => Err(ExpressionError::new("formula nested too deeply", self.get_span())),
+        }
+    }
+
+    /// Parses with `parse` one level deeper, coming back up afterwards.
+    fn nested(&mut self, parse: fn(&mut Parser) -> Result<Expression, ExpressionError>) -> Result<Expression, ExpressionError> {
+        self.enter()?;
+
+        let expression = parse(self);
+
+        self.depth -= 1;
+        expression
+    }
+
+    fn peek(&self) -> &Token {
+        &self.tokens[self.position].0
+    }
+
+    fn get_span(&self) -> Span {
+        self.tokens[self.position].1
+    }
+
+    fn advance(&mut self) -> (Token, Span) {
+        let token = self.tokens[self.position].clone();
+
+        self.position = (self.position + 1).min(self.tokens.len() - 1);
+
+        token
+    }
+
+    fn is_symbol(&self, symbol: &str) -> bool {
+        matches!(self.peek(), Token::Symbol(found) if *found == symbol)
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Token::Name(name) if name.eq_ignore_ascii_case(keyword))
+    }
+
+    fn expect(&mut self, symbol: &str) -> Result<Span, ExpressionError> {
+        match self.is_symbol(symbol) {
+            true => Ok(self.advance().1),
+            false => Err(ExpressionError::new(&format!("expected '{}', found {}", symbol, self.peek().describe()), self.get_span())),
+        }
+    }
+
+    fn binary(operator: BinaryOperator, lhs: Expression, rhs: Expression) -> Expression {
+        let span = lhs.get_span().merge(rhs.get_span());
+
+        Expression::new(ExpressionKind::Binary(operator, Box::new(lhs), Box::new(rhs)), span)
+    }
+
+    fn parse_or(&mut self) -> Result<Expression, ExpressionError> {
+        let depth = self.depth;
+        let mut lhs = self.parse_and()?;
+
+        while self.is_symbol("||") || self.is_keyword("or") {
+            self.enter()?;
+            self.advance();
+            lhs = Parser::binary(BinaryOperator::Or, lhs, self.parse_and()?);
+        }
+
+        self.depth = depth;
+        Ok(lhs)
+    }
+
+    fn parse_and(&mut self) -> Result<Expression, ExpressionError> {
+        let depth = self.depth;
+        let mut lhs = self.parse_not()?;
+
+        while self.is_symbol("&&") || self.is_keyword("and") {
+            self.enter()?;
+            self.advance();
+            lhs = Parser::binary(BinaryOperator::And, lhs, self.parse_not()?);
+        }
+
+        self.depth = depth;
+        Ok(lhs)
+    }
+
+    fn parse_not(&mut self) -> Result<Expression, ExpressionError> {
+        if self.is_symbol("!") || self.is_keyword("not") {
+            let start = self.advance().1;
+            let operand = self.nested(Parser::parse_not)?;
+            let span = start.merge(operand.get_span());
+
+            return Ok(Expression::new(ExpressionKind::Unary(UnaryOperator::Not, Box::new(operand)), span));
+        }
+
+        self.parse_comparison()
+    }
+
+    fn parse_comparison(&mut self) -> Result<Expression, ExpressionError> {
+        let lhs = self.parse_additive()?;
+
+        let operator = match self.peek() {
+            Token::Symbol("==") => BinaryOperator::Equal,
+            Token::Symbol("!=") => BinaryOperator::NotEqual,
+            Token::Symbol("<") => BinaryOperator::Less,
+            Token::Symbol("<=") => BinaryOperator::LessEqual,
+            Token::Symbol(">") => BinaryOperator::Greater,
+            Token::Symbol(">=") => BinaryOperator::GreaterEqual,
+            _ => return Ok(lhs),
+        };
+
+        self.advance();
+
+        let comparison = Parser::binary(operator, lhs, self.parse_additive()?);
+
+        // Comparisons don't chain, `a < b < c` would compare a boolean
+        if let Token::Symbol("==" | "!=" | "<" | "<=" | ">" | ">=") = self.peek() {
+            return Err(ExpressionError::new("comparisons can't be chained, combine them with 'and'", self.get_span()));
+        }
+
+        Ok(comparison)
+    }
+
+    fn parse_additive(&mut self) -> Result<Expression, ExpressionError> {
+        let depth = self.depth;
+        let mut lhs = self.parse_multiplicative()?;
+
+        loop {
+            let operator = match self.peek() {
+                Token::Symbol("+") => BinaryOperator::Add,
+                Token::Symbol("-") => BinaryOperator::Subtract,
+                _ => break,
+            };
+
+            self.enter()?;
+            self.advance();
+            lhs = Parser::binary(operator, lhs, self.parse_multiplicative()?);
+        }
+
+        self.depth = depth;
+        Ok(lhs)
+    }
+
+    fn parse_multiplicative(&mut self) -> Result<Expression, ExpressionError> {
+        let depth = self.depth;
+        let mut lhs = self.parse_unary()?;
+
+        loop {
+            let operator = match self.peek() {
+                Token::Symbol("*") => BinaryOperator::Multiply,
+                Token::Symbol("/") => BinaryOperator::Divide,
+                Token::Symbol("%") => BinaryOperator::Modulo,
+                _ => break,
+            };
+
+            self.enter()?;
+            self.advance();
+            lhs = Parser::binary(operator, lhs, self.parse_unary()?);
+        }
+
+        self.depth = depth;
+        Ok(lhs)
+    }
+
+    fn parse_unary(&mut self) -> Result<Expression, ExpressionError> {
+        if self.is_symbol("-") {
+            let start = self.advance().1;
+            let operand = self.nested(Parser::parse_unary)?;
+            let span = start.merge(operand.get_span());
+
+            return Ok(Expression::new(ExpressionKind::Unary(UnaryOperator::Negate, Box::new(operand)), span));
+        }
+
+        if self.is_symbol("+") {
+            self.advance();
+
+            return self.nested(Parser::parse_unary);
+        }
+
+        self.parse_power()
+    }
+
+    fn parse_power(&mut self) -> Result<Expression, ExpressionError> {
+        let base = self.parse_primary()?;
+
+        match self.is_symbol("^") {
+            // Right associative, and binds tighter than a minus on its left: -2^2 is -4
+            true => {
+                self.advance();
+                Ok(Parser::binary(BinaryOperator::Power, base, self.nested(Parser::parse_unary)?))
+            },
+            false => Ok(base),
+        }
+    }
+
+    fn parse_primary(&mut self) -> Result<Expression, ExpressionError> {
+        let (token, span) = self.advance();
+
+        let kind = match token {
+            Token::Integer(value) => ExpressionKind::Literal(ConnectorType::Integer(value)),
+            Token::Float(value) => ExpressionKind::Literal(ConnectorType::Float(value)),
+            Token::String(value) => ExpressionKind::Literal(ConnectorType::String(value)),
+            Token::Symbol("(") => {
+                let inner = self.nested(Parser::parse_or)?;
+                let end = self.expect(")")?;
+
+                return Ok(Expression::new(inner.get_kind().clone(), span.merge(end)));
+            },
+            Token::Name(name) if self.is_symbol("(") => return self.parse_call(&name, span),
+            Token::Name(name) => match name.to_lowercase().as_str() {
+                "true" => ExpressionKind::Literal(ConnectorType::Boolean(true)),
+                "false" => ExpressionKind::Literal(ConnectorType::Boolean(false)),
+                "null" => ExpressionKind::Literal(ConnectorType::Null(Null::new())),
+                "and" | "or" | "not" => return Err(ExpressionError::new(&format!("expected a value, found '{}'", name), span)),
+                _ => ExpressionKind::Variable(name),
+            },
+            token => return Err(ExpressionError::new(&format!("expected a value, found {}", token.describe()), span)),
+        };
+
+        Ok(Expression::new(kind, span))
+    }
+
+    fn parse_call(&mut self, name: &str, start: Span) -> Result<Expression, ExpressionError> {
+        let function = Function::from_name(name).ok_or_else(|| ExpressionError::new(&format!("unknown function '{}'", name), start))?;
+        let mut arguments = Vec::new();
+
+        self.expect("(")?;
+
+        if !self.is_symbol(")") {
+            arguments.push(self.nested(Parser::parse_or)?);
+
+            while self.is_symbol(",") {
+                self.advance();
+                arguments.push(self.nested(Parser::parse_or)?);
+            }
+        }
+
+        let span = start.merge(self.expect(")")?);
+        let (min, max) = function.get_arity();
+
+        if arguments.len() < min || max.is_some_and(|max| arguments.len() > max) {
+            let expected = match max {
+                Some(max) if max == min => format!("{}", min),
+                Some(max) => format!("{} to {}", min, max),
+                None => format!("at least {}", min),
+            };
+            let plural = if expected == "1" { "argument" } else { "arguments" };
+
+            return Err(ExpressionError::new(&format!("'{}' takes {} {}, found {}", function.get_name(), expected, plural, arguments.len()), span));
+        }
+
+        Ok(Expression::new(ExpressionKind::Call(function, arguments), span))
+    }
+}
+
+/// Parses a formula into a syntax tree.
+///
+/// # Example
+/// ```text
+/// use crate::nodes::expression::parser::parse;
+///
+/// let expression = parse("(a - mean) / sd * 100").unwrap();
+///
+/// assert_eq!(expression.get_variables(), vec!["a", "mean", "sd"]);
+/// ```
+pub fn parse(formula: &str) -> Result<Expression, ExpressionError> {
+    let mut parser = Parser {
+        tokens: tokenize(formula)?,
+        position: 0,
+        depth: 0,
+    };
+
+    let expression = parser.parse_or()?;
+
+    match parser.peek() {
+        Token::End => Ok(expression),
+        token => Err(ExpressionError::new(&format!("expected an operator, found {}", token.describe()), parser.get_span())),
+    }
+}
+
+#[cfg(test)]
+mod parser_test {
+    use super::*;
+
+    fn get_operator(expression: &Expression) -> Option<BinaryOperator> {
+        match expression.get_kind() {
+            ExpressionKind::Binary(operator, _, _) => Some(*operator),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_precedence() {
+        assert_eq!(get_operator(&parse("a + b * c").unwrap()), Some(BinaryOperator::Add));
+        assert_eq!(get_operator(&parse("(a + b) * c").unwrap()), Some(BinaryOperator::Multiply));
+        assert_eq!(get_operator(&parse("a < b and not c or d").unwrap()), Some(BinaryOperator::Or));
+        assert_eq!(get_operator(&parse("-a ^ 2").unwrap()), None);
+        assert_eq!(parse("(a - mean) / sd * 100").unwrap().get_variables(), vec!["a", "mean", "sd"]);
+        assert_eq!(parse(" ( a + 1 ) ").unwrap().get_span(), Span::new(1, 10));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(parse("a +").unwrap_err(), ExpressionError::new("expected a value, found end of formula", Span::new(3, 3)));
+        assert_eq!(parse("(a + b").unwrap_err(), ExpressionError::new("expected ')', found end of formula", Span::new(6, 6)));
+        assert_eq!(parse("a b").unwrap_err(), ExpressionError::new("expected an operator, found name 'b'", Span::new(2, 3)));
+        assert_eq!(parse("foo(1)").unwrap_err(), ExpressionError::new("unknown function 'foo'", Span::new(0, 3)));
+        assert_eq!(parse("abs(1, 2)").unwrap_err(), ExpressionError::new("'abs' takes 1 argument, found 2", Span::new(0, 9)));
+        assert_eq!(parse("a < b < c").unwrap_err().span, Span::new(6, 7));
+
+        let nested = format!("{}1{}", "(".repeat(100000), ")".repeat(100000));
+
+        assert_eq!(parse(&nested).unwrap_err(), ExpressionError::new("formula nested too deeply", Span::new(129, 130)));
+        assert_eq!(parse(&"-".repeat(100000)).unwrap_err().message, "formula nested too deeply");
+        assert_eq!(parse(&"not ".repeat(100000)).unwrap_err().message, "formula nested too deeply");
+        assert_eq!(parse(&vec!["1"; 100000].join(" + ")).unwrap_err().message, "formula nested too deeply");
+        assert!(parse(&format!("{}1{}", "(".repeat(100), ")".repeat(100))).is_ok());
+        assert!(parse(&vec!["1"; 100].join(" + ")).is_ok());
+    }
+}
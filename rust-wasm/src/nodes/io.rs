@@ -0,0 +1,127 @@
+pub mod arrow;
+pub mod csv;
+pub mod export;
+pub mod json;
+pub mod parquet;
+pub mod sqlite;
+use std::cell::{Ref, RefCell};
+use std::collections::HashSet;
+use std::fmt;
+use super::connector_manager::table::{Column, Table};
+use super::connector_manager::value::{ConnectorType, ConnectorTypeId};
+
+/// A malformed part of an imported file, lines and columns count from 1.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImportError {
+    line: usize,
+    column: usize,
+    message: String,
+}
+
+impl ImportError {
+    pub fn new(line: usize, column: usize, message: &str) -> ImportError {
+        ImportError {
+            line,
+            column,
+            message: message.to_string(),
+        }
+    }
+
+    pub fn get_line(&self) -> usize {
+        self.line
+    }
+
+    pub fn get_column(&self) -> usize {
+        self.column
+    }
+
+    pub fn get_message(&self) -> String {
+        self.message.clone()
+    }
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)
+    }
+}
+
+/// Bytes of the file an import node reads, kept as the state of the node so the file is saved with the blueprint.
+pub struct FileState {
+    bytes: RefCell<Vec<u8>>,
+}
+
+impl FileState {
+    pub fn new() -> FileState {
+        FileState {
+            bytes: RefCell::new(Vec::new()),
+        }
+    }
+
+    pub fn get_bytes(&self) -> Ref<'_, Vec<u8>> {
+        self.bytes.borrow()
+    }
+
+    pub fn get_state(&self) -> Vec<u8> {
+        self.bytes.borrow().clone()
+    }
+
+    /// Replaces the file, any bytes are accepted as they are only read when the node is computed.
+    pub fn set_state(&self, state: &[u8]) -> bool {
+        *self.bytes.borrow_mut() = state.to_vec();
+
+        true
+    }
+}
+
+impl Default for FileState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Table of errors with their `Line`, `Column` and `Message`.
+pub fn get_error_table(errors: &[ImportError]) -> Table {
+    Table::from_columns(vec![
+        Column::new("Line", ConnectorTypeId::INT, errors.iter().map(|error| error.get_line() as i32).collect()),
+        Column::new("Column", ConnectorTypeId::INT, errors.iter().map(|error| error.get_column() as i32).collect()),
+        Column::new("Message", ConnectorTypeId::STRING, errors.iter().map(|error| error.get_message()).collect()),
+    ]).unwrap_or_default()
+}
+
+/// Text of a file, invalid UTF-8 is replaced and a byte order mark is dropped.
+pub fn decode(bytes: &[u8]) -> String {
+    let text = String::from_utf8_lossy(bytes);
+
+    text.strip_prefix('\u{feff}').unwrap_or(&text).to_string()
+}
+
+/// Value of an integer read from a file, a `Float` when it doesn't fit in an `Integer`.\
+/// `None` when a `Float` can't hold it exactly either, such values are better kept as text than silently rounded.
+pub fn from_integer(value: i64) -> Option<ConnectorType> {
+    match i32::try_from(value) {
+        Ok(value) => Some(ConnectorType::Integer(value)),
+        Err(_) => Some(value as f32).filter(|float| *float as i128 == value as i128).map(ConnectorType::Float),
+    }
+}
+
+/// Column names made usable in a table, empty names become `Column n` and repeated names get a `_n` suffix.
+pub fn get_unique_names(names: &[String]) -> Vec<String> {
+    let mut used = HashSet::new();
+
+    names.iter().enumerate().map(|(index, name)| {
+        let name = match name.trim().is_empty() {
+            true => format!("Column {}", index + 1),
+            false => name.clone(),
+        };
+        let mut unique = name.clone();
+        let mut count = 1;
+
+        while !used.insert(unique.clone()) {
+            count += 1;
+            unique = format!("{}_{}", name, count);
+        }
+
+        unique
+    }).collect()
+}
@@ -0,0 +1,402 @@
+use std::io::Cursor;
+use std::sync::Arc;
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Date32Type, Date64Type, Decimal128Type, Float16Type, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type, TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType, UInt16Type, UInt32Type, UInt64Type, UInt8Type};
+use arrow_array::{Array, ArrayRef, BooleanArray, Float32Array, Int32Array, NullArray, RecordBatch, StringArray, TimestampMillisecondArray};
+use arrow_ipc::reader::{FileReader, StreamReader};
+use arrow_ipc::writer::{FileWriter, StreamWriter};
+use arrow_schema::{DataType, Field, Schema, TimeUnit};
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::datetime::DateTime;
+use crate::nodes::connector_manager::record::Record;
+use crate::nodes::connector_manager::table::{Column, Table};
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+use super::FileState;
+use super::export::{ExportOptions, write_json};
+
+/// Bytes starting an Arrow IPC file, streams start with a message instead.
+const FILE_MAGIC: &[u8] = b"ARROW1";
+
+/// Arrow IPC formats, the file format allows random access to its batches and the stream format is read in order.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ArrowFormat {
+    File,
+    Stream,
+}
+
+/// Values of an array, nulls included, mapped to connector values.\
+/// Integers that don't fit in an `Integer` are read as their decimal text, rather than rounded to a `Float`. Dates and timestamps become `DateTime`, lists `List` and structs `Record`.
+/// Values of types without a connector type, such as binary data or times of day, are `Null`, as are timestamps out of the range of `DateTime`.
+fn get_values(array: &dyn Array) -> Vec<ConnectorType> {
+    macro_rules! primitive {
+        ($type:ty, $convert:expr) => {{
+            let array = array.as_primitive::<$type>();
+
+            (0..array.len()).map(|index| match array.is_null(index) {
+                true => ConnectorType::Null(Null::new()),
+                false => $convert(array.value(index)),
+            }).collect()
+        }};
+    }
+
+    let integer = |value: i64| match i32::try_from(value) {
+        Ok(value) => ConnectorType::Integer(value),
+        Err(_) => ConnectorType::String(value.to_string()),
+    };
+    let date = |milliseconds: i64| ConnectorType::DateTime(DateTime::new(milliseconds));
+
+    match array.data_type() {
+        DataType::Int8 => primitive!(Int8Type, |value| integer(value as i64)),
+        DataType::Int16 => primitive!(Int16Type, |value| integer(value as i64)),
+        DataType::Int32 => primitive!(Int32Type, ConnectorType::Integer),
+        DataType::Int64 => primitive!(Int64Type, integer),
+        DataType::UInt8 => primitive!(UInt8Type, |value| integer(value as i64)),
+        DataType::UInt16 => primitive!(UInt16Type, |value| integer(value as i64)),
+        DataType::UInt32 => primitive!(UInt32Type, |value| integer(value as i64)),
+        DataType::UInt64 => primitive!(UInt64Type, |value: u64| i64::try_from(value).map(integer).unwrap_or(ConnectorType::String(value.to_string()))),
+        DataType::Float16 => primitive!(Float16Type, |value| ConnectorType::Float(f32::from(value))),
+        DataType::Float32 => primitive!(Float32Type, ConnectorType::Float),
+        DataType::Float64 => primitive!(Float64Type, |value| ConnectorType::Float(value as f32)),
+        DataType::Decimal128(_, scale) => {
+            let scale = 10f64.powi(*scale as i32);
+
+            primitive!(Decimal128Type, |value| ConnectorType::Float((value as f64 / scale) as f32))
+        },
+        DataType::Date32 => primitive!(Date32Type, |days| date(days as i64 * 86_400_000)),
+        DataType::Date64 => primitive!(Date64Type, date),
+        DataType::Timestamp(TimeUnit::Second, _) => primitive!(TimestampSecondType, |value: i64| value.checked_mul(1000).map_or(ConnectorType::Null(Null::new()), date)),
+        DataType::Timestamp(TimeUnit::Millisecond, _) => primitive!(TimestampMillisecondType, date),
+        DataType::Timestamp(TimeUnit::Microsecond, _) => primitive!(TimestampMicrosecondType, |value: i64| date(value.div_euclid(1000))),
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => primitive!(TimestampNanosecondType, |value: i64| date(value.div_euclid(1_000_000))),
+        DataType::Boolean => array.as_boolean().iter().map(|value| value.map_or(ConnectorType::Null(Null::new()), ConnectorType::Boolean)).collect(),
+        DataType::Utf8 => array.as_string::<i32>().iter().map(|value| value.map_or(ConnectorType::Null(Null::new()), ConnectorType::from)).collect(),
+        DataType::LargeUtf8 => array.as_string::<i64>().iter().map(|value| value.map_or(ConnectorType::Null(Null::new()), ConnectorType::from)).collect(),
+        DataType::Utf8View => array.as_string_view().iter().map(|value| value.map_or(ConnectorType::Null(Null::new()), ConnectorType::from)).collect(),
+        DataType::List(_) => get_lists(array.as_list::<i32>().iter(), array.len()),
+        DataType::LargeList(_) => get_lists(array.as_list::<i64>().iter(), array.len()),
+        DataType::FixedSizeList(_, _) => get_lists(array.as_fixed_size_list().iter(), array.len()),
+        DataType::Struct(fields) => {
+            let array = array.as_struct();
+            let columns: Vec<Vec<ConnectorType>> = array.columns().iter().map(|column| get_values(column.as_ref())).collect();
+
+            (0..array.len()).map(|index| match array.is_null(index) {
+                true => ConnectorType::Null(Null::new()),
+                false => {
+                    let mut record = Record::new();
+
+                    for (field, column) in fields.iter().zip(&columns) {
+                        record.set_field(field.name(), column[index].clone());
+                    }
+
+                    ConnectorType::Record(record)
+                },
+            }).collect()
+        },
+        DataType::Dictionary(_, _) => {
+            let array = array.as_any_dictionary();
+            let values = get_values(array.values().as_ref());
+
+            array.normalized_keys().into_iter().enumerate().map(|(index, key)| match array.is_null(index) {
+                true => ConnectorType::Null(Null::new()),
+                false => values.get(key).cloned().unwrap_or(ConnectorType::Null(Null::new())),
+            }).collect()
+        },
+        _ => vec![ConnectorType::Null(Null::new()); array.len()],
+    }
+}
+
+fn get_lists(lists: impl Iterator<Item = Option<ArrayRef>>, length: usize) -> Vec<ConnectorType> {
+    let mut values = Vec::with_capacity(length);
+
+    for list in lists {
+        values.push(match list {
+            Some(list) => ConnectorType::List(get_values(list.as_ref())),
+            None => ConnectorType::Null(Null::new()),
+        });
+    }
+
+    values
+}
+
+/// Connector type of a column read from an Arrow type, `ANY` for types whose values can differ.
+fn get_type(data_type: &DataType, values: &[ConnectorType]) -> i32 {
+    match data_type {
+        DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::UInt8 | DataType::UInt16 => ConnectorTypeId::INT,
+        DataType::Int64 | DataType::UInt32 | DataType::UInt64 => match values.iter().all(|value| !matches!(value, ConnectorType::String(_))) {
+            true => ConnectorTypeId::INT,
+            false => ConnectorTypeId::STRING,
+        },
+        DataType::Float16 | DataType::Float32 | DataType::Float64 | DataType::Decimal128(_, _) => ConnectorTypeId::FLOAT,
+        DataType::Date32 | DataType::Date64 | DataType::Timestamp(_, _) => ConnectorTypeId::DATETIME,
+        DataType::Boolean => ConnectorTypeId::BOOL,
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => ConnectorTypeId::STRING,
+        DataType::Struct(_) => ConnectorTypeId::RECORD,
+        DataType::Dictionary(_, value_type) => get_type(value_type, values),
+        _ => ConnectorTypeId::ANY,
+    }
+}
+
+/// Table of record batches sharing a schema, one column per field.\
+/// Values are converted one at a time into connector values, tables don't share the memory of Arrow arrays.
+/// Integer columns with values too large for an `Integer` are `STRING` columns, so no digits are lost.
+pub fn from_record_batches(schema: &Schema, batches: &[RecordBatch]) -> Table {
+    let columns = schema.fields().iter().enumerate().map(|(index, field)| {
+        let values: Vec<ConnectorType> = batches.iter().flat_map(|batch| get_values(batch.column(index).as_ref())).collect();
+        let connector_type = get_type(field.data_type(), &values);
+        let values = match connector_type {
+            ConnectorTypeId::STRING => values.into_iter().map(|value| match value {
+                ConnectorType::Integer(value) => ConnectorType::String(value.to_string()),
+                value => value,
+            }).collect(),
+            _ => values,
+        };
+
+        Column::new(field.name(), connector_type, values)
+    }).collect();
+
+    Table::from_columns(columns).unwrap_or_default()
+}
+
+/// Record batch of a table. Columns of lists, records or mixed values are written as JSON text.
+pub fn to_record_batch(table: &Table) -> Option<RecordBatch> {
+    let mut fields = Vec::new();
+    let mut arrays: Vec<ArrayRef> = Vec::new();
+
+    for column in table.get_columns() {
+        let values = column.get_values();
+        let (data_type, array): (DataType, ArrayRef) = match column.get_type() {
+            ConnectorTypeId::INT => (DataType::Int32, Arc::new(values.iter().map(|value| match value {
+                ConnectorType::Integer(value) => Some(*value),
+                _ => None,
+            }).collect::<Int32Array>())),
+            ConnectorTypeId::FLOAT => (DataType::Float32, Arc::new(values.iter().map(|value| match value {
+                ConnectorType::Float(value) => Some(*value),
+                _ => None,
+            }).collect::<Float32Array>())),
+            ConnectorTypeId::BOOL => (DataType::Boolean, Arc::new(values.iter().map(|value| match value {
+                ConnectorType::Boolean(value) => Some(*value),
+                _ => None,
+            }).collect::<BooleanArray>())),
+            ConnectorTypeId::DATETIME => {
+                let array = values.iter().map(|value| match value {
+                    ConnectorType::DateTime(value) => Some(value.get_milliseconds()),
+                    _ => None,
+                }).collect::<TimestampMillisecondArray>().with_timezone("UTC");
+
+                (array.data_type().clone(), Arc::new(array))
+            },
+            ConnectorTypeId::NULL => (DataType::Null, Arc::new(NullArray::new(values.len()))),
+            connector_type => (DataType::Utf8, Arc::new(values.iter().map(|value| match value {
+                ConnectorType::Null(_) => None,
+                ConnectorType::String(value) => Some(value.clone()),
+                value if connector_type == ConnectorTypeId::STRING => Some(value.to_string()),
+                value => Some(write_json(value, false, &ExportOptions::default())),
+            }).collect::<StringArray>())),
+        };
+
+        fields.push(Field::new(column.get_name(), data_type, true));
+        arrays.push(array);
+    }
+
+    RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays).ok()
+}
+
+/// Reads an Arrow IPC file or stream, the format being detected from its first bytes.
+pub fn read_arrow(bytes: &[u8]) -> Result<Table, String> {
+    match bytes.starts_with(FILE_MAGIC) {
+        true => {
+            let reader = FileReader::try_new(Cursor::new(bytes), None).map_err(|error| error.to_string())?;
+            let schema = reader.schema();
+            let batches = reader.collect::<Result<Vec<RecordBatch>, _>>().map_err(|error| error.to_string())?;
+
+            Ok(from_record_batches(&schema, &batches))
+        },
+        false => {
+            let reader = StreamReader::try_new(Cursor::new(bytes), None).map_err(|error| error.to_string())?;
+            let schema = reader.schema();
+            let batches = reader.collect::<Result<Vec<RecordBatch>, _>>().map_err(|error| error.to_string())?;
+
+            Ok(from_record_batches(&schema, &batches))
+        },
+    }
+}
+
+/// Writes a table as a single record batch in an Arrow IPC format.
+pub fn write_arrow(table: &Table, format: ArrowFormat) -> Result<Vec<u8>, String> {
+    let batch = to_record_batch(table).ok_or("Columns of different lengths")?;
+    let mut bytes = Vec::new();
+
+    match format {
+        ArrowFormat::File => {
+            let mut writer = FileWriter::try_new(&mut bytes, &batch.schema()).map_err(|error| error.to_string())?;
+
+            writer.write(&batch).and_then(|_| writer.finish()).map_err(|error| error.to_string())?;
+        },
+        ArrowFormat::Stream => {
+            let mut writer = StreamWriter::try_new(&mut bytes, &batch.schema()).map_err(|error| error.to_string())?;
+
+            writer.write(&batch).and_then(|_| writer.finish()).map_err(|error| error.to_string())?;
+        },
+    }
+
+    Ok(bytes)
+}
+
+/// Imports an Arrow IPC file or stream as a table, the format being detected from its first bytes.\
+/// `Error` describes why the bytes couldn't be read and is empty otherwise.
+pub struct ArrowImportNode {
+    file: FileState,
+}
+
+impl ArrowImportNode {
+    pub fn new() -> ArrowImportNode {
+        ArrowImportNode {
+            file: FileState::new(),
+        }
+    }
+}
+
+impl Default for ArrowImportNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for ArrowImportNode {
+    fn get_name(&self) -> String {
+        "Arrow Import".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        Vec::new()
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Table", Table::new(), ConnectorTypeId::TABLE),
+            Port::new("Error", "", ConnectorTypeId::STRING),
+        ]
+    }
+
+    fn compute(&self, _: &mut Context) -> Vec<ConnectorType> {
+        let bytes = self.file.get_bytes();
+
+        if bytes.is_empty() {
+            return vec![ConnectorType::Table(Table::new()), ConnectorType::from("")];
+        }
+
+        match read_arrow(&bytes) {
+            Ok(table) => vec![ConnectorType::Table(table), ConnectorType::from("")],
+            Err(error) => vec![ConnectorType::Table(Table::new()), ConnectorType::String(error)],
+        }
+    }
+
+    fn get_state(&self) -> Vec<u8> {
+        self.file.get_state()
+    }
+
+    fn set_state(&self, state: &[u8]) -> bool {
+        self.file.set_state(state)
+    }
+}
+
+#[cfg(test)]
+mod arrow_test {
+    use super::*;
+    use arrow_array::builder::{ListBuilder, StringDictionaryBuilder, Int32Builder};
+    use arrow_array::{Date32Array, Int64Array, StructArray, TimestampSecondArray, UInt64Array};
+    use crate::helper::testing::null;
+    use crate::nodes::node_manager::Manager;
+
+    fn get_table() -> Table {
+        Table::from_columns(vec![
+            Column::new("id", ConnectorTypeId::INT, vec![ConnectorType::new(1), null(), ConnectorType::new(3)]),
+            Column::new("score", ConnectorTypeId::FLOAT, vec![ConnectorType::new(0.5), ConnectorType::new(1.5), null()]),
+            Column::new("name", ConnectorTypeId::STRING, vec![null(), ConnectorType::new("b"), ConnectorType::new("c")]),
+            Column::new("ok", ConnectorTypeId::BOOL, vec![ConnectorType::new(true), ConnectorType::new(false), null()]),
+            Column::new("date", ConnectorTypeId::DATETIME, vec![ConnectorType::DateTime(DateTime::new(86_400_000)), null(), ConnectorType::DateTime(DateTime::new(-1))]),
+        ]).unwrap()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let table = get_table();
+
+        for format in [ArrowFormat::File, ArrowFormat::Stream] {
+            let bytes = write_arrow(&table, format).unwrap();
+
+            assert_eq!(bytes.starts_with(FILE_MAGIC), format == ArrowFormat::File);
+            assert_eq!(read_arrow(&bytes), Ok(table.clone()));
+        }
+
+        let mixed = Table::from_columns(vec![Column::new("value", ConnectorTypeId::ANY, vec![ConnectorType::new(vec![1, 2]), ConnectorType::new("a"), null()])]).unwrap();
+        let read = read_arrow(&write_arrow(&mixed, ArrowFormat::Stream).unwrap()).unwrap();
+
+        assert_eq!(read.get_column("value").unwrap().get_values(), &[ConnectorType::new("[1,2]"), ConnectorType::new("a"), null()]);
+    }
+
+    #[test]
+    fn test_types() {
+        let mut lists = ListBuilder::new(Int32Builder::new());
+
+        lists.append_value([Some(1), None]);
+        lists.append_null();
+        lists.append_value([]);
+
+        let mut dictionary = StringDictionaryBuilder::<Int32Type>::new();
+
+        dictionary.append_value("x");
+        dictionary.append_null();
+        dictionary.append_value("x");
+
+        let point = StructArray::from(vec![
+            (Arc::new(Field::new("x", DataType::Int32, true)), Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+        ]);
+        let batch = RecordBatch::try_from_iter(vec![
+            ("big", Arc::new(Int64Array::from(vec![Some(1), None, Some(5_000_000_000)])) as ArrayRef),
+            ("small", Arc::new(Int64Array::from(vec![1, 2, 3])) as ArrayRef),
+            ("id", Arc::new(UInt64Array::from(vec![1, 2, u64::MAX])) as ArrayRef),
+            ("day", Arc::new(Date32Array::from(vec![1, 0, -1])) as ArrayRef),
+            ("second", Arc::new(TimestampSecondArray::from(vec![1, i64::MAX, i64::MIN])) as ArrayRef),
+            ("list", Arc::new(lists.finish()) as ArrayRef),
+            ("category", Arc::new(dictionary.finish()) as ArrayRef),
+            ("point", Arc::new(point) as ArrayRef),
+        ]).unwrap();
+        let table = from_record_batches(&batch.schema(), &[batch.clone(), batch]);
+        let types: Vec<i32> = table.get_columns().iter().map(|column| column.get_type()).collect();
+
+        assert_eq!(table.get_row_count(), 6);
+        assert_eq!(types, vec![ConnectorTypeId::STRING, ConnectorTypeId::INT, ConnectorTypeId::STRING, ConnectorTypeId::DATETIME, ConnectorTypeId::DATETIME, ConnectorTypeId::ANY, ConnectorTypeId::STRING, ConnectorTypeId::RECORD]);
+        assert_eq!(table.get_column("big").unwrap().get_values()[..3], [ConnectorType::new("1"), null(), ConnectorType::new("5000000000")]);
+        assert_eq!(table.get_column("id").unwrap().get_values()[..3], [ConnectorType::new("1"), ConnectorType::new("2"), ConnectorType::new("18446744073709551615")]);
+        assert_eq!(table.get_column("day").unwrap().get_values()[2], ConnectorType::DateTime(DateTime::new(-86_400_000)));
+        assert_eq!(table.get_column("second").unwrap().get_values()[..3], [ConnectorType::DateTime(DateTime::new(1000)), null(), null()], "Overflowing timestamps should be null");
+        assert_eq!(table.get_column("list").unwrap().get_values()[..3], [ConnectorType::new(vec![ConnectorType::new(1), null()]), null(), ConnectorType::List(Vec::new())]);
+        assert_eq!(table.get_column("category").unwrap().get_values()[..3], [ConnectorType::new("x"), null(), ConnectorType::new("x")]);
+
+        let mut point = Record::new();
+
+        point.set_field("x", 2);
+
+        assert_eq!(table.get_column("point").unwrap().get_values()[1], ConnectorType::Record(point));
+    }
+
+    #[test]
+    fn test_node() {
+        let mut manager = Manager::new();
+        let node = manager.add_node(ArrowImportNode::new());
+
+        manager.set_node_state(node, &write_arrow(&get_table(), ArrowFormat::File).unwrap());
+
+        assert_eq!(manager.evaluate(node), vec![ConnectorType::Table(get_table()), ConnectorType::new("")]);
+
+        manager.set_node_state(node, b"not arrow");
+
+        let outputs = manager.evaluate(node);
+
+        assert_eq!(outputs[0], ConnectorType::Table(Table::new()));
+        assert_ne!(outputs[1], ConnectorType::new(""));
+    }
+}
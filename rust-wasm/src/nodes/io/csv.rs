@@ -0,0 +1,556 @@
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::datetime::DateTime;
+use crate::nodes::connector_manager::table::{Column, Table};
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+use super::{FileState, ImportError, decode, from_integer, get_error_table, get_unique_names};
+
+/// Whether the first record holds the column names.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Header {
+    Present,
+    Absent,
+    Detect,
+}
+
+/// How a CSV file is read.\
+/// Without an escape character, a quote is written inside a quoted field by doubling it.
+/// Unquoted fields equal to one of the null values are `Null`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsvOptions {
+    delimiter: char,
+    quote: Option<char>,
+    escape: Option<char>,
+    header: Header,
+    null_values: Vec<String>,
+}
+
+impl CsvOptions {
+    pub fn new(delimiter: char, quote: Option<char>, escape: Option<char>, header: Header, null_values: Vec<String>) -> CsvOptions {
+        CsvOptions {
+            delimiter,
+            quote,
+            escape,
+            header,
+            null_values,
+        }
+    }
+
+    pub fn get_delimiter(&self) -> char {
+        self.delimiter
+    }
+
+    pub fn get_quote(&self) -> Option<char> {
+        self.quote
+    }
+
+    pub fn get_escape(&self) -> Option<char> {
+        self.escape
+    }
+
+    pub fn get_header(&self) -> Header {
+        self.header
+    }
+
+    pub fn get_null_values(&self) -> &[String] {
+        &self.null_values
+    }
+}
+
+/// Texts read as `Null` by default.
+pub const NULL_VALUES: [&str; 6] = ["", "NA", "N/A", "null", "NULL", "NaN"];
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        let null_values = NULL_VALUES.iter().map(|value| value.to_string()).collect();
+
+        CsvOptions::new(',', Some('"'), None, Header::Detect, null_values)
+    }
+}
+
+/// A field as written in the file, with the position where it starts.
+#[derive(Clone, Debug, PartialEq)]
+struct Field {
+    text: String,
+    quoted: bool,
+    line: usize,
+    column: usize,
+}
+
+/// Characters of the file with the position of the next one.
+struct Cursor {
+    chars: Vec<char>,
+    index: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Cursor {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.index).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let char = self.peek()?;
+
+        self.index += 1;
+
+        // `\r\n` counts as one line break, on its `\n`
+        if char == '\n' || (char == '\r' && self.peek() != Some('\n')) {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+
+        Some(char)
+    }
+
+    fn is_line_end(&self) -> bool {
+        matches!(self.peek(), None | Some('\n') | Some('\r'))
+    }
+}
+
+/// Splits the text into records of fields. Malformed fields are read as well as possible and reported.
+fn parse_records(text: &str, options: &CsvOptions) -> (Vec<Vec<Field>>, Vec<ImportError>) {
+    let mut cursor = Cursor {
+        chars: text.chars().collect(),
+        index: 0,
+        line: 1,
+        column: 1,
+    };
+    let mut records = Vec::new();
+    let mut errors = Vec::new();
+
+    while cursor.peek().is_some() {
+        let mut record = Vec::new();
+
+        loop {
+            let mut field = Field {
+                text: String::new(),
+                quoted: false,
+                line: cursor.line,
+                column: cursor.column,
+            };
+
+            if options.get_quote().is_some() && cursor.peek() == options.get_quote() {
+                field.quoted = true;
+                cursor.next();
+                parse_quoted(&mut cursor, &mut field, options, &mut errors);
+            }
+
+            if !field.quoted || (!cursor.is_line_end() && cursor.peek() != Some(options.get_delimiter())) {
+                if field.quoted {
+                    errors.push(ImportError::new(cursor.line, cursor.column, "unexpected text after closing quote"));
+                }
+
+                while !cursor.is_line_end() && cursor.peek() != Some(options.get_delimiter()) {
+                    field.text.push(cursor.next().unwrap());
+                }
+            }
+
+            record.push(field);
+
+            if cursor.peek() != Some(options.get_delimiter()) {
+                break;
+            }
+
+            cursor.next();
+        }
+
+        if cursor.peek() == Some('\r') {
+            cursor.next();
+        }
+
+        if cursor.peek() == Some('\n') {
+            cursor.next();
+        }
+
+        let blank = record.len() == 1 && !record[0].quoted && record[0].text.trim().is_empty();
+
+        if !blank {
+            records.push(record);
+        }
+    }
+
+    (records, errors)
+}
+
+/// Reads a quoted field after its opening quote, up to and including the closing quote.
+fn parse_quoted(cursor: &mut Cursor, field: &mut Field, options: &CsvOptions, errors: &mut Vec<ImportError>) {
+    let quote = options.get_quote();
+
+    loop {
+        let char = match cursor.peek() {
+            Some(char) => char,
+            None => {
+                errors.push(ImportError::new(field.line, field.column, "unterminated quoted field"));
+                return;
+            },
+        };
+
+        if options.get_escape().is_some() && Some(char) == options.get_escape() && options.get_escape() != quote {
+            cursor.next();
+
+            if let Some(escaped) = cursor.next() {
+                field.text.push(escaped);
+            }
+        } else if Some(char) == quote {
+            cursor.next();
+
+            if (options.get_escape().is_none() || options.get_escape() == quote) && cursor.peek() == quote {
+                field.text.push(cursor.next().unwrap());
+                continue;
+            }
+
+            return;
+        } else {
+            field.text.push(cursor.next().unwrap());
+        }
+    }
+}
+
+fn is_integer(text: &str) -> bool {
+    text.parse::<i32>().is_ok()
+}
+
+fn is_whole(text: &str) -> bool {
+    let digits = text.strip_prefix(['+', '-']).unwrap_or(text);
+
+    !digits.is_empty() && digits.bytes().all(|byte| byte.is_ascii_digit())
+}
+
+fn is_float(text: &str) -> bool {
+    // Whole numbers a float would round are kept as text, so identifiers don't lose digits
+    if is_whole(text) {
+        return text.parse::<i64>().ok().and_then(from_integer).is_some();
+    }
+
+    // Words such as `inf` parse as floats but are kept as text, `NaN` is how a float NaN is written
+    text == "NaN" || text.bytes().any(|byte| byte.is_ascii_digit())
+        && text.bytes().all(|byte| byte.is_ascii_digit() || matches!(byte, b'+' | b'-' | b'.' | b'e' | b'E'))
+        && text.parse::<f32>().is_ok()
+}
+
+fn is_boolean(text: &str) -> bool {
+    text.eq_ignore_ascii_case("true") || text.eq_ignore_ascii_case("false")
+}
+
+/// Narrowest type all the texts can be read as, from integer, float, boolean, date and string.
+pub fn infer_type(texts: &[&str]) -> i32 {
+    let candidates = [
+        (ConnectorTypeId::INT, is_integer as fn(&str) -> bool),
+        (ConnectorTypeId::FLOAT, is_float),
+        (ConnectorTypeId::BOOL, is_boolean),
+        (ConnectorTypeId::DATETIME, |text| DateTime::parse(text).is_some()),
+    ];
+
+    if texts.is_empty() {
+        return ConnectorTypeId::STRING;
+    }
+
+    candidates.iter()
+        .find(|(_, test)| texts.iter().all(|text| test(text.trim())))
+        .map(|(connector_type, _)| *connector_type)
+        .unwrap_or(ConnectorTypeId::STRING)
+}
+
+/// Value of a text read as a type from `infer_type`, `Null` when it can't be.
+pub fn parse_value(text: &str, connector_type: i32) -> ConnectorType {
+    let trimmed = text.trim();
+    let value = match connector_type {
+        ConnectorTypeId::INT => trimmed.parse().ok().map(ConnectorType::Integer),
+        ConnectorTypeId::FLOAT => trimmed.parse().ok().filter(|_| is_float(trimmed)).map(ConnectorType::Float),
+        ConnectorTypeId::BOOL => is_boolean(trimmed).then(|| ConnectorType::Boolean(trimmed.eq_ignore_ascii_case("true"))),
+        ConnectorTypeId::DATETIME => DateTime::parse(trimmed).map(ConnectorType::DateTime),
+        _ => Some(ConnectorType::String(text.to_string())),
+    };
+
+    value.unwrap_or(ConnectorType::Null(Null::new()))
+}
+
+/// Whether the first record names the columns: its fields are distinct and not null,
+/// and it doesn't fit the types of the other records. Files of text only are taken to have a header.
+fn detect_header(records: &[Vec<Field>], options: &CsvOptions) -> bool {
+    let first = match records.first() {
+        Some(first) => first,
+        None => return false,
+    };
+
+    let mut names: Vec<&str> = first.iter().map(|field| field.text.as_str()).collect();
+
+    if names.iter().any(|name| name.trim().is_empty() || options.get_null_values().iter().any(|value| value == name)) {
+        return false;
+    }
+
+    names.sort_unstable();
+    names.dedup();
+
+    if names.len() < first.len() {
+        return false;
+    }
+
+    let mut typed = false;
+
+    for (index, field) in first.iter().enumerate() {
+        let texts = get_texts(&records[1..], index, options);
+        let connector_type = infer_type(&texts);
+
+        if connector_type != ConnectorTypeId::STRING {
+            typed = true;
+
+            if infer_type(&[&field.text]) != connector_type && !(connector_type == ConnectorTypeId::FLOAT && is_integer(field.text.trim())) {
+                return true;
+            }
+        }
+    }
+
+    !typed
+}
+
+/// Texts of a column that aren't null.
+fn get_texts<'a>(records: &'a [Vec<Field>], index: usize, options: &CsvOptions) -> Vec<&'a str> {
+    records.iter()
+        .filter_map(|record| record.get(index))
+        .filter(|field| !is_null(field, options))
+        .map(|field| field.text.as_str())
+        .collect()
+}
+
+fn is_null(field: &Field, options: &CsvOptions) -> bool {
+    !field.quoted && options.get_null_values().iter().any(|value| *value == field.text.trim())
+}
+
+/// Reads a CSV file into a table with a column type inferred from the values of each column.\
+/// Records with missing fields are completed with `Null` and extra fields are dropped, both are reported with the malformed quotes.
+pub fn read_csv(bytes: &[u8], options: &CsvOptions) -> (Table, Vec<ImportError>) {
+    let (records, mut errors) = parse_records(&decode(bytes), options);
+
+    let header = match options.get_header() {
+        Header::Present => !records.is_empty(),
+        Header::Absent => false,
+        Header::Detect => detect_header(&records, options),
+    };
+
+    let names: Vec<String> = match (header, records.first()) {
+        (true, Some(first)) => first.iter().map(|field| field.text.clone()).collect(),
+        (_, Some(first)) => (0..first.len()).map(|index| format!("Column {}", index + 1)).collect(),
+        _ => Vec::new(),
+    };
+    let names = get_unique_names(&names);
+    let body = &records[header as usize..];
+
+    for record in body {
+        let last = record.last().unwrap();
+
+        if record.len() != names.len() {
+            let (line, column) = match record.get(names.len()) {
+                Some(extra) => (extra.line, extra.column),
+                None => (last.line, last.column + last.text.chars().count()),
+            };
+
+            errors.push(ImportError::new(line, column, &format!("expected {} fields, found {}", names.len(), record.len())));
+        }
+    }
+
+    errors.sort_by_key(|error| (error.line, error.column));
+
+    let columns = names.iter().enumerate().map(|(index, name)| {
+        let connector_type = infer_type(&get_texts(body, index, options));
+        let values: Vec<ConnectorType> = body.iter().map(|record| match record.get(index) {
+            Some(field) if !is_null(field, options) => parse_value(&field.text, connector_type),
+            _ => ConnectorType::Null(Null::new()),
+        }).collect();
+
+        Column::new(name, connector_type, values)
+    }).collect();
+
+    (Table::from_columns(columns).unwrap_or_default(), errors)
+}
+
+/// Imports a CSV file as a table, with a column type inferred from the values of each column.\
+/// `Delimiter`, `Quote` and `Escape` use the first character of their text, an empty `Quote` disables quoting
+/// and an empty `Escape` means quotes are doubled. `Header` is detected when `Null`.
+/// `Null Values` lists the texts read as `Null`.
+pub struct CsvImportNode {
+    file: FileState,
+}
+
+impl CsvImportNode {
+    pub fn new() -> CsvImportNode {
+        CsvImportNode {
+            file: FileState::new(),
+        }
+    }
+}
+
+impl Default for CsvImportNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for CsvImportNode {
+    fn get_name(&self) -> String {
+        "CSV Import".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        let defaults = CsvOptions::default();
+
+        vec![
+            Port::new("Delimiter", ",", ConnectorTypeId::STRING),
+            Port::new("Quote", "\"", ConnectorTypeId::STRING),
+            Port::new("Escape", "", ConnectorTypeId::STRING),
+            Port::new("Header", Null::new(), ConnectorTypeId::ANY),
+            Port::new("Null Values", defaults.null_values, ConnectorTypeId::LIST),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Table", Table::new(), ConnectorTypeId::TABLE),
+            Port::new("Errors", get_error_table(&[]), ConnectorTypeId::TABLE),
+        ]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let first = |value: ConnectorType| match value {
+            ConnectorType::String(text) => text.chars().next(),
+            _ => None,
+        };
+
+        let header = match context.get_input(3) {
+            ConnectorType::Boolean(true) => Header::Present,
+            ConnectorType::Boolean(false) => Header::Absent,
+            _ => Header::Detect,
+        };
+        let null_values = match context.get_input(4) {
+            ConnectorType::List(values) => values.iter().map(|value| value.to_string()).collect(),
+            _ => Vec::new(),
+        };
+        let options = CsvOptions::new(first(context.get_input(0)).unwrap_or(','), first(context.get_input(1)), first(context.get_input(2)), header, null_values);
+        let (table, errors) = read_csv(&self.file.get_bytes(), &options);
+
+        vec![ConnectorType::Table(table), ConnectorType::Table(get_error_table(&errors))]
+    }
+
+    fn get_state(&self) -> Vec<u8> {
+        self.file.get_state()
+    }
+
+    fn set_state(&self, state: &[u8]) -> bool {
+        self.file.set_state(state)
+    }
+}
+
+#[cfg(test)]
+mod csv_test {
+    use super::*;
+    use crate::nodes::node_manager::Manager;
+
+    fn read(text: &str, options: &CsvOptions) -> (Table, Vec<ImportError>) {
+        read_csv(text.as_bytes(), options)
+    }
+
+    #[test]
+    fn test_types() {
+        let text = "\u{feff}id,score,ok,date,name\n1,2.5,true,2024-03-01,a\r\n2,3,FALSE,2024-03-02 10:00,\"b, c\"\n3,NA,true,,\"say \"\"hi\"\"\"\n";
+        let (table, errors) = read(text, &CsvOptions::default());
+
+        assert!(errors.is_empty());
+        assert_eq!(table.get_column_names(), vec!["id", "score", "ok", "date", "name"]);
+
+        let types: Vec<i32> = table.get_columns().iter().map(|column| column.get_type()).collect();
+
+        assert_eq!(types, vec![ConnectorTypeId::INT, ConnectorTypeId::FLOAT, ConnectorTypeId::BOOL, ConnectorTypeId::DATETIME, ConnectorTypeId::STRING]);
+        assert_eq!(table.get_column("score").unwrap().get_values()[1], 3.0);
+        assert!(table.get_column("score").unwrap().get_values()[2].is_null());
+        assert_eq!(table.get_column("ok").unwrap().get_values()[1], false);
+        assert_eq!(table.get_column("date").unwrap().get_values()[0], ConnectorType::DateTime(DateTime::from_parts(2024, 3, 1, 0, 0, 0, 0).unwrap()));
+        assert_eq!(table.get_column("name").unwrap().get_values()[1], "b, c");
+        assert_eq!(table.get_column("name").unwrap().get_values()[2], "say \"hi\"");
+    }
+
+    #[test]
+    fn test_large_integers() {
+        assert_eq!(infer_type(&["1", "5000000000"]), ConnectorTypeId::FLOAT);
+        assert_eq!(infer_type(&["1", "12345678901"]), ConnectorTypeId::STRING);
+        assert_eq!(infer_type(&["1.5", "-99999999999999999999"]), ConnectorTypeId::STRING);
+
+        let (table, _) = read("id\n12345678901\n12345678902\n", &CsvOptions::default());
+
+        assert_eq!(table.get_column("id").unwrap().get_values(), &[ConnectorType::new("12345678901"), ConnectorType::new("12345678902")]);
+    }
+
+    #[test]
+    fn test_header() {
+        let (table, _) = read("1,2\n3,4\n", &CsvOptions::default());
+
+        assert_eq!(table.get_column_names(), vec!["Column 1", "Column 2"]);
+        assert_eq!(table.get_row_count(), 2);
+
+        let (table, _) = read("a;b;a\nx;y;z\n", &CsvOptions::new(';', Some('"'), None, Header::Present, Vec::new()));
+
+        assert_eq!(table.get_column_names(), vec!["a", "b", "a_2"]);
+        assert_eq!(table.get_row_count(), 1);
+
+        let (table, _) = read("x,y\n", &CsvOptions::new(',', Some('"'), None, Header::Absent, Vec::new()));
+
+        assert_eq!(table.get_row_count(), 1);
+    }
+
+    #[test]
+    fn test_options() {
+        let options = CsvOptions::new('\t', Some('\''), Some('\\'), Header::Present, vec!["-".to_string()]);
+        let (table, errors) = read("a\tb\n'it\\'s\tok'\t-\n'-'\t'multi\nline'\n", &options);
+
+        assert!(errors.is_empty());
+        assert_eq!(table.get_column("a").unwrap().get_values(), &[ConnectorType::new("it's\tok"), ConnectorType::new("-")]);
+        assert_eq!(table.get_column("b").unwrap().get_values(), &[ConnectorType::Null(Null::new()), ConnectorType::new("multi\nline")]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let (table, errors) = read("a,b\n1,2,3\n4\n\"5\"x,6\n\"7,8\n", &CsvOptions::default());
+        let errors: Vec<String> = errors.iter().map(|error| error.to_string()).collect();
+
+        assert_eq!(errors, vec![
+            "line 2, column 5: expected 2 fields, found 3",
+            "line 3, column 2: expected 2 fields, found 1",
+            "line 4, column 4: unexpected text after closing quote",
+            "line 5, column 1: unterminated quoted field",
+            "line 5, column 5: expected 2 fields, found 1",
+        ]);
+        assert_eq!(table.get_row_count(), 4);
+        assert_eq!(table.get_column("a").unwrap().get_type(), ConnectorTypeId::STRING);
+        assert!(table.get_column("b").unwrap().get_values()[1].is_null());
+    }
+
+    #[test]
+    fn test_node() {
+        let mut manager = Manager::new();
+        let node = manager.add_node(CsvImportNode::new());
+
+        assert!(manager.set_node_state(node, b"name,value\nx,1\ny,\n"));
+        assert_eq!(manager.get_node_state(node), b"name,value\nx,1\ny,\n");
+
+        let outputs = manager.evaluate(node);
+
+        match &outputs[0] {
+            ConnectorType::Table(table) => {
+                assert_eq!(table.get_column("value").unwrap().get_values(), &[ConnectorType::new(1), ConnectorType::Null(Null::new())]);
+            },
+            value => panic!("Expected a table, found {:?}", value),
+        }
+
+        manager.set_input_value(node, 0, ";");
+        manager.set_input_value(node, 3, false);
+
+        match &manager.evaluate(node)[0] {
+            ConnectorType::Table(table) => assert_eq!(table.get_column_names(), vec!["Column 1"]),
+            value => panic!("Expected a table, found {:?}", value),
+        }
+    }
+}
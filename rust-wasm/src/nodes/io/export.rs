@@ -0,0 +1,326 @@
+use crate::nodes::connector_manager::table::{Column, Table};
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::node_manager::Manager;
+use crate::nodes::text::get_common_type;
+use super::arrow::{ArrowFormat, write_arrow};
+use super::csv::NULL_VALUES;
+use super::json::to_table;
+
+/// File formats values are exported to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+    /// One JSON document per row or item, as in NDJSON.
+    Lines,
+    /// Arrow IPC file holding the rows.
+    Arrow,
+    /// Arrow IPC stream holding the rows.
+    ArrowStream,
+}
+
+/// How values are written.\
+/// The delimiter separates CSV fields and the null value is the CSV text of `Null`, which is `null` in JSON.
+/// With decimals, floats are written with that many digits after the decimal point, otherwise as short as they can be read back.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExportOptions {
+    delimiter: char,
+    null_value: String,
+    decimals: Option<usize>,
+}
+
+impl ExportOptions {
+    pub fn new(delimiter: char, null_value: &str, decimals: Option<usize>) -> ExportOptions {
+        ExportOptions {
+            delimiter,
+            null_value: null_value.to_string(),
+            decimals,
+        }
+    }
+
+    pub fn get_delimiter(&self) -> char {
+        self.delimiter
+    }
+
+    pub fn get_null_value(&self) -> String {
+        self.null_value.clone()
+    }
+
+    pub fn get_decimals(&self) -> Option<usize> {
+        self.decimals
+    }
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        ExportOptions::new(',', "", None)
+    }
+}
+
+fn format_float(value: f32, options: &ExportOptions) -> String {
+    match options.get_decimals() {
+        Some(decimals) => format!("{:.*}", decimals, value),
+        None => value.to_string(),
+    }
+}
+
+/// Table of the rows of a value: tables as they are, lists of records with a column per field,
+/// other lists with a `Value` column and a record as a single row.
+fn get_table(value: &ConnectorType) -> Table {
+    match value {
+        ConnectorType::Table(table) => table.clone(),
+        ConnectorType::List(values) if values.iter().any(|value| matches!(value, ConnectorType::Record(_))) => to_table(values, None),
+        ConnectorType::List(values) => match get_common_type(values) {
+            ConnectorTypeId::NULL => Table::from(values.clone()),
+            connector_type => Table::from_columns(vec![Column::new("Value", connector_type, values.clone())]).unwrap_or_default(),
+        },
+        ConnectorType::Record(record) => Table::from(record.clone()),
+        value => Table::from(vec![value.clone()]),
+    }
+}
+
+/// Field of a CSV file, quoted when it holds the delimiter, a quote, a line break or surrounding spaces,
+/// or could be read back as `Null`, with the null value or one of the texts read as `Null` by default.
+fn write_csv_field(text: &str, null: bool, options: &ExportOptions, output: &mut String) {
+    let quoted = !null && (text.trim() == options.get_null_value()
+        || NULL_VALUES.contains(&text.trim())
+        || text.starts_with(' ')
+        || text.ends_with(' ')
+        || text.contains([options.get_delimiter(), '"', '\n', '\r']));
+
+    match quoted {
+        true => {
+            output.push('"');
+            output.push_str(&text.replace('"', "\"\""));
+            output.push('"');
+        },
+        false => output.push_str(text),
+    }
+}
+
+/// Writes the rows of a value as CSV with a header line, lists and records in cells are written as JSON.
+pub fn write_csv(value: &ConnectorType, options: &ExportOptions) -> String {
+    let table = get_table(value);
+    let mut output = String::new();
+    let delimiter = options.get_delimiter().to_string();
+
+    for (index, name) in table.get_column_names().iter().enumerate() {
+        if index > 0 {
+            output.push_str(&delimiter);
+        }
+
+        write_csv_field(name, false, options, &mut output);
+    }
+
+    output.push('\n');
+
+    for row in table.get_rows() {
+        for (index, value) in row.iter().enumerate() {
+            if index > 0 {
+                output.push_str(&delimiter);
+            }
+
+            let text = match value {
+                ConnectorType::Null(_) => options.get_null_value(),
+                ConnectorType::Float(value) => format_float(*value, options),
+                ConnectorType::List(_) | ConnectorType::Record(_) | ConnectorType::Table(_) => {
+                    let mut json = String::new();
+
+                    write_json_value(value, options, &mut json);
+                    json
+                },
+                value => value.to_string(),
+            };
+
+            write_csv_field(&text, value.is_null(), options, &mut output);
+        }
+
+        output.push('\n');
+    }
+
+    output
+}
+
+fn write_json_string(text: &str, output: &mut String) {
+    output.push('"');
+
+    for char in text.chars() {
+        match char {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            char if (char as u32) < 0x20 => output.push_str(&format!("\\u{:04x}", char as u32)),
+            char => output.push(char),
+        }
+    }
+
+    output.push('"');
+}
+
+fn write_json_record<'a>(fields: impl Iterator<Item = (String, &'a ConnectorType)>, options: &ExportOptions, output: &mut String) {
+    output.push('{');
+
+    for (index, (name, value)) in fields.enumerate() {
+        if index > 0 {
+            output.push(',');
+        }
+
+        write_json_string(&name, output);
+        output.push(':');
+        write_json_value(value, options, output);
+    }
+
+    output.push('}');
+}
+
+/// Writes a value as JSON: records are objects, tables arrays of objects and dates ISO 8601 strings.
+/// Floats that aren't finite are `null`.
+fn write_json_value(value: &ConnectorType, options: &ExportOptions, output: &mut String) {
+    match value {
+        ConnectorType::Integer(value) => output.push_str(&value.to_string()),
+        ConnectorType::Float(value) if value.is_finite() => output.push_str(&format_float(*value, options)),
+        ConnectorType::Float(_) | ConnectorType::Null(_) => output.push_str("null"),
+        ConnectorType::Boolean(value) => output.push_str(&value.to_string()),
+        ConnectorType::String(value) => write_json_string(value, output),
+        ConnectorType::DateTime(value) => write_json_string(&value.to_string(), output),
+        ConnectorType::List(values) => {
+            output.push('[');
+
+            for (index, value) in values.iter().enumerate() {
+                if index > 0 {
+                    output.push(',');
+                }
+
+                write_json_value(value, options, output);
+            }
+
+            output.push(']');
+        },
+        ConnectorType::Record(record) => write_json_record(record.get_fields().iter().map(|(name, value)| (name.clone(), value)), options, output),
+        ConnectorType::Table(table) => {
+            output.push('[');
+
+            for row in 0..table.get_row_count() {
+                if row > 0 {
+                    output.push(',');
+                }
+
+                write_json_record(table.get_columns().iter().map(|column| (column.get_name(), &column.get_values()[row])), options, output);
+            }
+
+            output.push(']');
+        },
+    }
+}
+
+/// Writes a value as a JSON document, or with `lines` a document per table row or list item.
+pub fn write_json(value: &ConnectorType, lines: bool, options: &ExportOptions) -> String {
+    let mut output = String::new();
+
+    match (value, lines) {
+        (ConnectorType::Table(table), true) => {
+            for row in 0..table.get_row_count() {
+                write_json_record(table.get_columns().iter().map(|column| (column.get_name(), &column.get_values()[row])), options, &mut output);
+                output.push('\n');
+            }
+        },
+        (ConnectorType::List(values), true) => {
+            for value in values {
+                write_json_value(value, options, &mut output);
+                output.push('\n');
+            }
+        },
+        (value, _) => write_json_value(value, options, &mut output),
+    }
+
+    output
+}
+
+/// Bytes of a file holding the value, text formats being UTF-8 encoded.
+pub fn export(value: &ConnectorType, format: ExportFormat, options: &ExportOptions) -> Vec<u8> {
+    match format {
+        ExportFormat::Csv => write_csv(value, options).into_bytes(),
+        ExportFormat::Json => write_json(value, false, options).into_bytes(),
+        ExportFormat::Lines => write_json(value, true, options).into_bytes(),
+        ExportFormat::Arrow => write_arrow(&get_table(value), ArrowFormat::File).unwrap_or_default(),
+        ExportFormat::ArrowStream => write_arrow(&get_table(value), ArrowFormat::Stream).unwrap_or_default(),
+    }
+}
+
+/// Bytes of a file holding the last computed value of an output, to be downloaded.
+pub fn export_output(manager: &Manager, node: usize, slot: usize, format: ExportFormat, options: &ExportOptions) -> Vec<u8> {
+    export(&manager.get_output(node, slot), format, options)
+}
+
+#[cfg(test)]
+mod export_test {
+    use super::*;
+    use crate::helper::types::Null;
+    use crate::nodes::connector_manager::datetime::DateTime;
+    use crate::nodes::connector_manager::record::Record;
+    use crate::nodes::io::csv::{CsvOptions, read_csv};
+    use crate::nodes::io::json::parse_json;
+
+    fn get_table() -> ConnectorType {
+        ConnectorType::Table(Table::from_columns(vec![
+            Column::new("name", ConnectorTypeId::STRING, vec![ConnectorType::new("a, \"b\""), ConnectorType::new(""), ConnectorType::new(" c")]),
+            Column::new("score", ConnectorTypeId::FLOAT, vec![ConnectorType::new(1.25), ConnectorType::Null(Null::new()), ConnectorType::new(f32::NAN)]),
+            Column::new("date", ConnectorTypeId::DATETIME, vec![ConnectorType::Null(Null::new()), ConnectorType::DateTime(DateTime::new(0)), ConnectorType::Null(Null::new())]),
+            Column::new("tags", ConnectorTypeId::ANY, vec![ConnectorType::new(vec![1, 2]), ConnectorType::Null(Null::new()), ConnectorType::new(true)]),
+        ]).unwrap())
+    }
+
+    #[test]
+    fn test_csv() {
+        let table = get_table();
+
+        assert_eq!(write_csv(&table, &ExportOptions::default()), "name,score,date,tags\n\"a, \"\"b\"\"\",1.25,,\"[1,2]\"\n\"\",,1970-01-01T00:00:00Z,\n\" c\",\"NaN\",,true\n");
+        assert_eq!(write_csv(&table, &ExportOptions::new(';', "NA", Some(3))), "name;score;date;tags\n\"a, \"\"b\"\"\";1.250;NA;[1,2]\n\"\";NA;1970-01-01T00:00:00Z;NA\n\" c\";\"NaN\";NA;true\n");
+        assert_eq!(write_csv(&ConnectorType::new(vec![1, 2]), &ExportOptions::default()), "Value\n1\n2\n");
+
+        let (read, errors) = read_csv(write_csv(&table, &ExportOptions::default()).as_bytes(), &CsvOptions::default());
+
+        assert!(errors.is_empty());
+        assert_eq!(read.get_column("name"), match &table {
+            ConnectorType::Table(table) => table.get_column("name"),
+            _ => None,
+        });
+        assert!(matches!(read.get_column("score").unwrap().get_values()[2], ConnectorType::Float(value) if value.is_nan()));
+
+        let texts = ConnectorType::new(vec!["NA", "N/A", "null", "NULL", "NaN", "", " NA", "\tnull"]);
+
+        for options in [ExportOptions::default(), ExportOptions::new(',', "-", None)] {
+            let (read, errors) = read_csv(write_csv(&texts, &options).as_bytes(), &CsvOptions::default());
+
+            assert!(errors.is_empty());
+            assert_eq!(ConnectorType::List(read.get_column("Value").unwrap().get_values().to_vec()), texts, "Texts read as null should be quoted");
+        }
+    }
+
+    #[test]
+    fn test_json() {
+        let table = get_table();
+        let json = write_json(&table, false, &ExportOptions::default());
+
+        assert_eq!(json, "[{\"name\":\"a, \\\"b\\\"\",\"score\":1.25,\"date\":null,\"tags\":[1,2]},{\"name\":\"\",\"score\":null,\"date\":\"1970-01-01T00:00:00Z\",\"tags\":null},{\"name\":\" c\",\"score\":null,\"date\":null,\"tags\":true}]");
+        assert!(matches!(parse_json(&json), Ok(ConnectorType::List(rows)) if rows.len() == 3));
+
+        let mut record = Record::new();
+
+        record.set_field("text", "line\nbreak\u{1}");
+        record.set_field("value", 2.0);
+
+        assert_eq!(write_json(&ConnectorType::Record(record), false, &ExportOptions::new(',', "", Some(2))), "{\"text\":\"line\\nbreak\\u0001\",\"value\":2.00}");
+        assert_eq!(write_json(&ConnectorType::new(vec![1, 2]), true, &ExportOptions::default()), "1\n2\n");
+        assert_eq!(String::from_utf8(export(&table, ExportFormat::Lines, &ExportOptions::default())).unwrap().lines().count(), 3);
+    }
+
+    #[test]
+    fn test_arrow() {
+        let bytes = export(&ConnectorType::new(vec![1, 2]), ExportFormat::Arrow, &ExportOptions::default());
+
+        assert_eq!(crate::nodes::io::arrow::read_arrow(&bytes).map(|table| table.get_column("Value").unwrap().get_type()), Ok(ConnectorTypeId::INT));
+    }
+}
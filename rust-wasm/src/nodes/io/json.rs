@@ -0,0 +1,637 @@
+use std::collections::HashMap;
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::record::Record;
+use crate::nodes::connector_manager::table::{Column, Table};
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+use crate::nodes::text::get_common_type;
+use super::{FileState, ImportError, decode, from_integer, get_error_table, get_unique_names};
+
+/// Deepest nesting of arrays and objects read, deeper documents are rejected instead of overflowing the stack.
+const MAX_DEPTH: usize = 256;
+
+/// Layout of a JSON file.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum JsonFormat {
+    /// A single document.
+    Document,
+    /// One document per line, as in NDJSON.
+    Lines,
+    /// A document when the whole file parses as one, lines otherwise.
+    Detect,
+}
+
+/// Characters of a document with the position of the next one.
+struct Parser {
+    chars: Vec<char>,
+    index: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Parser {
+    fn new(text: &str, line: usize) -> Parser {
+        Parser {
+            chars: text.chars().collect(),
+            index: 0,
+            line,
+            column: 1,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.index).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let char = self.peek()?;
+
+        self.index += 1;
+
+        match char {
+            '\n' => {
+                self.line += 1;
+                self.column = 1;
+            },
+            _ => self.column += 1,
+        }
+
+        Some(char)
+    }
+
+    fn error(&self, message: &str) -> ImportError {
+        ImportError::new(self.line, self.column, message)
+    }
+
+    fn unexpected(&self) -> ImportError {
+        match self.peek() {
+            Some(char) => self.error(&format!("unexpected '{}'", char)),
+            None => self.error("unexpected end of document"),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(' ' | '\t' | '\n' | '\r')) {
+            self.next();
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ImportError> {
+        self.skip_whitespace();
+
+        match self.peek() == Some(expected) {
+            true => {
+                self.next();
+                Ok(())
+            },
+            false => Err(self.unexpected()),
+        }
+    }
+
+    fn parse_document(&mut self) -> Result<ConnectorType, ImportError> {
+        let value = self.parse_value(0)?;
+
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some(_) => Err(self.unexpected()),
+            None => Ok(value),
+        }
+    }
+
+    fn parse_value(&mut self, depth: usize) -> Result<ConnectorType, ImportError> {
+        self.skip_whitespace();
+
+        if depth > MAX_DEPTH {
+            return Err(self.error("document nested too deeply"));
+        }
+
+        match self.peek() {
+            Some('{') => self.parse_object(depth),
+            Some('[') => self.parse_array(depth),
+            Some('"') => self.parse_string().map(ConnectorType::String),
+            Some('-' | '0'..='9') => self.parse_number(),
+            Some(char) if char.is_ascii_alphabetic() => {
+                let (line, column) = (self.line, self.column);
+                let mut word = String::new();
+
+                while let Some(char) = self.peek().filter(|char| char.is_ascii_alphanumeric()) {
+                    word.push(char);
+                    self.next();
+                }
+
+                match word.as_str() {
+                    "true" => Ok(ConnectorType::Boolean(true)),
+                    "false" => Ok(ConnectorType::Boolean(false)),
+                    "null" => Ok(ConnectorType::Null(Null::new())),
+                    _ => Err(ImportError::new(line, column, &format!("unexpected '{}'", word))),
+                }
+            },
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn parse_object(&mut self, depth: usize) -> Result<ConnectorType, ImportError> {
+        let mut record = Record::new();
+
+        self.next();
+        self.skip_whitespace();
+
+        if self.peek() == Some('}') {
+            self.next();
+            return Ok(ConnectorType::Record(record));
+        }
+
+        loop {
+            self.skip_whitespace();
+
+            if self.peek() != Some('"') {
+                return Err(self.unexpected());
+            }
+
+            let name = self.parse_string()?;
+
+            self.expect(':')?;
+            record.set_field(&name, self.parse_value(depth + 1)?);
+            self.skip_whitespace();
+
+            match self.peek() {
+                Some(',') => {
+                    self.next();
+                },
+                Some('}') => {
+                    self.next();
+                    return Ok(ConnectorType::Record(record));
+                },
+                _ => return Err(self.unexpected()),
+            }
+        }
+    }
+
+    fn parse_array(&mut self, depth: usize) -> Result<ConnectorType, ImportError> {
+        let mut values = Vec::new();
+
+        self.next();
+        self.skip_whitespace();
+
+        if self.peek() == Some(']') {
+            self.next();
+            return Ok(ConnectorType::List(values));
+        }
+
+        loop {
+            values.push(self.parse_value(depth + 1)?);
+            self.skip_whitespace();
+
+            match self.peek() {
+                Some(',') => {
+                    self.next();
+                },
+                Some(']') => {
+                    self.next();
+                    return Ok(ConnectorType::List(values));
+                },
+                _ => return Err(self.unexpected()),
+            }
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, ImportError> {
+        let (line, column) = (self.line, self.column);
+        let mut text = String::new();
+
+        self.next();
+
+        loop {
+            match self.next() {
+                Some('"') => return Ok(text),
+                Some('\\') => {
+                    let char = match self.next() {
+                        Some('"') => '"',
+                        Some('\\') => '\\',
+                        Some('/') => '/',
+                        Some('b') => '\u{8}',
+                        Some('f') => '\u{c}',
+                        Some('n') => '\n',
+                        Some('r') => '\r',
+                        Some('t') => '\t',
+                        Some('u') => self.parse_unicode()?,
+                        _ => return Err(ImportError::new(self.line, self.column - 1, "invalid escape sequence")),
+                    };
+
+                    text.push(char);
+                },
+                Some(char) if (char as u32) < 0x20 => return Err(ImportError::new(self.line, self.column - 1, "control character in string")),
+                Some(char) => text.push(char),
+                None => return Err(ImportError::new(line, column, "unterminated string")),
+            }
+        }
+    }
+
+    /// Character of a `\u` escape, combining surrogate pairs. Lone surrogates become the replacement character.
+    fn parse_unicode(&mut self) -> Result<char, ImportError> {
+        let first = self.parse_hex()?;
+
+        if !(0xd800..0xdc00).contains(&first) {
+            return Ok(char::from_u32(first).unwrap_or(char::REPLACEMENT_CHARACTER));
+        }
+
+        if self.chars.get(self.index..self.index + 2) != Some(&['\\', 'u']) {
+            return Ok(char::REPLACEMENT_CHARACTER);
+        }
+
+        self.next();
+        self.next();
+
+        let second = self.parse_hex()?;
+
+        match (0xdc00..0xe000).contains(&second) {
+            true => Ok(char::from_u32(0x10000 + ((first - 0xd800) << 10) + (second - 0xdc00)).unwrap_or(char::REPLACEMENT_CHARACTER)),
+            false => Ok(char::REPLACEMENT_CHARACTER),
+        }
+    }
+
+    fn parse_hex(&mut self) -> Result<u32, ImportError> {
+        let mut code = 0;
+
+        for _ in 0..4 {
+            match self.peek().and_then(|char| char.to_digit(16)) {
+                Some(digit) => {
+                    code = code * 16 + digit;
+                    self.next();
+                },
+                None => return Err(self.error("invalid unicode escape")),
+            }
+        }
+
+        Ok(code)
+    }
+
+    /// Integers that fit are `Integer`, other numbers `Float`.
+    fn parse_number(&mut self) -> Result<ConnectorType, ImportError> {
+        let (line, column) = (self.line, self.column);
+        let start = self.index;
+        let mut integer = true;
+
+        if self.peek() == Some('-') {
+            self.next();
+        }
+
+        let digits = |parser: &mut Parser| {
+            let start = parser.index;
+
+            while parser.peek().is_some_and(|char| char.is_ascii_digit()) {
+                parser.next();
+            }
+
+            parser.index - start
+        };
+
+        let leading_zero = self.peek() == Some('0');
+        let count = digits(self);
+
+        if count == 0 || (leading_zero && count > 1) {
+            return Err(ImportError::new(line, column, "invalid number"));
+        }
+
+        if self.peek() == Some('.') {
+            integer = false;
+            self.next();
+
+            if digits(self) == 0 {
+                return Err(ImportError::new(line, column, "invalid number"));
+            }
+        }
+
+        if matches!(self.peek(), Some('e' | 'E')) {
+            integer = false;
+            self.next();
+
+            if matches!(self.peek(), Some('+' | '-')) {
+                self.next();
+            }
+
+            if digits(self) == 0 {
+                return Err(ImportError::new(line, column, "invalid number"));
+            }
+        }
+
+        let text: String = self.chars[start..self.index].iter().collect();
+
+        match integer {
+            true => Ok(text.parse().ok().and_then(from_integer).unwrap_or(ConnectorType::String(text))),
+            false => Ok(ConnectorType::Float(text.parse().unwrap_or(f32::NAN))),
+        }
+    }
+}
+
+/// Parses a JSON document: objects become records, arrays lists and numbers integers when they fit in one.\
+/// Whole numbers a float would round are kept as their text.
+pub fn parse_json(text: &str) -> Result<ConnectorType, ImportError> {
+    Parser::new(text, 1).parse_document()
+}
+
+/// Parses one document per line, skipping blank lines. Lines that fail to parse are left out and reported.
+pub fn parse_json_lines(text: &str) -> (Vec<ConnectorType>, Vec<ImportError>) {
+    let mut values = Vec::new();
+    let mut errors = Vec::new();
+
+    for (index, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        match Parser::new(line, index + 1).parse_document() {
+            Ok(value) => values.push(value),
+            Err(error) => errors.push(error),
+        }
+    }
+
+    (values, errors)
+}
+
+/// Fields of a record, with the fields of nested records joined to their parent's name by the separator.
+fn flatten(record: &Record, separator: &str, prefix: &str, fields: &mut Vec<(String, ConnectorType)>) {
+    for (name, value) in record.get_fields() {
+        let name = format!("{}{}", prefix, name);
+
+        match value {
+            ConnectorType::Record(nested) if !nested.is_empty() => flatten(nested, separator, &format!("{}{}", name, separator), fields),
+            value => fields.push((name, value.clone())),
+        }
+    }
+}
+
+/// Table with a row per value, records give a column per field and other values a `Value` column.\
+/// With a separator, nested records are flattened into columns such as `address.city`, otherwise they are kept as record values.
+/// A flattened name repeated in a row gets a `_n` suffix, so `{"a.b": 1, "a": {"b": 2}}` gives `a.b` and `a.b_2`.
+/// Columns have the type their values share, integers mixed with floats being floats.
+pub fn to_table(values: &[ConnectorType], separator: Option<&str>) -> Table {
+    let rows: Vec<Vec<(String, ConnectorType)>> = values.iter().map(|value| match (value, separator) {
+        (ConnectorType::Record(record), Some(separator)) => {
+            let mut fields = Vec::new();
+
+            flatten(record, separator, "", &mut fields);
+            fields
+        },
+        (ConnectorType::Record(record), None) => record.get_fields().to_vec(),
+        (value, _) => vec![("Value".to_string(), value.clone())],
+    }).collect();
+
+    let mut names: Vec<String> = Vec::new();
+    let mut columns: Vec<Vec<ConnectorType>> = Vec::new();
+    let mut indexes: HashMap<(&str, usize), usize> = HashMap::new();
+
+    for (row, fields) in rows.iter().enumerate() {
+        // Fields repeating a name in a row, such as `a.b` next to `b` nested in `a`, are told apart by their occurrence
+        let mut occurrences: HashMap<&str, usize> = HashMap::new();
+
+        for (name, value) in fields {
+            let occurrence = occurrences.entry(name).or_insert(0);
+            let index = *indexes.entry((name, *occurrence)).or_insert_with(|| {
+                names.push(match *occurrence {
+                    0 => name.clone(),
+                    occurrence => format!("{}_{}", name, occurrence + 1),
+                });
+                columns.push(vec![ConnectorType::Null(Null::new()); rows.len()]);
+                columns.len() - 1
+            });
+
+            columns[index][row] = value.clone();
+            *occurrence += 1;
+        }
+    }
+
+    let columns = get_unique_names(&names).iter().zip(columns).map(|(unique, values)| {
+        let numbers = values.iter().filter(|value| !value.is_null()).all(|value| matches!(value, ConnectorType::Integer(_) | ConnectorType::Float(_)));
+        let connector_type = match get_common_type(&values) {
+            ConnectorTypeId::ANY if numbers => ConnectorTypeId::FLOAT,
+            ConnectorTypeId::NULL => ConnectorTypeId::ANY,
+            connector_type => connector_type,
+        };
+
+        Column::new(unique, connector_type, values)
+    }).collect();
+
+    Table::from_columns(columns).unwrap_or_default()
+}
+
+/// Reads a JSON file into its value and a table of its rows.\
+/// The rows of a document are the items of an array, or the document itself, and the rows of lines are their documents.
+/// The value of lines is the list of their documents.
+pub fn read_json(bytes: &[u8], format: JsonFormat, separator: Option<&str>) -> (ConnectorType, Table, Vec<ImportError>) {
+    let text = decode(bytes);
+    let lines = || {
+        let (values, errors) = parse_json_lines(&text);
+
+        (ConnectorType::List(values), errors)
+    };
+
+    let (value, errors) = match format {
+        JsonFormat::Document => match parse_json(&text) {
+            Ok(value) => (value, Vec::new()),
+            Err(error) => (ConnectorType::Null(Null::new()), vec![error]),
+        },
+        JsonFormat::Lines => lines(),
+        JsonFormat::Detect => match parse_json(&text) {
+            Ok(value) => (value, Vec::new()),
+            Err(error) if text.trim().lines().count() <= 1 => (ConnectorType::Null(Null::new()), vec![error]),
+            Err(_) => lines(),
+        },
+    };
+
+    let table = match &value {
+        ConnectorType::List(values) => to_table(values, separator),
+        ConnectorType::Null(_) => Table::new(),
+        value => to_table(std::slice::from_ref(value), separator),
+    };
+
+    (value, table, errors)
+}
+
+/// Imports a JSON file both as the `Value` it holds and as a `Table` of its rows.\
+/// `Lines` reads one document per line when true and is detected when `Null`.
+/// Nested objects are flattened into columns named with the `Separator` when `Flatten` is true.
+pub struct JsonImportNode {
+    file: FileState,
+}
+
+impl JsonImportNode {
+    pub fn new() -> JsonImportNode {
+        JsonImportNode {
+            file: FileState::new(),
+        }
+    }
+}
+
+impl Default for JsonImportNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for JsonImportNode {
+    fn get_name(&self) -> String {
+        "JSON Import".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Lines", Null::new(), ConnectorTypeId::ANY),
+            Port::new("Flatten", true, ConnectorTypeId::BOOL),
+            Port::new("Separator", ".", ConnectorTypeId::STRING),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Table", Table::new(), ConnectorTypeId::TABLE),
+            Port::new("Value", Null::new(), ConnectorTypeId::ANY),
+            Port::new("Errors", get_error_table(&[]), ConnectorTypeId::TABLE),
+        ]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let format = match context.get_input(0) {
+            ConnectorType::Boolean(true) => JsonFormat::Lines,
+            ConnectorType::Boolean(false) => JsonFormat::Document,
+            _ => JsonFormat::Detect,
+        };
+        let separator = match (context.get_input(1), context.get_input(2)) {
+            (ConnectorType::Boolean(true), ConnectorType::String(separator)) => Some(separator),
+            _ => None,
+        };
+        let (value, table, errors) = read_json(&self.file.get_bytes(), format, separator.as_deref());
+
+        vec![ConnectorType::Table(table), value, ConnectorType::Table(get_error_table(&errors))]
+    }
+
+    fn get_state(&self) -> Vec<u8> {
+        self.file.get_state()
+    }
+
+    fn set_state(&self, state: &[u8]) -> bool {
+        self.file.set_state(state)
+    }
+}
+
+#[cfg(test)]
+mod json_test {
+    use super::*;
+    use crate::helper::testing::null;
+    use crate::nodes::node_manager::Manager;
+
+    #[test]
+    fn test_parse() {
+        let value = parse_json(" {\"a\": [1, -2.5e1, 3000000000], \"b\": {\"c\": null, \"d\": \"x\\ty\\u00e9\\ud83d\\ude00\"}, \"e\": true} ").unwrap();
+        let mut nested = Record::new();
+        let mut record = Record::new();
+
+        nested.set_field("c", null());
+        nested.set_field("d", "x\tyé😀");
+        record.set_field("a", vec![ConnectorType::new(1), ConnectorType::new(-25.0), ConnectorType::new(3e9f32)]);
+        record.set_field("b", nested);
+        record.set_field("e", true);
+
+        assert_eq!(value, ConnectorType::Record(record));
+        assert_eq!(parse_json("[]").unwrap(), ConnectorType::List(Vec::new()));
+        assert_eq!(parse_json("[12345678901, -99999999999999999999]").unwrap(), ConnectorType::new(vec!["12345678901", "-99999999999999999999"]));
+    }
+
+    #[test]
+    fn test_errors() {
+        let message = |text: &str| parse_json(text).unwrap_err().to_string();
+
+        assert_eq!(message("{\"a\": 1,\n \"b\" 2}"), "line 2, column 6: unexpected '2'");
+        assert_eq!(message("[1, 2"), "line 1, column 6: unexpected end of document");
+        assert_eq!(message("[01]"), "line 1, column 2: invalid number");
+        assert_eq!(message("\"abc"), "line 1, column 1: unterminated string");
+        assert_eq!(message("[nul]"), "line 1, column 2: unexpected 'nul'");
+        assert_eq!(message("{} {}"), "line 1, column 4: unexpected '{'");
+        assert_eq!(message(&"[".repeat(1000)), "line 1, column 258: document nested too deeply");
+    }
+
+    #[test]
+    fn test_table() {
+        let text = "[{\"id\": 1, \"user\": {\"name\": \"a\", \"tags\": [\"x\"]}}, {\"id\": 2.5, \"extra\": false}]";
+        let (_, table, errors) = read_json(text.as_bytes(), JsonFormat::Detect, Some("."));
+
+        assert!(errors.is_empty());
+        assert_eq!(table.get_column_names(), vec!["id", "user.name", "user.tags", "extra"]);
+        assert_eq!(table.get_column("id").unwrap().get_type(), ConnectorTypeId::FLOAT);
+        assert_eq!(table.get_column("id").unwrap().get_values(), &[ConnectorType::new(1.0), ConnectorType::new(2.5)]);
+        assert_eq!(table.get_column("extra").unwrap().get_values(), &[null(), ConnectorType::new(false)]);
+
+        let (_, table, _) = read_json(text.as_bytes(), JsonFormat::Document, None);
+
+        assert_eq!(table.get_column_names(), vec!["id", "user", "extra"]);
+        assert_eq!(table.get_column("user").unwrap().get_type(), ConnectorTypeId::RECORD);
+
+        let text = "[{\"a.b\": 1, \"a\": {\"b\": 2}}, {\"a\": {\"b\": 3}, \"a.b_2\": 4}]";
+        let (_, table, _) = read_json(text.as_bytes(), JsonFormat::Detect, Some("."));
+
+        assert_eq!(table.get_column_names(), vec!["a.b", "a.b_2", "a.b_2_2"]);
+        assert_eq!(table.get_column("a.b").unwrap().get_values(), &[ConnectorType::new(1), ConnectorType::new(3)]);
+        assert_eq!(table.get_column("a.b_2").unwrap().get_values(), &[ConnectorType::new(2), null()]);
+        assert_eq!(table.get_column("a.b_2_2").unwrap().get_values(), &[null(), ConnectorType::new(4)]);
+    }
+
+    #[test]
+    fn test_lines() {
+        let text = "{\"a\": 1}\n\n{\"a\": 2, \"b\": \"x\"}\n{\"a\": \n";
+        let (value, table, errors) = read_json(text.as_bytes(), JsonFormat::Detect, Some("."));
+
+        assert_eq!(errors.iter().map(|error| error.to_string()).collect::<Vec<String>>(), vec!["line 4, column 7: unexpected end of document"]);
+        match value {
+            ConnectorType::List(values) => {
+                assert_eq!(values.len(), 2);
+                match &values[1] {
+                    ConnectorType::Record(record) => assert_eq!(record.get_field("b"), Some(&ConnectorType::new("x"))),
+                    value => panic!("Expected a record, found {:?}", value),
+                }
+            },
+            value => panic!("Expected a list, found {:?}", value),
+        }
+
+        assert_eq!(table.get_column("a").unwrap().get_type(), ConnectorTypeId::INT);
+        assert_eq!(table.get_row_count(), 2);
+
+        let (_, table, _) = read_json(b"[1, \"a\", null]\n", JsonFormat::Detect, Some("."));
+
+        assert_eq!(table.get_column_names(), vec!["Value"]);
+        assert_eq!(table.get_column("Value").unwrap().get_type(), ConnectorTypeId::ANY);
+    }
+
+    #[test]
+    fn test_node() {
+        let mut manager = Manager::new();
+        let node = manager.add_node(JsonImportNode::new());
+
+        manager.set_node_state(node, b"{\"a\": {\"b\": 1}}");
+
+        match &manager.evaluate(node)[0] {
+            ConnectorType::Table(table) => assert_eq!(table.get_column_names(), vec!["a.b"]),
+            value => panic!("Expected a table, found {:?}", value),
+        }
+
+        manager.set_input_value(node, 2, "_");
+
+        match &manager.evaluate(node)[0] {
+            ConnectorType::Table(table) => assert_eq!(table.get_column_names(), vec!["a_b"]),
+            value => panic!("Expected a table, found {:?}", value),
+        }
+
+        manager.set_input_value(node, 0, true);
+        manager.set_node_state(node, b"1\n{");
+
+        let outputs = manager.evaluate(node);
+
+        assert_eq!(outputs[1], ConnectorType::new(vec![1]));
+
+        match &outputs[2] {
+            ConnectorType::Table(errors) => assert_eq!(errors.get_row_count(), 1),
+            value => panic!("Expected a table, found {:?}", value),
+        }
+    }
+}
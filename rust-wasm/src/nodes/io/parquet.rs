@@ -0,0 +1,241 @@
+use arrow_array::{RecordBatch, RecordBatchReader};
+use bytes::Bytes;
+use parquet::arrow::ProjectionMask;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::table::Table;
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+use crate::nodes::relational::columns::select;
+use crate::nodes::relational::get_names;
+use super::FileState;
+use super::arrow::from_record_batches;
+
+/// Reads a Parquet file into a table, with the columns of the names in that order and the rows of the row groups.\
+/// All columns and row groups are read when none are given. Pages can be uncompressed or compressed with snappy, gzip or zstd.
+pub fn read_parquet(bytes: &[u8], columns: &[String], row_groups: &[usize]) -> Result<Table, String> {
+    let mut builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::copy_from_slice(bytes)).map_err(|error| error.to_string())?;
+    let names: Vec<String> = builder.schema().fields().iter().map(|field| field.name().clone()).collect();
+    let count = builder.metadata().num_row_groups();
+
+    if !columns.is_empty() {
+        let mut indices = Vec::new();
+
+        for column in columns {
+            match names.iter().position(|name| name == column) {
+                Some(index) => indices.push(index),
+                None => return Err(format!("Unknown column '{}'", column)),
+            }
+        }
+
+        let mask = ProjectionMask::roots(builder.parquet_schema(), indices);
+
+        builder = builder.with_projection(mask);
+    }
+
+    if let Some(row_group) = row_groups.iter().find(|row_group| **row_group >= count) {
+        return Err(format!("Row group {} out of range, the file has {}", row_group, count));
+    }
+
+    if !row_groups.is_empty() {
+        builder = builder.with_row_groups(row_groups.to_vec());
+    }
+
+    let reader = builder.build().map_err(|error| error.to_string())?;
+    let schema = reader.schema();
+    let batches = reader.collect::<Result<Vec<RecordBatch>, _>>().map_err(|error| error.to_string())?;
+    let table = from_record_batches(&schema, &batches);
+
+    match columns.is_empty() {
+        true => Ok(table),
+        false => select(&table, columns).ok_or("Columns can't be selected".to_string()),
+    }
+}
+
+/// Names of the columns and number of row groups of a Parquet file.
+pub fn get_parquet_layout(bytes: &[u8]) -> Result<(Vec<String>, usize), String> {
+    let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::copy_from_slice(bytes)).map_err(|error| error.to_string())?;
+    let names = builder.schema().fields().iter().map(|field| field.name().clone()).collect();
+
+    Ok((names, builder.metadata().num_row_groups()))
+}
+
+/// Imports a Parquet file as a table, reading only the row groups and columns it is asked for.\
+/// `Columns` and `Row Groups` select what is read, everything when `Null` or empty.
+/// `Column Names` and `Row Group Count` describe the whole file and `Error` why it couldn't be read.
+pub struct ParquetImportNode {
+    file: FileState,
+}
+
+impl ParquetImportNode {
+    pub fn new() -> ParquetImportNode {
+        ParquetImportNode {
+            file: FileState::new(),
+        }
+    }
+}
+
+impl Default for ParquetImportNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for ParquetImportNode {
+    fn get_name(&self) -> String {
+        "Parquet Import".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Columns", Null::new(), ConnectorTypeId::ANY),
+            Port::new("Row Groups", Null::new(), ConnectorTypeId::ANY),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Table", Table::new(), ConnectorTypeId::TABLE),
+            Port::new("Column Names", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+            Port::new("Row Group Count", 0, ConnectorTypeId::INT),
+            Port::new("Error", "", ConnectorTypeId::STRING),
+        ]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let bytes = self.file.get_bytes();
+        let failure = |error: String| vec![
+            ConnectorType::Table(Table::new()),
+            ConnectorType::List(Vec::new()),
+            ConnectorType::Integer(0),
+            ConnectorType::String(error),
+        ];
+
+        if bytes.is_empty() {
+            return failure(String::new());
+        }
+
+        let columns = get_names(&context.get_input(0));
+        let row_groups: Vec<usize> = match context.get_input(1) {
+            ConnectorType::List(values) => values.iter().map(|value| match value {
+                ConnectorType::Integer(value) => usize::try_from(*value).unwrap_or(usize::MAX),
+                _ => usize::MAX,
+            }).collect(),
+            ConnectorType::Integer(value) => vec![usize::try_from(value).unwrap_or(usize::MAX)],
+            _ => Vec::new(),
+        };
+
+        let (names, count) = match get_parquet_layout(&bytes) {
+            Ok(layout) => layout,
+            Err(error) => return failure(error),
+        };
+
+        match read_parquet(&bytes, &columns, &row_groups) {
+            Ok(table) => vec![
+                ConnectorType::Table(table),
+                ConnectorType::from(names),
+                ConnectorType::Integer(count as i32),
+                ConnectorType::from(""),
+            ],
+            Err(error) => vec![
+                ConnectorType::Table(Table::new()),
+                ConnectorType::from(names),
+                ConnectorType::Integer(count as i32),
+                ConnectorType::String(error),
+            ],
+        }
+    }
+
+    fn get_state(&self) -> Vec<u8> {
+        self.file.get_state()
+    }
+
+    fn set_state(&self, state: &[u8]) -> bool {
+        self.file.set_state(state)
+    }
+}
+
+#[cfg(test)]
+mod parquet_test {
+    use std::sync::Arc;
+    use arrow_array::{ArrayRef, Float64Array, Int64Array, StringArray};
+    use parquet::arrow::ArrowWriter;
+    use parquet::basic::{Compression, GzipLevel, ZstdLevel};
+    use parquet::file::properties::WriterProperties;
+    use super::*;
+    use crate::nodes::node_manager::Manager;
+
+    fn write(compression: Compression) -> Vec<u8> {
+        let batch = RecordBatch::try_from_iter(vec![
+            ("id", Arc::new(Int64Array::from(vec![1, 2, 3, 4, 5])) as ArrayRef),
+            ("score", Arc::new(Float64Array::from(vec![Some(0.5), None, Some(1.5), Some(2.5), None])) as ArrayRef),
+            ("name", Arc::new(StringArray::from(vec![Some("a"), Some("b"), None, Some("d"), Some("e")])) as ArrayRef),
+        ]).unwrap();
+        let properties = WriterProperties::builder().set_compression(compression).set_max_row_group_size(2).build();
+        let mut bytes = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut bytes, batch.schema(), Some(properties)).unwrap();
+
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        bytes
+    }
+
+    #[test]
+    fn test_compression() {
+        let compressions = [
+            Compression::UNCOMPRESSED,
+            Compression::SNAPPY,
+            Compression::GZIP(GzipLevel::default()),
+            Compression::ZSTD(ZstdLevel::default()),
+        ];
+
+        for compression in compressions {
+            let table = read_parquet(&write(compression), &[], &[]).unwrap();
+            let types: Vec<i32> = table.get_columns().iter().map(|column| column.get_type()).collect();
+
+            assert_eq!(types, vec![ConnectorTypeId::INT, ConnectorTypeId::FLOAT, ConnectorTypeId::STRING]);
+            assert_eq!(table.get_row_count(), 5);
+            assert_eq!(table.get_column("score").unwrap().get_values()[..3], [ConnectorType::new(0.5), ConnectorType::Null(Null::new()), ConnectorType::new(1.5)]);
+            assert!(table.get_column("name").unwrap().get_values()[2].is_null());
+        }
+    }
+
+    #[test]
+    fn test_selection() {
+        let bytes = write(Compression::SNAPPY);
+        let table = read_parquet(&bytes, &["name".to_string(), "id".to_string()], &[2, 0]).unwrap();
+
+        assert_eq!(get_parquet_layout(&bytes), Ok((vec!["id".to_string(), "score".to_string(), "name".to_string()], 3)));
+        assert_eq!(table.get_column_names(), vec!["name", "id"]);
+        assert_eq!(table.get_column("id").unwrap().get_values(), &[ConnectorType::new(5), ConnectorType::new(1), ConnectorType::new(2)]);
+        assert_eq!(read_parquet(&bytes, &["other".to_string()], &[]), Err("Unknown column 'other'".to_string()));
+        assert_eq!(read_parquet(&bytes, &[], &[3]), Err("Row group 3 out of range, the file has 3".to_string()));
+        assert!(read_parquet(b"not parquet", &[], &[]).is_err());
+    }
+
+    #[test]
+    fn test_node() {
+        let mut manager = Manager::new();
+        let node = manager.add_node(ParquetImportNode::new());
+
+        manager.set_node_state(node, &write(Compression::ZSTD(ZstdLevel::default())));
+        manager.set_input_value(node, 0, "score");
+        manager.set_input_value(node, 1, 1);
+
+        let outputs = manager.evaluate(node);
+
+        match &outputs[0] {
+            ConnectorType::Table(table) => {
+                assert_eq!(table.get_column_names(), vec!["score"]);
+                assert_eq!(table.get_row_count(), 2);
+            },
+            value => panic!("Expected a table, found {:?}", value),
+        }
+
+        assert_eq!(outputs[1], ConnectorType::new(vec!["id", "score", "name"]));
+        assert_eq!(outputs[2], 3);
+        assert_eq!(outputs[3], "");
+    }
+}
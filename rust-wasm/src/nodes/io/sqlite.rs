@@ -0,0 +1,275 @@
+use rusqlite::Connection;
+use rusqlite::types::Value;
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::datetime::DateTime;
+use crate::nodes::connector_manager::table::{Column, Table};
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+use super::{FileState, from_integer, get_unique_names};
+
+/// Opens a SQLite database from the bytes of its file, read only.
+pub fn open_sqlite(bytes: &[u8]) -> Result<Connection, String> {
+    let mut connection = Connection::open_in_memory().map_err(|error| error.to_string())?;
+
+    connection.deserialize_read_exact("main", bytes, bytes.len(), true).map_err(|error| error.to_string())?;
+
+    Ok(connection)
+}
+
+/// Names of the tables and views of a database, in alphabetical order.
+pub fn get_tables(connection: &Connection) -> Result<Vec<String>, String> {
+    let query = "SELECT name FROM sqlite_schema WHERE type IN ('table', 'view') AND name NOT LIKE 'sqlite_%' ORDER BY name";
+    let mut statement = connection.prepare(query).map_err(|error| error.to_string())?;
+    let names = statement.query_map([], |row| row.get(0)).map_err(|error| error.to_string())?;
+
+    names.collect::<Result<Vec<String>, _>>().map_err(|error| error.to_string())
+}
+
+/// Whether every integer of a column can be read as a number without being rounded.
+fn is_exact(values: &[Value]) -> bool {
+    values.iter().all(|value| !matches!(value, Value::Integer(value) if from_integer(*value).is_none()))
+}
+
+/// Value of a cell from its storage class, blobs being `Null`. Integers that don't fit in an `Integer` are `Float`,
+/// and all the integers of a column are read as text when a float would round one of them.
+fn get_value(value: Value, exact: bool) -> ConnectorType {
+    match value {
+        Value::Integer(value) => match from_integer(value) {
+            Some(value) if exact => value,
+            _ => ConnectorType::String(value.to_string()),
+        },
+        Value::Real(value) => ConnectorType::Float(value as f32),
+        Value::Text(text) => ConnectorType::String(text),
+        Value::Null | Value::Blob(_) => ConnectorType::Null(Null::new()),
+    }
+}
+
+/// Type of a column from the storage classes of its values, integers mixed with reals being floats.\
+/// Integer columns declared as booleans are `BOOL` when their values are 0 or 1, and text columns declared as dates
+/// or timestamps are `DATETIME` when their values parse as ISO 8601 dates. Columns without values get the type of their declaration.
+fn get_type(declared: &str, values: &[ConnectorType]) -> i32 {
+    let declared = declared.to_uppercase();
+    let values: Vec<&ConnectorType> = values.iter().filter(|value| !value.is_null()).collect();
+    let all = |test: &dyn Fn(&ConnectorType) -> bool| values.iter().all(|value| test(value));
+
+    if values.is_empty() {
+        // Affinity rules of SQLite
+        return match declared {
+            declared if declared.contains("INT") => ConnectorTypeId::INT,
+            declared if declared.contains("CHAR") || declared.contains("CLOB") || declared.contains("TEXT") => ConnectorTypeId::STRING,
+            declared if declared.contains("REAL") || declared.contains("FLOA") || declared.contains("DOUB") => ConnectorTypeId::FLOAT,
+            _ => ConnectorTypeId::ANY,
+        };
+    }
+
+    if declared.contains("BOOL") && all(&|value| *value == ConnectorType::Integer(0) || *value == ConnectorType::Integer(1)) {
+        return ConnectorTypeId::BOOL;
+    }
+
+    if (declared.contains("DATE") || declared.contains("TIME")) && all(&|value| matches!(value, ConnectorType::String(text) if DateTime::parse(text).is_some())) {
+        return ConnectorTypeId::DATETIME;
+    }
+
+    if all(&|value| matches!(value, ConnectorType::Integer(_))) {
+        ConnectorTypeId::INT
+    } else if all(&|value| matches!(value, ConnectorType::Integer(_) | ConnectorType::Float(_))) {
+        ConnectorTypeId::FLOAT
+    } else if all(&|value| matches!(value, ConnectorType::String(_))) {
+        ConnectorTypeId::STRING
+    } else {
+        ConnectorTypeId::ANY
+    }
+}
+
+/// Runs a query that only reads the database, such as a `SELECT`, into a table.
+pub fn query(connection: &Connection, sql: &str) -> Result<Table, String> {
+    let mut statement = connection.prepare(sql).map_err(|error| error.to_string())?;
+
+    if !statement.readonly() {
+        return Err("Only queries reading the database can be run".to_string());
+    }
+
+    let names: Vec<String> = statement.column_names().iter().map(|name| name.to_string()).collect();
+    let declared: Vec<String> = statement.columns().iter().map(|column| column.decl_type().unwrap_or("").to_string()).collect();
+    let mut values: Vec<Vec<Value>> = vec![Vec::new(); names.len()];
+    let mut rows = statement.query([]).map_err(|error| error.to_string())?;
+
+    while let Some(row) = rows.next().map_err(|error| error.to_string())? {
+        for (index, column) in values.iter_mut().enumerate() {
+            column.push(row.get_ref(index).map_err(|error| error.to_string())?.into());
+        }
+    }
+
+    let columns = get_unique_names(&names).iter().zip(declared).zip(values).map(|((name, declared), values)| {
+        let exact = is_exact(&values);
+        let values: Vec<ConnectorType> = values.into_iter().map(|value| get_value(value, exact)).collect();
+        let connector_type = get_type(&declared, &values);
+        let values = match connector_type {
+            ConnectorTypeId::DATETIME => values.iter().map(|value| match value {
+                ConnectorType::String(text) => DateTime::parse(text).map_or(ConnectorType::Null(Null::new()), ConnectorType::DateTime),
+                value => value.clone(),
+            }).collect(),
+            _ => values,
+        };
+
+        Column::new(name, connector_type, values)
+    }).collect();
+
+    Ok(Table::from_columns(columns).unwrap_or_default())
+}
+
+/// Imports a table from a SQLite database file.\
+/// `Query` is run against the database when it isn't empty, and `Tables` lists its tables and views.
+/// `Error` describes why the database or the query failed and is empty otherwise.
+pub struct SqliteImportNode {
+    file: FileState,
+}
+
+impl SqliteImportNode {
+    pub fn new() -> SqliteImportNode {
+        SqliteImportNode {
+            file: FileState::new(),
+        }
+    }
+}
+
+impl Default for SqliteImportNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for SqliteImportNode {
+    fn get_name(&self) -> String {
+        "SQLite Import".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![Port::new("Query", "", ConnectorTypeId::STRING)]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Table", Table::new(), ConnectorTypeId::TABLE),
+            Port::new("Tables", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+            Port::new("Error", "", ConnectorTypeId::STRING),
+        ]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let bytes = self.file.get_bytes();
+        let failure = |tables: Vec<String>, error: String| vec![ConnectorType::Table(Table::new()), ConnectorType::from(tables), ConnectorType::String(error)];
+
+        if bytes.is_empty() {
+            return failure(Vec::new(), String::new());
+        }
+
+        let (connection, tables) = match open_sqlite(&bytes).and_then(|connection| get_tables(&connection).map(|tables| (connection, tables))) {
+            Ok(opened) => opened,
+            Err(error) => return failure(Vec::new(), error),
+        };
+
+        let sql = match context.get_input(0) {
+            ConnectorType::String(sql) if !sql.trim().is_empty() => sql,
+            _ => return failure(tables, String::new()),
+        };
+
+        match query(&connection, &sql) {
+            Ok(table) => vec![ConnectorType::Table(table), ConnectorType::from(tables), ConnectorType::from("")],
+            Err(error) => failure(tables, error),
+        }
+    }
+
+    fn get_state(&self) -> Vec<u8> {
+        self.file.get_state()
+    }
+
+    fn set_state(&self, state: &[u8]) -> bool {
+        self.file.set_state(state)
+    }
+}
+
+#[cfg(test)]
+mod sqlite_test {
+    use super::*;
+    use crate::nodes::node_manager::Manager;
+
+    fn get_database() -> Vec<u8> {
+        let connection = Connection::open_in_memory().unwrap();
+
+        connection.execute_batch("
+            CREATE TABLE people (id INTEGER PRIMARY KEY, name TEXT, score REAL, active BOOLEAN, joined DATE, big INTEGER, data BLOB, code INTEGER);
+            INSERT INTO people VALUES (1, 'a', 1.5, 1, '2024-03-01', 5000000000, x'00', 12345678901);
+            INSERT INTO people VALUES (2, NULL, 2, 0, '2024-03-02 10:00', 1, NULL, 7);
+            INSERT INTO people VALUES (3, 'c', NULL, NULL, NULL, 2, NULL, NULL);
+            CREATE TABLE empty (value TEXT);
+            CREATE VIEW named AS SELECT name FROM people WHERE name IS NOT NULL;
+        ").unwrap();
+
+        connection.serialize("main").unwrap().to_vec()
+    }
+
+    #[test]
+    fn test_query() {
+        let connection = open_sqlite(&get_database()).unwrap();
+        let table = query(&connection, "SELECT * FROM people ORDER BY id").unwrap();
+        let types: Vec<i32> = table.get_columns().iter().map(|column| column.get_type()).collect();
+
+        assert_eq!(get_tables(&connection), Ok(vec!["empty".to_string(), "named".to_string(), "people".to_string()]));
+        assert_eq!(types, vec![
+            ConnectorTypeId::INT,
+            ConnectorTypeId::STRING,
+            ConnectorTypeId::FLOAT,
+            ConnectorTypeId::BOOL,
+            ConnectorTypeId::DATETIME,
+            ConnectorTypeId::FLOAT,
+            ConnectorTypeId::ANY,
+            ConnectorTypeId::STRING,
+        ]);
+        assert_eq!(table.get_column("score").unwrap().get_values(), &[ConnectorType::new(1.5), ConnectorType::new(2.0), ConnectorType::Null(Null::new())]);
+        assert_eq!(table.get_column("active").unwrap().get_values()[..2], [ConnectorType::new(true), ConnectorType::new(false)]);
+        assert_eq!(table.get_column("joined").unwrap().get_values()[0], ConnectorType::DateTime(DateTime::from_parts(2024, 3, 1, 0, 0, 0, 0).unwrap()));
+        assert!(table.get_column("name").unwrap().get_values()[1].is_null());
+        assert_eq!(table.get_column("big").unwrap().get_values()[0], ConnectorType::new(5e9f32));
+        assert_eq!(table.get_column("code").unwrap().get_values()[..2], [ConnectorType::new("12345678901"), ConnectorType::new("7")]);
+
+        let table = query(&connection, "SELECT value, count(*) AS value FROM empty").unwrap();
+
+        assert_eq!(table.get_column_names(), vec!["value", "value_2"]);
+        assert_eq!(table.get_column("value").unwrap().get_type(), ConnectorTypeId::STRING);
+    }
+
+    #[test]
+    fn test_errors() {
+        let connection = open_sqlite(&get_database()).unwrap();
+
+        assert_eq!(query(&connection, "DELETE FROM people"), Err("Only queries reading the database can be run".to_string()));
+        assert!(query(&connection, "SELECT * FROM missing").is_err());
+        assert!(open_sqlite(b"not a database").and_then(|connection| get_tables(&connection)).is_err());
+    }
+
+    #[test]
+    fn test_node() {
+        let mut manager = Manager::new();
+        let node = manager.add_node(SqliteImportNode::new());
+
+        manager.set_node_state(node, &get_database());
+
+        let outputs = manager.evaluate(node);
+
+        assert_eq!(outputs[1], ConnectorType::new(vec!["empty", "named", "people"]));
+        assert_eq!(outputs[2], "");
+
+        manager.set_input_value(node, 0, "SELECT * FROM named");
+
+        match &manager.evaluate(node)[0] {
+            ConnectorType::Table(table) => assert_eq!(table.get_column("name").unwrap().get_values(), &[ConnectorType::new("a"), ConnectorType::new("c")]),
+            value => panic!("Expected a table, found {:?}", value),
+        }
+
+        manager.set_input_value(node, 0, "SELECT nothing");
+
+        assert_ne!(manager.evaluate(node)[2], "");
+    }
+}
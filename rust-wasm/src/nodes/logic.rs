@@ -0,0 +1,501 @@
+use std::cmp::Ordering;
+use crate::helper::types::Null;
+use super::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use super::math::{broadcast, to_float};
+use super::node_manager::context::Context;
+use super::node_manager::node::{Node, Port};
+
+/// Order of two scalars, `None` when they can't be compared.\
+/// Numbers compare by value whatever their type, other values only with values of the same type.
+pub fn compare(lhs: &ConnectorType, rhs: &ConnectorType) -> Option<Ordering> {
+    match (lhs, rhs) {
+        (ConnectorType::Integer(lhs), ConnectorType::Integer(rhs)) => Some(lhs.cmp(rhs)),
+        (ConnectorType::String(lhs), ConnectorType::String(rhs)) => Some(lhs.cmp(rhs)),
+        (ConnectorType::DateTime(lhs), ConnectorType::DateTime(rhs)) => Some(lhs.cmp(rhs)),
+        (ConnectorType::Boolean(lhs), ConnectorType::Boolean(rhs)) => Some(lhs.cmp(rhs)),
+        _ => to_float(lhs)?.partial_cmp(&to_float(rhs)?),
+    }
+}
+
+/// Comparisons of two values.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Comparison {
+    Equal,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+}
+
+impl Comparison {
+    /// Compares two scalars, `Null` when either is `Null`.\
+    /// Values that can't be compared are not equal, and have no order.
+    pub fn apply(&self, lhs: &ConnectorType, rhs: &ConnectorType) -> ConnectorType {
+        if lhs.is_null() || rhs.is_null() {
+            return ConnectorType::Null(Null::new());
+        }
+
+        let ordering = match (compare(lhs, rhs), self) {
+            (Some(ordering), _) => ordering,
+            (None, Comparison::Equal) => return ConnectorType::Boolean(false),
+            (None, Comparison::NotEqual) => return ConnectorType::Boolean(true),
+            (None, _) => return ConnectorType::Null(Null::new()),
+        };
+
+        ConnectorType::Boolean(match self {
+            Comparison::Equal => ordering == Ordering::Equal,
+            Comparison::NotEqual => ordering != Ordering::Equal,
+            Comparison::Less => ordering == Ordering::Less,
+            Comparison::LessEqual => ordering != Ordering::Greater,
+            Comparison::Greater => ordering == Ordering::Greater,
+            Comparison::GreaterEqual => ordering != Ordering::Less,
+        })
+    }
+}
+
+/// Boolean operations of two values, with three-valued logic:
+/// a `Null` operand gives `Null` unless the other operand decides the result alone.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LogicOperation {
+    And,
+    Or,
+    Xor,
+}
+
+impl LogicOperation {
+    /// Applies the operation to two scalars, values that aren't booleans count as `Null`.
+    pub fn apply(&self, lhs: &ConnectorType, rhs: &ConnectorType) -> ConnectorType {
+        let null = ConnectorType::Null(Null::new());
+
+        match (self, lhs, rhs) {
+            (LogicOperation::And, ConnectorType::Boolean(false), _) | (LogicOperation::And, _, ConnectorType::Boolean(false)) => ConnectorType::Boolean(false),
+            (LogicOperation::Or, ConnectorType::Boolean(true), _) | (LogicOperation::Or, _, ConnectorType::Boolean(true)) => ConnectorType::Boolean(true),
+            (operation, ConnectorType::Boolean(lhs), ConnectorType::Boolean(rhs)) => ConnectorType::Boolean(match operation {
+                LogicOperation::And => *lhs && *rhs,
+                LogicOperation::Or => *lhs || *rhs,
+                LogicOperation::Xor => lhs != rhs,
+            }),
+            _ => null,
+        }
+    }
+
+    /// Result decided by the first operand alone, so the second doesn't need to be evaluated.
+    pub fn get_shortcut(&self, lhs: &ConnectorType) -> Option<ConnectorType> {
+        match (self, lhs) {
+            (LogicOperation::And, ConnectorType::Boolean(false)) => Some(ConnectorType::Boolean(false)),
+            (LogicOperation::Or, ConnectorType::Boolean(true)) => Some(ConnectorType::Boolean(true)),
+            _ => None,
+        }
+    }
+}
+
+/// Negation of a boolean, values that aren't booleans give `Null`.
+pub fn not(value: &ConnectorType) -> ConnectorType {
+    match value {
+        ConnectorType::Boolean(value) => ConnectorType::Boolean(!value),
+        _ => ConnectorType::Null(Null::new()),
+    }
+}
+
+/// Node for a `Comparison` of `A` and `B`, element-wise on lists.
+pub struct ComparisonNode {
+    comparison: Comparison,
+}
+
+impl ComparisonNode {
+    pub fn new(comparison: Comparison) -> ComparisonNode {
+        ComparisonNode {
+            comparison,
+        }
+    }
+}
+
+impl Node for ComparisonNode {
+    fn get_name(&self) -> String {
+        match self.comparison {
+            Comparison::NotEqual => "Not Equal".to_string(),
+            Comparison::LessEqual => "Less Or Equal".to_string(),
+            Comparison::GreaterEqual => "Greater Or Equal".to_string(),
+            comparison => format!("{:?}", comparison),
+        }
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("A", 0, ConnectorTypeId::ANY),
+            Port::new("B", 0, ConnectorTypeId::ANY),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Result", false, ConnectorTypeId::ANY)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        vec![broadcast(&context.get_inputs(), &|operands| self.comparison.apply(&operands[0], &operands[1]))]
+    }
+}
+
+/// Whether `Value` lies between `Min` and `Max`, bounds included unless `Inclusive` is false.
+pub struct BetweenNode {}
+
+impl BetweenNode {
+    pub fn new() -> BetweenNode {
+        BetweenNode {}
+    }
+}
+
+impl Default for BetweenNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for BetweenNode {
+    fn get_name(&self) -> String {
+        "Between".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Value", 0, ConnectorTypeId::ANY),
+            Port::new("Min", 0, ConnectorTypeId::ANY),
+            Port::new("Max", 1, ConnectorTypeId::ANY),
+            Port::new("Inclusive", true, ConnectorTypeId::BOOL),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Result", false, ConnectorTypeId::ANY)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let (above, below) = match context.get_input(3) {
+            ConnectorType::Boolean(false) => (Comparison::Greater, Comparison::Less),
+            _ => (Comparison::GreaterEqual, Comparison::LessEqual),
+        };
+
+        vec![broadcast(&context.get_inputs()[..3], &|operands| {
+            LogicOperation::And.apply(&above.apply(&operands[0], &operands[1]), &below.apply(&operands[0], &operands[2]))
+        })]
+    }
+}
+
+/// Whether `Value` is `Null`, element-wise on lists.
+pub struct IsNullNode {}
+
+impl IsNullNode {
+    pub fn new() -> IsNullNode {
+        IsNullNode {}
+    }
+}
+
+impl Default for IsNullNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for IsNullNode {
+    fn get_name(&self) -> String {
+        "Is Null".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![Port::new("Value", Null::new(), ConnectorTypeId::ANY)]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Result", true, ConnectorTypeId::ANY)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        vec![broadcast(&context.get_inputs(), &|operands| ConnectorType::Boolean(operands[0].is_null()))]
+    }
+}
+
+/// Node for a `LogicOperation` of `A` and `B`, element-wise on lists.\
+/// `B` isn't evaluated when a scalar `A` decides the result.
+pub struct LogicNode {
+    operation: LogicOperation,
+}
+
+impl LogicNode {
+    pub fn new(operation: LogicOperation) -> LogicNode {
+        LogicNode {
+            operation,
+        }
+    }
+}
+
+impl Node for LogicNode {
+    fn get_name(&self) -> String {
+        format!("{:?}", self.operation)
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("A", false, ConnectorTypeId::ANY),
+            Port::new("B", false, ConnectorTypeId::ANY),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Result", false, ConnectorTypeId::ANY)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let lhs = context.get_input(0);
+
+        if let Some(result) = self.operation.get_shortcut(&lhs) {
+            return vec![result];
+        }
+
+        vec![broadcast(&[lhs, context.get_input(1)], &|operands| self.operation.apply(&operands[0], &operands[1]))]
+    }
+}
+
+/// Negation of `Value`, element-wise on lists.
+pub struct NotNode {}
+
+impl NotNode {
+    pub fn new() -> NotNode {
+        NotNode {}
+    }
+}
+
+impl Default for NotNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for NotNode {
+    fn get_name(&self) -> String {
+        "Not".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![Port::new("Value", false, ConnectorTypeId::ANY)]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Result", true, ConnectorTypeId::ANY)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        vec![broadcast(&context.get_inputs(), &|operands| not(&operands[0]))]
+    }
+}
+
+/// Outputs `Then` when `Condition` is true and `Else` when it is false, `Null` when it isn't a boolean.\
+/// Only the input taken is evaluated. A list of conditions picks element-wise, evaluating both inputs.
+pub struct IfNode {}
+
+impl IfNode {
+    pub fn new() -> IfNode {
+        IfNode {}
+    }
+}
+
+impl Default for IfNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for IfNode {
+    fn get_name(&self) -> String {
+        "If".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Condition", false, ConnectorTypeId::ANY),
+            Port::new("Then", Null::new(), ConnectorTypeId::ANY),
+            Port::new("Else", Null::new(), ConnectorTypeId::ANY),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Result", Null::new(), ConnectorTypeId::ANY)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        match context.get_input(0) {
+            ConnectorType::Boolean(true) => vec![context.get_input(1)],
+            ConnectorType::Boolean(false) => vec![context.get_input(2)],
+            condition @ ConnectorType::List(_) => {
+                let operands = [condition, context.get_input(1), context.get_input(2)];
+
+                vec![broadcast(&operands, &|operands| match operands[0] {
+                    ConnectorType::Boolean(true) => operands[1].clone(),
+                    ConnectorType::Boolean(false) => operands[2].clone(),
+                    _ => ConnectorType::Null(Null::new()),
+                })]
+            },
+            _ => vec![ConnectorType::Null(Null::new())],
+        }
+    }
+}
+
+/// Outputs the input of the case equal to `Key`, or `Default` when no case is.\
+/// Cases are given when the node is created and each gets an input named after its value.
+/// Only the input taken is evaluated.
+pub struct SwitchNode {
+    cases: Vec<ConnectorType>,
+}
+
+impl SwitchNode {
+    pub fn new(cases: Vec<ConnectorType>) -> SwitchNode {
+        SwitchNode {
+            cases,
+        }
+    }
+}
+
+impl Node for SwitchNode {
+    fn get_name(&self) -> String {
+        "Switch".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        let mut inputs = vec![Port::new("Key", Null::new(), ConnectorTypeId::ANY)];
+
+        inputs.extend(self.cases.iter().map(|case| Port::new(&case.to_string(), Null::new(), ConnectorTypeId::ANY)));
+        inputs.push(Port::new("Default", Null::new(), ConnectorTypeId::ANY));
+
+        inputs
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Result", Null::new(), ConnectorTypeId::ANY)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let key = context.get_input(0);
+        let case = self.cases.iter().position(|case| Comparison::Equal.apply(&key, case) == ConnectorType::Boolean(true));
+
+        match case {
+            Some(index) => vec![context.get_input(index + 1)],
+            None => vec![context.get_input(self.cases.len() + 1)],
+        }
+    }
+}
+
+#[cfg(test)]
+mod logic_test {
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use super::*;
+    use crate::helper::testing::null;
+    use crate::nodes::node_manager::Manager;
+
+    /// Counts how many times it is computed.
+    struct CountNode {
+        count: Rc<Cell<usize>>,
+    }
+
+    impl Node for CountNode {
+        fn get_name(&self) -> String {
+            "Count".to_string()
+        }
+
+        fn get_inputs(&self) -> Vec<Port> {
+            Vec::new()
+        }
+
+        fn get_outputs(&self) -> Vec<Port> {
+            vec![Port::new("Value", 0, ConnectorTypeId::INT)]
+        }
+
+        fn compute(&self, _: &mut Context) -> Vec<ConnectorType> {
+            self.count.set(self.count.get() + 1);
+
+            vec![ConnectorType::Integer(self.count.get() as i32)]
+        }
+    }
+
+    #[test]
+    fn test_comparison() {
+        assert_eq!(Comparison::Equal.apply(&ConnectorType::new(1), &ConnectorType::new(1.0)), true);
+        assert_eq!(Comparison::Less.apply(&ConnectorType::new("a"), &ConnectorType::new("b")), true);
+        assert_eq!(Comparison::GreaterEqual.apply(&ConnectorType::new(2.5), &ConnectorType::new(3)), false);
+        assert_eq!(Comparison::Equal.apply(&ConnectorType::new("1"), &ConnectorType::new(1)), false);
+        assert_eq!(Comparison::NotEqual.apply(&ConnectorType::new("1"), &ConnectorType::new(1)), true);
+        assert_eq!(Comparison::Less.apply(&ConnectorType::new("1"), &ConnectorType::new(1)), null());
+        assert_eq!(Comparison::Equal.apply(&null(), &null()), null());
+    }
+
+    #[test]
+    fn test_logic() {
+        let (t, f) = (ConnectorType::new(true), ConnectorType::new(false));
+
+        assert_eq!(LogicOperation::And.apply(&t, &f), false);
+        assert_eq!(LogicOperation::And.apply(&null(), &f), false);
+        assert_eq!(LogicOperation::And.apply(&null(), &t), null());
+        assert_eq!(LogicOperation::Or.apply(&null(), &t), true);
+        assert_eq!(LogicOperation::Xor.apply(&t, &f), true);
+        assert_eq!(LogicOperation::Xor.apply(&t, &null()), null());
+        assert_eq!(not(&f), true);
+        assert_eq!(not(&ConnectorType::new(1)), null());
+    }
+
+    #[test]
+    fn test_nodes() {
+        let mut manager = Manager::new();
+        let less = manager.add_node(ComparisonNode::new(Comparison::Less));
+        let between = manager.add_node(BetweenNode::new());
+        let xor = manager.add_node(LogicNode::new(LogicOperation::Xor));
+        let is_null = manager.add_node(IsNullNode::new());
+
+        manager.set_input_value(less, 0, vec![1, 5, 9]);
+        manager.set_input_value(less, 1, 5);
+        manager.set_input_value(between, 0, vec![1, 5, 9]);
+        manager.set_input_value(between, 1, 5);
+        manager.set_input_value(between, 2, 9);
+        manager.set_input_value(between, 3, false);
+        manager.connect(less, 0, xor, 0);
+        manager.connect(between, 0, xor, 1);
+
+        assert_eq!(manager.evaluate(less)[0], ConnectorType::new(vec![true, false, false]));
+        assert_eq!(manager.evaluate(between)[0], ConnectorType::new(vec![false, false, false]));
+        assert_eq!(manager.evaluate(xor)[0], ConnectorType::new(vec![true, false, false]));
+
+        manager.set_input_value(is_null, 0, vec![ConnectorType::new(1), null()]);
+
+        assert_eq!(manager.evaluate(is_null)[0], ConnectorType::new(vec![false, true]));
+    }
+
+    #[test]
+    fn test_lazy() {
+        let count = Rc::new(Cell::new(0));
+        let mut manager = Manager::new();
+        let source = manager.add_node(CountNode { count: count.clone() });
+        let choose = manager.add_node(IfNode::new());
+        let switch = manager.add_node(SwitchNode::new(vec![ConnectorType::new("a"), ConnectorType::new(2)]));
+        let and = manager.add_node(LogicNode::new(LogicOperation::And));
+
+        manager.set_input_value(choose, 0, true);
+        manager.set_input_value(choose, 1, "taken");
+        manager.connect(source, 0, choose, 2);
+        manager.set_input_value(switch, 0, 2.0);
+        manager.connect(source, 0, switch, 1);
+        manager.set_input_value(switch, 2, "two");
+        manager.connect(source, 0, and, 1);
+
+        assert_eq!(manager.evaluate(choose)[0], "taken");
+        assert_eq!(manager.evaluate(switch)[0], "two");
+        assert_eq!(manager.evaluate(and)[0], false);
+        assert_eq!(count.get(), 0);
+
+        manager.set_input_value(choose, 0, false);
+        manager.set_input_value(switch, 0, "b");
+
+        assert_eq!(manager.evaluate(choose)[0], 1);
+        assert_eq!(manager.evaluate(switch)[0], null());
+        assert_eq!(count.get(), 1);
+        assert_eq!(manager.get_node(switch).unwrap().get_inputs().len(), 4);
+    }
+}
@@ -0,0 +1,401 @@
+use crate::helper::types::Null;
+use super::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use super::node_manager::context::Context;
+use super::node_manager::node::{Node, Port};
+
+/// Operations taking two operands.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BinaryOperation {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Modulo,
+    Power,
+    Min,
+    Max,
+    Atan2,
+}
+
+/// Operations taking a single operand.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UnaryOperation {
+    Abs,
+    Round,
+    Floor,
+    Ceil,
+    Sqrt,
+    Log,
+    Log10,
+    Exp,
+    Sin,
+    Cos,
+    Tan,
+    Asin,
+    Acos,
+    Atan,
+}
+
+/// Applies `operation` to the operands, element-wise when any of them is a list.\
+/// Scalar operands are repeated for every element, lists of different lengths produce `Null`.
+///
+/// # Example
+/// ```
+/// let operands = [ConnectorType::new(vec![1, 2, 3]), ConnectorType::new(10)];
+/// let result = broadcast(&operands, &|operands| BinaryOperation::Add.apply(&operands[0], &operands[1]));
+///
+/// assert_eq!(result, ConnectorType::new(vec![11, 12, 13]));
+/// ```
+pub fn broadcast<F>(operands: &[ConnectorType], operation: &F) -> ConnectorType
+where
+    F: Fn(&[ConnectorType]) -> ConnectorType
+{
+    let mut length = None;
+
+    for operand in operands {
+        if let ConnectorType::List(values) = operand {
+            match length {
+                None => length = Some(values.len()),
+                Some(length) if length != values.len() => return ConnectorType::Null(Null::new()),
+                _ => (),
+            }
+        }
+    }
+
+    match length {
+        None => operation(operands),
+        Some(length) => {
+            let values = (0..length).map(|index| {
+                let elements: Vec<ConnectorType> = operands.iter().map(|operand| match operand {
+                    ConnectorType::List(values) => values[index].clone(),
+                    scalar => scalar.clone(),
+                }).collect();
+
+                broadcast(&elements, operation)
+            }).collect();
+
+            ConnectorType::List(values)
+        },
+    }
+}
+
+/// Reads a numeric value as a float, booleans count as 0 and 1.
+pub fn to_float(value: &ConnectorType) -> Option<f32> {
+    match value {
+        ConnectorType::Integer(value) => Some(*value as f32),
+        ConnectorType::Float(value) => Some(*value),
+        ConnectorType::Boolean(value) => Some(if *value { 1.0 } else { 0.0 }),
+        _ => None,
+    }
+}
+
+/// Brings two scalar operands to a common numeric type.\
+/// Integers and booleans stay integers, anything mixed with a float becomes a float.
+fn promote(lhs: &ConnectorType, rhs: &ConnectorType) -> Option<(ConnectorType, ConnectorType)> {
+    match (lhs, rhs) {
+        (ConnectorType::Integer(_) | ConnectorType::Boolean(_), ConnectorType::Integer(_) | ConnectorType::Boolean(_)) => {
+            Some((lhs.get_variant(ConnectorTypeId::INT), rhs.get_variant(ConnectorTypeId::INT)))
+        },
+        _ => Some((ConnectorType::Float(to_float(lhs)?), ConnectorType::Float(to_float(rhs)?))),
+    }
+}
+
+fn from_float(value: f32) -> ConnectorType {
+    match value.is_nan() {
+        true => ConnectorType::Null(Null::new()),
+        false => ConnectorType::Float(value),
+    }
+}
+
+impl BinaryOperation {
+    /// Value of the second operand when it is not connected.
+    pub fn get_default(&self) -> i32 {
+        match self {
+            BinaryOperation::Multiply | BinaryOperation::Divide | BinaryOperation::Modulo | BinaryOperation::Power => 1,
+            _ => 0,
+        }
+    }
+
+    /// Applies the operation to two scalars.\
+    /// Non-numeric operands, integer overflow and integer division by zero produce `Null`.
+    pub fn apply(&self, lhs: &ConnectorType, rhs: &ConnectorType) -> ConnectorType {
+        let (lhs, rhs) = match promote(lhs, rhs) {
+            Some(operands) => operands,
+            None => return ConnectorType::Null(Null::new()),
+        };
+
+        if let (ConnectorType::Integer(lhs), ConnectorType::Integer(rhs)) = (&lhs, &rhs) {
+            let checked = match self {
+                BinaryOperation::Add => lhs.checked_add(*rhs),
+                BinaryOperation::Subtract => lhs.checked_sub(*rhs),
+                BinaryOperation::Multiply => lhs.checked_mul(*rhs),
+                BinaryOperation::Divide => lhs.checked_div(*rhs),
+                BinaryOperation::Modulo => lhs.checked_rem(*rhs),
+                _ => Some(0),
+            };
+
+            if checked.is_none() {
+                return ConnectorType::Null(Null::new());
+            }
+        }
+
+        match (self, lhs, rhs) {
+            (BinaryOperation::Add, lhs, rhs) => lhs + rhs,
+            (BinaryOperation::Subtract, lhs, rhs) => lhs - rhs,
+            (BinaryOperation::Multiply, lhs, rhs) => lhs * rhs,
+            (BinaryOperation::Divide, lhs, rhs) => lhs / rhs,
+            (BinaryOperation::Modulo, lhs, rhs) => lhs % rhs,
+            (BinaryOperation::Power, ConnectorType::Integer(lhs), ConnectorType::Integer(rhs)) if rhs >= 0 => {
+                match lhs.checked_pow(rhs as u32) {
+                    Some(value) => ConnectorType::Integer(value),
+                    None => ConnectorType::Null(Null::new()),
+                }
+            },
+            (BinaryOperation::Power, lhs, rhs) => from_float(to_float(&lhs).unwrap().powf(to_float(&rhs).unwrap())),
+            (BinaryOperation::Min, lhs, rhs) => lhs.min(rhs),
+            (BinaryOperation::Max, lhs, rhs) => lhs.max(rhs),
+            (BinaryOperation::Atan2, lhs, rhs) => from_float(to_float(&lhs).unwrap().atan2(to_float(&rhs).unwrap())),
+        }
+    }
+}
+
+impl UnaryOperation {
+    /// Applies the operation to a scalar.\
+    /// Non-numeric operands and results outside the domain of the function produce `Null`.
+    pub fn apply(&self, value: &ConnectorType) -> ConnectorType {
+        if let ConnectorType::Integer(value) = value {
+            match self {
+                UnaryOperation::Abs => return value.checked_abs().map(ConnectorType::Integer).unwrap_or(ConnectorType::Null(Null::new())),
+                UnaryOperation::Round | UnaryOperation::Floor | UnaryOperation::Ceil => return ConnectorType::Integer(*value),
+                _ => (),
+            }
+        }
+
+        let value = match to_float(value) {
+            Some(value) => value,
+            None => return ConnectorType::Null(Null::new()),
+        };
+
+        from_float(match self {
+            UnaryOperation::Abs => value.abs(),
+            UnaryOperation::Round => value.round(),
+            UnaryOperation::Floor => value.floor(),
+            UnaryOperation::Ceil => value.ceil(),
+            UnaryOperation::Sqrt => value.sqrt(),
+            UnaryOperation::Log => value.ln(),
+            UnaryOperation::Log10 => value.log10(),
+            UnaryOperation::Exp => value.exp(),
+            UnaryOperation::Sin => value.sin(),
+            UnaryOperation::Cos => value.cos(),
+            UnaryOperation::Tan => value.tan(),
+            UnaryOperation::Asin => value.asin(),
+            UnaryOperation::Acos => value.acos(),
+            UnaryOperation::Atan => value.atan(),
+        })
+    }
+}
+
+/// Clamps a scalar between a lower and an upper bound.
+pub fn clamp(value: &ConnectorType, min: &ConnectorType, max: &ConnectorType) -> ConnectorType {
+    BinaryOperation::Min.apply(&BinaryOperation::Max.apply(value, min), max)
+}
+
+/// Node for a `BinaryOperation`, operands are `A` and `B`.
+pub struct BinaryNode {
+    operation: BinaryOperation,
+}
+
+impl BinaryNode {
+    pub fn new(operation: BinaryOperation) -> BinaryNode {
+        BinaryNode {
+            operation,
+        }
+    }
+}
+
+impl Node for BinaryNode {
+    fn get_name(&self) -> String {
+        format!("{:?}", self.operation)
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("A", 0, ConnectorTypeId::ANY),
+            Port::new("B", self.operation.get_default(), ConnectorTypeId::ANY),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Result", 0, ConnectorTypeId::ANY)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let operands = context.get_inputs();
+
+        vec![broadcast(&operands, &|operands| self.operation.apply(&operands[0], &operands[1]))]
+    }
+}
+
+/// Node for a `UnaryOperation`.
+pub struct UnaryNode {
+    operation: UnaryOperation,
+}
+
+impl UnaryNode {
+    pub fn new(operation: UnaryOperation) -> UnaryNode {
+        UnaryNode {
+            operation,
+        }
+    }
+}
+
+impl Node for UnaryNode {
+    fn get_name(&self) -> String {
+        format!("{:?}", self.operation)
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![Port::new("Value", 0, ConnectorTypeId::ANY)]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Result", 0, ConnectorTypeId::ANY)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let operands = context.get_inputs();
+
+        vec![broadcast(&operands, &|operands| self.operation.apply(&operands[0]))]
+    }
+}
+
+/// Clamps `Value` between `Min` and `Max`.
+pub struct ClampNode {}
+
+impl ClampNode {
+    pub fn new() -> ClampNode {
+        ClampNode {}
+    }
+}
+
+impl Default for ClampNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for ClampNode {
+    fn get_name(&self) -> String {
+        "Clamp".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Value", 0, ConnectorTypeId::ANY),
+            Port::new("Min", 0, ConnectorTypeId::ANY),
+            Port::new("Max", 1, ConnectorTypeId::ANY),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Result", 0, ConnectorTypeId::ANY)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let operands = context.get_inputs();
+
+        vec![broadcast(&operands, &|operands| clamp(&operands[0], &operands[1], &operands[2]))]
+    }
+}
+
+#[cfg(test)]
+mod math_test {
+    use super::*;
+    use crate::nodes::node_manager::Manager;
+
+    #[test]
+    fn test_binary() {
+        let int = ConnectorType::new(7);
+        let float = ConnectorType::new(2.0);
+
+        assert_eq!(BinaryOperation::Add.apply(&int, &ConnectorType::new(3)), 10);
+        assert_eq!(BinaryOperation::Subtract.apply(&int, &float), 5.0);
+        assert_eq!(BinaryOperation::Multiply.apply(&int, &ConnectorType::new(true)), 7);
+        assert_eq!(BinaryOperation::Divide.apply(&int, &ConnectorType::new(2)), 3);
+        assert_eq!(BinaryOperation::Divide.apply(&int, &float), 3.5);
+        assert_eq!(BinaryOperation::Modulo.apply(&int, &ConnectorType::new(4)), 3);
+        assert_eq!(BinaryOperation::Power.apply(&int, &ConnectorType::new(2)), 49);
+        assert_eq!(BinaryOperation::Power.apply(&ConnectorType::new(2), &ConnectorType::new(-1)), 0.5);
+        assert_eq!(BinaryOperation::Min.apply(&int, &ConnectorType::new(3)), 3);
+        assert_eq!(BinaryOperation::Max.apply(&float, &ConnectorType::new(3)), 3.0);
+    }
+
+    #[test]
+    fn test_binary_null() {
+        let null = ConnectorType::Null(Null::new());
+
+        assert_eq!(BinaryOperation::Divide.apply(&ConnectorType::new(1), &ConnectorType::new(0)), null);
+        assert_eq!(BinaryOperation::Modulo.apply(&ConnectorType::new(1), &ConnectorType::new(0)), null);
+        assert_eq!(BinaryOperation::Add.apply(&ConnectorType::new(i32::MAX), &ConnectorType::new(1)), null);
+        assert_eq!(BinaryOperation::Power.apply(&ConnectorType::new(10), &ConnectorType::new(10)), null);
+        assert_eq!(BinaryOperation::Add.apply(&ConnectorType::new("1"), &ConnectorType::new(1)), null);
+        assert_eq!(BinaryOperation::Add.apply(&null, &ConnectorType::new(1)), null);
+        assert_eq!(BinaryOperation::Divide.apply(&ConnectorType::new(1.0), &ConnectorType::new(0.0)), f32::INFINITY);
+    }
+
+    #[test]
+    fn test_unary() {
+        assert_eq!(UnaryOperation::Abs.apply(&ConnectorType::new(-3)), 3);
+        assert_eq!(UnaryOperation::Abs.apply(&ConnectorType::new(-3.5)), 3.5);
+        assert_eq!(UnaryOperation::Round.apply(&ConnectorType::new(2.5)), 3.0);
+        assert_eq!(UnaryOperation::Floor.apply(&ConnectorType::new(-2.5)), -3.0);
+        assert_eq!(UnaryOperation::Ceil.apply(&ConnectorType::new(2.1)), 3.0);
+        assert_eq!(UnaryOperation::Round.apply(&ConnectorType::new(4)), 4);
+        assert_eq!(UnaryOperation::Sqrt.apply(&ConnectorType::new(16)), 4.0);
+        assert_eq!(UnaryOperation::Log.apply(&ConnectorType::new(1)), 0.0);
+        assert_eq!(UnaryOperation::Log10.apply(&ConnectorType::new(100)), 2.0);
+        assert_eq!(UnaryOperation::Exp.apply(&ConnectorType::new(0)), 1.0);
+        assert_eq!(UnaryOperation::Sin.apply(&ConnectorType::new(0)), 0.0);
+        assert_eq!(UnaryOperation::Cos.apply(&ConnectorType::new(0)), 1.0);
+        assert_eq!(UnaryOperation::Sqrt.apply(&ConnectorType::new(-1)), Null::new());
+        assert_eq!(UnaryOperation::Asin.apply(&ConnectorType::new(2)), Null::new());
+        assert_eq!(UnaryOperation::Abs.apply(&ConnectorType::new(i32::MIN)), Null::new());
+    }
+
+    #[test]
+    fn test_broadcast() {
+        let list = ConnectorType::new(vec![1, 2, 3]);
+        let add = |operands: &[ConnectorType]| BinaryOperation::Add.apply(&operands[0], &operands[1]);
+
+        assert_eq!(broadcast(&[list.clone(), ConnectorType::new(1)], &add), ConnectorType::new(vec![2, 3, 4]));
+        assert_eq!(broadcast(&[ConnectorType::new(0.5), list.clone()], &add), ConnectorType::new(vec![1.5, 2.5, 3.5]));
+        assert_eq!(broadcast(&[list.clone(), list.clone()], &add), ConnectorType::new(vec![2, 4, 6]));
+        assert_eq!(broadcast(&[list, ConnectorType::new(vec![1])], &add), Null::new());
+
+        let nested = ConnectorType::new(vec![ConnectorType::new(vec![1, 2]), ConnectorType::new(3)]);
+
+        assert_eq!(broadcast(&[nested, ConnectorType::new(1)], &add), ConnectorType::new(vec![ConnectorType::new(vec![2, 3]), ConnectorType::new(4)]));
+        assert_eq!(clamp(&ConnectorType::new(5), &ConnectorType::new(0), &ConnectorType::new(3)), 3);
+        assert_eq!(clamp(&ConnectorType::new(-1.5), &ConnectorType::new(0), &ConnectorType::new(3)), 0.0);
+    }
+
+    #[test]
+    fn test_nodes() {
+        let mut manager = Manager::new();
+
+        let add = manager.add_node(BinaryNode::new(BinaryOperation::Add));
+        let sqrt = manager.add_node(UnaryNode::new(UnaryOperation::Sqrt));
+        let clamp = manager.add_node(ClampNode::new());
+
+        manager.set_input_value(add, 0, vec![7, 14, 97]);
+        manager.set_input_value(add, 1, 2);
+        manager.connect(add, 0, sqrt, 0);
+        manager.connect(sqrt, 0, clamp, 0);
+        manager.set_input_value(clamp, 1, 4.0);
+        manager.set_input_value(clamp, 2, 9.5);
+
+        assert_eq!(manager.evaluate(sqrt), vec![ConnectorType::new(vec![3.0, 4.0, 9.949874])]);
+        assert_eq!(manager.evaluate(clamp), vec![ConnectorType::new(vec![4.0, 4.0, 9.5])]);
+        assert_eq!(manager.get_node(add).unwrap().get_name(), "Add");
+    }
+}
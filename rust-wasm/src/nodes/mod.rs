@@ -1,2 +1,11 @@
 pub mod connector_manager;
-//pub mod node_manager;
+pub mod node_manager;
+pub mod math;
+pub mod logic;
+pub mod statistics;
+pub mod timeseries;
+pub mod relational;
+pub mod expression;
+pub mod text;
+pub mod io;
+pub mod registry;
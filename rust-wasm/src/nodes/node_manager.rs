@@ -0,0 +1,251 @@
+#[cfg(test)]
+mod test;
+pub mod context;
+pub mod node;
+use std::collections::HashSet;
+use std::rc::Rc;
+use crate::helper::random::{Random, split_mix};
+use crate::helper::types::Null;
+use super::connector_manager::Manager as ConnectorManager;
+use super::connector_manager::value::ConnectorType;
+use self::context::Context;
+use self::node::{Node, NodeInstance};
+
+/// Contains all the nodes of a blueprint and the connectors backing their ports.\
+/// Used to add, connect and evaluate nodes.\
+///
+/// Every port of a node is a connector in the connector manager, so values flow between nodes
+/// through the same edges and type conversions as plain connectors.\
+/// Evaluation is pull based, evaluating a node evaluates the nodes connected to the inputs it reads.\
+/// Each node is evaluated at most once per call to `evaluate`.
+///
+/// Nodes draw random numbers from their own stream, derived from the blueprint seed and the node index.
+/// The same blueprint always draws the same numbers, and adding a node doesn't change the draws of the others.
+///
+/// # Example
+/// ```
+/// use crate::nodes::node_manager::Manager;
+/// use crate::nodes::math::{BinaryNode, BinaryOperation};
+///
+/// let mut manager = Manager::new();
+///
+/// let add = manager.add_node(BinaryNode::new(BinaryOperation::Add));
+/// let multiply = manager.add_node(BinaryNode::new(BinaryOperation::Multiply));
+///
+/// // (2 + 3) * 4
+/// manager.set_input_value(add, 0, 2);
+/// manager.set_input_value(add, 1, 3);
+/// manager.connect(add, 0, multiply, 0);
+/// manager.set_input_value(multiply, 1, 4);
+///
+/// let result = manager.evaluate(multiply);
+/// ```
+pub struct Manager {
+    connectors: ConnectorManager,
+    nodes: Vec<NodeInstance>,
+    owners: Vec<usize>,
+    evaluated: HashSet<usize>,
+    evaluating: Vec<usize>,
+    seed: u64,
+}
+
+impl Default for Manager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Manager {
+    pub fn new() -> Manager {
+        Manager {
+            connectors: ConnectorManager::new(),
+            nodes: Vec::new(),
+            owners: Vec::new(),
+            evaluated: HashSet::new(),
+            evaluating: Vec::new(),
+            seed: 0,
+        }
+    }
+
+    pub fn add_node<T>(&mut self, node: T) -> usize
+    where
+        T: Node + 'static
+    {
+        let index = self.nodes.len();
+        let name = node.get_name();
+        let mut inputs = Vec::new();
+        let mut outputs = Vec::new();
+        let mut values = Vec::new();
+
+        for port in node.get_inputs() {
+            let connector_name = format!("{}.{}", name, port.get_name());
+
+            inputs.push(self.add_connector(index, connector_name, port.get_value(), port.get_type(), false));
+            values.push(port.get_value().get_variant(port.get_type()));
+        }
+
+        for port in node.get_outputs() {
+            let connector_name = format!("{}.{}", name, port.get_name());
+
+            outputs.push(self.add_connector(index, connector_name, port.get_value(), port.get_type(), true));
+        }
+
+        self.nodes.push(NodeInstance::new(Rc::new(node), inputs, outputs, values));
+
+        index
+    }
+
+    pub fn get_node(&self, index: usize) -> Option<&NodeInstance> {
+        self.nodes.get(index)
+    }
+
+    pub fn get_node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Seed of the blueprint, to be saved with it.
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+    }
+
+    /// Random number stream of a node, it starts over every time it is requested.
+    pub fn get_random(&self, node: usize) -> Random {
+        let mut stream = node as u64;
+
+        Random::new(self.seed ^ split_mix(&mut stream))
+    }
+
+    /// Internal state of a node, to be saved with the blueprint.
+    pub fn get_node_state(&self, index: usize) -> Vec<u8> {
+        match self.nodes.get(index) {
+            Some(node) => node.get_node().get_state(),
+            None => Vec::new(),
+        }
+    }
+
+    pub fn set_node_state(&mut self, index: usize, state: &[u8]) -> bool {
+        match self.nodes.get(index) {
+            Some(node) => node.get_node().set_state(state),
+            None => false,
+        }
+    }
+
+    pub fn merge_node_state(&mut self, index: usize, state: &[u8]) -> bool {
+        match self.nodes.get(index) {
+            Some(node) => node.get_node().merge_state(state),
+            None => false,
+        }
+    }
+
+    /// Connects an output of one node to an input of another.\
+    /// An input that is already connected is reconnected.
+    pub fn connect(&mut self, output_node: usize, output_slot: usize, input_node: usize, input_slot: usize) {
+        let output = self.get_output_connector(output_node, output_slot);
+        let input = self.get_input_connector(input_node, input_slot);
+
+        if let (Some(output), Some(input)) = (output, input) {
+            self.connectors.connect(input, output);
+        }
+    }
+
+    pub fn disconnect(&mut self, node: usize, input_slot: usize) {
+        if let Some(input) = self.get_input_connector(node, input_slot) {
+            if self.connectors.get_connector(input).unwrap().has_edge() {
+                self.connectors.disconnect(input);
+            }
+        }
+    }
+
+    /// Sets the value used by an input while it is not connected.
+    pub fn set_input_value<T>(&mut self, node: usize, slot: usize, value: T)
+    where
+        T: Into<ConnectorType>
+    {
+        let connector = match self.get_input_connector(node, slot) {
+            Some(connector) => connector,
+            None => return,
+        };
+
+        let connector_type = self.connectors.get_connector(connector).unwrap().get_type();
+        let value: ConnectorType = value.into();
+
+        self.nodes[node].set_value(slot, value.get_variant(connector_type));
+    }
+
+    /// Reads an input of a node, evaluating the node connected to it first.
+    pub fn get_input(&mut self, node: usize, slot: usize) -> ConnectorType {
+        let connector = match self.get_input_connector(node, slot) {
+            Some(connector) => connector,
+            None => return ConnectorType::Null(Null::new()),
+        };
+
+        match self.connectors.get_connector(connector).unwrap().get_connected().first() {
+            Some(output) => {
+                let owner = self.owners[*output];
+
+                self.evaluate_node(owner);
+                self.connectors.get_value(connector)
+            },
+            None => self.nodes[node].get_value(slot),
+        }
+    }
+
+    /// Last computed value of an output.
+    pub fn get_output(&self, node: usize, slot: usize) -> ConnectorType {
+        match self.get_output_connector(node, slot) {
+            Some(connector) => self.connectors.get_value(connector),
+            None => ConnectorType::Null(Null::new()),
+        }
+    }
+
+    /// Evaluates a node and everything it depends on, returning its outputs.
+    pub fn evaluate(&mut self, index: usize) -> Vec<ConnectorType> {
+        if index >= self.nodes.len() {
+            return Vec::new();
+        }
+
+        self.evaluated.clear();
+        self.evaluate_node(index);
+
+        (0..self.nodes[index].get_outputs().len()).map(|slot| self.get_output(index, slot)).collect()
+    }
+
+    fn evaluate_node(&mut self, index: usize) {
+        // A node reading the output of a node still being evaluated gets the value it currently holds
+        if self.evaluated.contains(&index) || self.evaluating.contains(&index) {
+            return;
+        }
+
+        self.evaluating.push(index);
+
+        let node = self.nodes[index].get_node();
+        let values = node.compute(&mut Context::new(self, index));
+
+        for (connector, value) in self.nodes[index].get_outputs().into_iter().zip(values) {
+            self.connectors.set_value(connector, value);
+        }
+
+        self.evaluating.pop();
+        self.evaluated.insert(index);
+    }
+
+    fn add_connector(&mut self, node: usize, name: String, value: ConnectorType, connector_type: i32, is_output: bool) -> usize {
+        let index = self.connectors.add_connector(name, value, connector_type, is_output);
+
+        self.owners.push(node);
+
+        index
+    }
+
+    fn get_input_connector(&self, node: usize, slot: usize) -> Option<usize> {
+        self.nodes.get(node).and_then(|node| node.get_inputs().get(slot).copied())
+    }
+
+    fn get_output_connector(&self, node: usize, slot: usize) -> Option<usize> {
+        self.nodes.get(node).and_then(|node| node.get_outputs().get(slot).copied())
+    }
+}
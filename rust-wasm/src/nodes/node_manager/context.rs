@@ -0,0 +1,36 @@
+use super::Manager;
+use crate::helper::random::Random;
+use crate::nodes::connector_manager::value::ConnectorType;
+
+/// Handed to a node while it computes.\
+/// Reading an input evaluates the node connected to it first.
+pub struct Context<'a> {
+    manager: &'a mut Manager,
+    node: usize,
+}
+
+impl<'a> Context<'a> {
+    pub fn new(manager: &'a mut Manager, node: usize) -> Context<'a> {
+        Context {
+            manager,
+            node,
+        }
+    }
+
+    pub fn get_input(&mut self, slot: usize) -> ConnectorType {
+        self.manager.get_input(self.node, slot)
+    }
+
+    pub fn get_inputs(&mut self) -> Vec<ConnectorType> {
+        (0..self.get_input_count()).map(|slot| self.get_input(slot)).collect()
+    }
+
+    /// Random number stream of the node, the same on every evaluation.
+    pub fn get_random(&self) -> Random {
+        self.manager.get_random(self.node)
+    }
+
+    pub fn get_input_count(&self) -> usize {
+        self.manager.get_node(self.node).map(|node| node.get_inputs().len()).unwrap_or(0)
+    }
+}
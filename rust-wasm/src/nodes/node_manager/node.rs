@@ -0,0 +1,173 @@
+use std::rc::Rc;
+use super::context::Context;
+use crate::nodes::connector_manager::value::ConnectorType;
+
+/// Describes a single input or output of a node.\
+/// The value is used as the default for inputs that are not connected and as the initial value for outputs.
+pub struct Port {
+    name: String,
+    value: ConnectorType,
+    connector_type: i32,
+}
+
+impl Port {
+    pub fn new<T>(name: &str, value: T, connector_type: i32) -> Port
+    where
+        T: Into<ConnectorType>
+    {
+        Port {
+            name: name.to_string(),
+            value: value.into(),
+            connector_type,
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_value(&self) -> ConnectorType {
+        self.value.clone()
+    }
+
+    pub fn get_type(&self) -> i32 {
+        self.connector_type
+    }
+}
+
+/// A computation that can be placed in a blueprint.\
+/// Nodes only describe their ports and how outputs are computed from inputs,
+/// the node manager owns the connectors backing the ports.
+///
+/// # Example
+/// ```
+/// struct Double {}
+///
+/// impl Node for Double {
+///     fn get_name(&self) -> String {
+///         "Double".to_string()
+///     }
+///
+///     fn get_inputs(&self) -> Vec<Port> {
+///         vec![Port::new("Value", 0, ConnectorTypeId::INT)]
+///     }
+///
+///     fn get_outputs(&self) -> Vec<Port> {
+///         vec![Port::new("Result", 0, ConnectorTypeId::INT)]
+///     }
+///
+///     fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+///         vec![context.get_input(0) * 2]
+///     }
+/// }
+/// ```
+pub trait Node {
+    fn get_name(&self) -> String;
+
+    fn get_inputs(&self) -> Vec<Port>;
+
+    fn get_outputs(&self) -> Vec<Port>;
+
+    /// Returns one value per output port, in port order.\
+    /// Inputs are pulled through the context, so inputs that are never read are never evaluated.
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType>;
+
+    /// Internal state saved with the blueprint, stateless nodes have none.
+    fn get_state(&self) -> Vec<u8> {
+        Vec::new()
+    }
+
+    /// Restores state returned by `get_state`, returns false when the state can't be read.
+    fn set_state(&self, state: &[u8]) -> bool {
+        state.is_empty()
+    }
+
+    /// Merges state returned by `get_state` of another node of the same kind into this node.\
+    /// Returns false when the state can't be read or the node has no mergeable state.
+    fn merge_state(&self, _state: &[u8]) -> bool {
+        false
+    }
+}
+
+/// Boxed nodes are nodes too, so nodes chosen at runtime can be added to a blueprint.
+impl Node for Box<dyn Node> {
+    fn get_name(&self) -> String {
+        self.as_ref().get_name()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        self.as_ref().get_inputs()
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        self.as_ref().get_outputs()
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        self.as_ref().compute(context)
+    }
+
+    fn get_state(&self) -> Vec<u8> {
+        self.as_ref().get_state()
+    }
+
+    fn set_state(&self, state: &[u8]) -> bool {
+        self.as_ref().set_state(state)
+    }
+
+    fn merge_state(&self, state: &[u8]) -> bool {
+        self.as_ref().merge_state(state)
+    }
+}
+
+/// A node placed in a blueprint together with the connectors backing its ports.
+pub struct NodeInstance {
+    node: Rc<dyn Node>,
+    inputs: Vec<usize>,
+    outputs: Vec<usize>,
+    values: Vec<ConnectorType>,
+}
+
+impl NodeInstance {
+    pub fn new(node: Rc<dyn Node>, inputs: Vec<usize>, outputs: Vec<usize>, values: Vec<ConnectorType>) -> NodeInstance {
+        NodeInstance {
+            node,
+            inputs,
+            outputs,
+            values,
+        }
+    }
+
+    pub fn get_node(&self) -> Rc<dyn Node> {
+        self.node.clone()
+    }
+
+    pub fn get_name(&self) -> String {
+        self.node.get_name()
+    }
+
+    /// Connector indices of the inputs, in port order.
+    pub fn get_inputs(&self) -> Vec<usize> {
+        self.inputs.clone()
+    }
+
+    /// Connector indices of the outputs, in port order.
+    pub fn get_outputs(&self) -> Vec<usize> {
+        self.outputs.clone()
+    }
+
+    /// Value used for an input while it is not connected.
+    pub fn get_value(&self, slot: usize) -> ConnectorType {
+        self.values[slot].clone()
+    }
+
+    pub fn set_value(&mut self, slot: usize, value: ConnectorType) {
+        self.values[slot] = value;
+    }
+}
+
+impl core::fmt::Debug for NodeInstance {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "NodeInstance {{ name: {}, inputs: {:?}, outputs: {:?} }}", self.get_name(), self.inputs, self.outputs)
+    }
+}
@@ -0,0 +1,226 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use super::Manager;
+use super::context::Context;
+use super::node::{Node, Port};
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Increment {
+        calls: Rc<Cell<usize>>,
+    }
+
+    impl Node for Increment {
+        fn get_name(&self) -> String {
+            "Increment".to_string()
+        }
+
+        fn get_inputs(&self) -> Vec<Port> {
+            vec![Port::new("Value", 0, ConnectorTypeId::INT)]
+        }
+
+        fn get_outputs(&self) -> Vec<Port> {
+            vec![Port::new("Result", 0, ConnectorTypeId::INT)]
+        }
+
+        fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+            self.calls.set(self.calls.get() + 1);
+
+            vec![context.get_input(0) + 1]
+        }
+    }
+
+    struct Sum {}
+
+    impl Node for Sum {
+        fn get_name(&self) -> String {
+            "Sum".to_string()
+        }
+
+        fn get_inputs(&self) -> Vec<Port> {
+            vec![Port::new("A", 0, ConnectorTypeId::INT), Port::new("B", 0, ConnectorTypeId::INT)]
+        }
+
+        fn get_outputs(&self) -> Vec<Port> {
+            vec![Port::new("Result", 0, ConnectorTypeId::INT)]
+        }
+
+        fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+            vec![context.get_input(0) + context.get_input(1)]
+        }
+    }
+
+    /// Only reads `B` when `A` is zero.
+    struct Fallback {}
+
+    impl Node for Fallback {
+        fn get_name(&self) -> String {
+            "Fallback".to_string()
+        }
+
+        fn get_inputs(&self) -> Vec<Port> {
+            vec![Port::new("A", 0, ConnectorTypeId::INT), Port::new("B", 0, ConnectorTypeId::INT)]
+        }
+
+        fn get_outputs(&self) -> Vec<Port> {
+            vec![Port::new("Result", 0, ConnectorTypeId::INT)]
+        }
+
+        fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+            match context.get_input(0) {
+                ConnectorType::Integer(0) => vec![context.get_input(1)],
+                value => vec![value],
+            }
+        }
+    }
+
+    /// Outputs a random integer from its stream.
+    struct Draw {}
+
+    impl Node for Draw {
+        fn get_name(&self) -> String {
+            "Draw".to_string()
+        }
+
+        fn get_inputs(&self) -> Vec<Port> {
+            Vec::new()
+        }
+
+        fn get_outputs(&self) -> Vec<Port> {
+            vec![Port::new("Result", 0, ConnectorTypeId::INT)]
+        }
+
+        fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+            vec![ConnectorType::Integer(context.get_random().next_u64() as i32)]
+        }
+    }
+
+    fn increment(manager: &mut Manager) -> (usize, Rc<Cell<usize>>) {
+        let calls = Rc::new(Cell::new(0));
+        let node = manager.add_node(Increment { calls: calls.clone() });
+
+        (node, calls)
+    }
+
+    #[test]
+    fn node_evaluation() {
+        let mut manager = Manager::new();
+
+        let (first, _) = increment(&mut manager);
+        let (second, _) = increment(&mut manager);
+
+        manager.set_input_value(first, 0, 5);
+        manager.connect(first, 0, second, 0);
+
+        assert_eq!(manager.evaluate(second), vec![ConnectorType::new(7)], "Second node should add to the first");
+        assert_eq!(manager.get_output(first, 0), 6, "First node should have been evaluated");
+
+        manager.disconnect(second, 0);
+
+        assert_eq!(manager.evaluate(second), vec![ConnectorType::new(1)], "Disconnected input should use its default");
+    }
+
+    #[test]
+    fn node_input_conversion() {
+        let mut manager = Manager::new();
+
+        let (node, _) = increment(&mut manager);
+
+        manager.set_input_value(node, 0, "41");
+
+        assert_eq!(manager.evaluate(node), vec![ConnectorType::new(42)], "Input value should be converted to the port type");
+    }
+
+    #[test]
+    fn node_evaluated_once() {
+        let mut manager = Manager::new();
+
+        let (source, calls) = increment(&mut manager);
+        let sum = manager.add_node(Sum {});
+
+        manager.connect(source, 0, sum, 0);
+        manager.connect(source, 0, sum, 1);
+
+        assert_eq!(manager.evaluate(sum), vec![ConnectorType::new(2)]);
+        assert_eq!(calls.get(), 1, "Shared upstream node should be evaluated once");
+
+        manager.evaluate(sum);
+
+        assert_eq!(calls.get(), 2, "Every evaluation should recompute");
+    }
+
+    #[test]
+    fn node_lazy_inputs() {
+        let mut manager = Manager::new();
+
+        let (first, first_calls) = increment(&mut manager);
+        let (second, second_calls) = increment(&mut manager);
+        let fallback = manager.add_node(Fallback {});
+
+        manager.connect(first, 0, fallback, 0);
+        manager.connect(second, 0, fallback, 1);
+
+        assert_eq!(manager.evaluate(fallback), vec![ConnectorType::new(1)]);
+        assert_eq!(first_calls.get(), 1);
+        assert_eq!(second_calls.get(), 0, "Unread input should not be evaluated");
+    }
+
+    #[test]
+    fn node_reconnection() {
+        let mut manager = Manager::new();
+
+        let (first, _) = increment(&mut manager);
+        let (second, _) = increment(&mut manager);
+        let sum = manager.add_node(Sum {});
+
+        manager.set_input_value(second, 0, 10);
+        manager.connect(first, 0, sum, 0);
+        manager.connect(first, 0, sum, 1);
+
+        assert_eq!(manager.evaluate(sum), vec![ConnectorType::new(2)]);
+
+        manager.connect(second, 0, sum, 1);
+
+        assert_eq!(manager.evaluate(sum), vec![ConnectorType::new(12)], "Input B should follow the new connection");
+    }
+
+    #[test]
+    fn node_invalid_indices() {
+        let mut manager = Manager::new();
+
+        let (node, _) = increment(&mut manager);
+
+        manager.connect(node, 3, node, 0);
+        manager.set_input_value(node, 4, 1);
+
+        assert!(manager.evaluate(node + 1).is_empty());
+        assert!(manager.get_output(node, 1).is_null());
+    }
+
+    #[test]
+    fn node_random_streams() {
+        let mut manager = Manager::new();
+
+        let first = manager.add_node(Draw {});
+        let second = manager.add_node(Draw {});
+        let draw = manager.evaluate(first);
+
+        assert_eq!(manager.evaluate(first), draw, "Every evaluation should draw the same numbers");
+        assert_ne!(manager.evaluate(second), draw, "Nodes should have their own streams");
+
+        let mut other = Manager::new();
+
+        other.add_node(Draw {});
+        other.add_node(Draw {});
+
+        assert_eq!(other.evaluate(first), draw, "Adding nodes should keep the draws of the others");
+
+        other.set_seed(1);
+
+        assert_eq!(other.get_seed(), 1);
+        assert_ne!(other.evaluate(first), draw, "The seed should change the draws");
+    }
+}
@@ -0,0 +1,396 @@
+use std::fmt::Debug;
+use super::connector_manager::record::Record;
+use super::connector_manager::value::ConnectorType;
+use super::expression::ExpressionNode;
+use super::io::arrow::ArrowImportNode;
+use super::io::csv::CsvImportNode;
+use super::io::json::JsonImportNode;
+use super::io::parquet::ParquetImportNode;
+use super::io::sqlite::SqliteImportNode;
+use super::logic::{BetweenNode, Comparison, ComparisonNode, IfNode, IsNullNode, LogicNode, LogicOperation, NotNode, SwitchNode};
+use super::math::{BinaryNode, BinaryOperation, ClampNode, UnaryNode, UnaryOperation};
+use super::node_manager::node::Node;
+use super::relational::columns::{DropColumnsNode, RenameColumnsNode, SelectColumnsNode};
+use super::relational::derive::DeriveColumnNode;
+use super::relational::filter::FilterNode;
+use super::relational::group::{Aggregation, ColumnAggregation, GroupByNode};
+use super::relational::join::{JoinKind, JoinNode};
+use super::relational::pivot::{PivotNode, UnpivotNode};
+use super::relational::rows::{DistinctNode, End, SliceNode, TakeNode};
+use super::relational::sort::{NullPlacement, SortKey, SortNode};
+use super::statistics::correlation::{CorrelationMatrixNode, CorrelationMethod, CorrelationNode, CovarianceMatrixNode};
+use super::statistics::descriptive::{KahanSum, Moments, QuantileNode, Statistic, StatisticNode, SummaryNode};
+use super::statistics::distribution::{DistributionFunction, DistributionKind, DistributionNode, SampleNode};
+use super::statistics::histogram::{BinStrategy, HistogramNode};
+use super::statistics::hypothesis::{HypothesisTest, HypothesisTestNode};
+use super::statistics::online::{AccumulatorNode, Extrema, HyperLogLog, TDigest};
+use super::statistics::regression::{RegressionModel, RegressionNode};
+use super::statistics::resampling::{Alternative, BootstrapNode, PermutationTestNode, StatisticGraph};
+use super::statistics::sampling::{BootstrapResampleNode, RandomSampleNode, ShuffleNode, TrainTestSplitNode};
+use super::statistics::{NullHandling, get_number};
+use super::text::basic::{ConcatNode, JoinTextNode, PadNode, ReplaceNode, Search, SearchNode, Side, SplitNode, SubstringNode, TextNode, TextOperation};
+use super::text::format::{FormatDateNode, FormatNumberNode};
+use super::text::pattern::{RegexNode, RegexOperation};
+use super::timeseries::resample::ResampleNode;
+use super::timeseries::rolling::{RollingAggregation, RollingNode, Window};
+use super::timeseries::smoothing::{ExponentialSmoothingNode, Seasonality, SmoothingModel};
+use super::timeseries::transform::{Cumulative, CumulativeNode, DifferenceNode, PercentChangeNode, Shift, ShiftNode};
+
+const NULL_HANDLINGS: [NullHandling; 3] = [NullHandling::Skip, NullHandling::Propagate, NullHandling::Zero];
+const AGGREGATIONS: [Aggregation; 9] = [
+    Aggregation::Count,
+    Aggregation::Sum,
+    Aggregation::Mean,
+    Aggregation::Min,
+    Aggregation::Max,
+    Aggregation::Median,
+    Aggregation::DistinctCount,
+    Aggregation::First,
+    Aggregation::Last,
+];
+
+fn get_text<'a>(options: &'a Record, field: &str) -> Option<&'a str> {
+    match options.get_field(field) {
+        Some(ConnectorType::String(text)) => Some(text),
+        _ => None,
+    }
+}
+
+fn get_list<'a>(options: &'a Record, field: &str) -> &'a [ConnectorType] {
+    match options.get_field(field) {
+        Some(ConnectorType::List(values)) => values,
+        _ => &[],
+    }
+}
+
+fn get_flag(options: &Record, field: &str) -> bool {
+    matches!(options.get_field(field), Some(ConnectorType::Boolean(true)))
+}
+
+/// Variant of an enum named by a text field, as it is written by `Debug`.
+fn get_variant<T>(options: &Record, field: &str, variants: &[T]) -> Option<T>
+where
+    T: Clone + Debug
+{
+    let name = get_text(options, field)?;
+
+    variants.iter().find(|variant| format!("{:?}", variant) == name).cloned()
+}
+
+/// `Nulls` defaults to skipping nulls.
+fn get_null_handling(options: &Record) -> NullHandling {
+    get_variant(options, "Nulls", &NULL_HANDLINGS).unwrap_or(NullHandling::Skip)
+}
+
+/// `Concat` takes its separator from `Separator`.
+fn get_aggregation(options: &Record) -> Option<Aggregation> {
+    match get_text(options, "Aggregation")? {
+        "Concat" => Some(Aggregation::Concat(get_text(options, "Separator").unwrap_or(", ").to_string())),
+        _ => get_variant(options, "Aggregation", &AGGREGATIONS),
+    }
+}
+
+fn get_bin_strategy(options: &Record) -> Option<BinStrategy> {
+    let number = |field: &str| options.get_field(field).and_then(get_number);
+
+    match get_text(options, "Bins").unwrap_or("Sturges") {
+        "Count" => Some(BinStrategy::Count(number("Count")?.max(1.0) as usize)),
+        "Width" => Some(BinStrategy::Width(number("Width")?)),
+        "Edges" => Some(BinStrategy::Edges(get_list(options, "Edges").iter().map(get_number).collect::<Option<_>>()?)),
+        "Sturges" => Some(BinStrategy::Sturges),
+        "Scott" => Some(BinStrategy::Scott),
+        "FreedmanDiaconis" => Some(BinStrategy::FreedmanDiaconis),
+        _ => None,
+    }
+}
+
+fn get_smoothing_model(options: &Record) -> Option<SmoothingModel> {
+    match get_text(options, "Model")? {
+        "Simple" => Some(SmoothingModel::Simple),
+        "Holt" => Some(SmoothingModel::Holt),
+        "HoltWinters" => Some(SmoothingModel::HoltWinters(get_variant(options, "Seasonality", &[Seasonality::Additive, Seasonality::Multiplicative])?)),
+        _ => None,
+    }
+}
+
+fn get_records(options: &Record, field: &str) -> Option<Vec<Record>> {
+    get_list(options, field).iter().map(|value| match value {
+        ConnectorType::Record(record) => Some(record.clone()),
+        _ => None,
+    }).collect()
+}
+
+/// Creates a node from the name of its kind, which is the name of its type without `Node`,
+/// and the options its constructor takes. Options are named fields, enum variants are written as in Rust.\
+/// Returns `None` for unknown kinds, missing options and nodes computed by a graph, see `create_graph_node`.
+///
+/// # Example
+/// ```
+/// let options = parse_json(r#"{"Operation": "Add"}"#);
+///
+/// let add = create_node("Binary", &options);
+/// ```
+pub fn create_node(kind: &str, options: &Record) -> Option<Box<dyn Node>> {
+    let variant = |field: &str| get_text(options, field);
+    let node: Box<dyn Node> = match kind {
+        "Binary" => {
+            let operations = [
+                BinaryOperation::Add,
+                BinaryOperation::Subtract,
+                BinaryOperation::Multiply,
+                BinaryOperation::Divide,
+                BinaryOperation::Modulo,
+                BinaryOperation::Power,
+                BinaryOperation::Min,
+                BinaryOperation::Max,
+                BinaryOperation::Atan2,
+            ];
+
+            Box::new(BinaryNode::new(get_variant(options, "Operation", &operations)?))
+        },
+        "Unary" => {
+            let operations = [
+                UnaryOperation::Abs,
+                UnaryOperation::Round,
+                UnaryOperation::Floor,
+                UnaryOperation::Ceil,
+                UnaryOperation::Sqrt,
+                UnaryOperation::Log,
+                UnaryOperation::Log10,
+                UnaryOperation::Exp,
+                UnaryOperation::Sin,
+                UnaryOperation::Cos,
+                UnaryOperation::Tan,
+                UnaryOperation::Asin,
+                UnaryOperation::Acos,
+                UnaryOperation::Atan,
+            ];
+
+            Box::new(UnaryNode::new(get_variant(options, "Operation", &operations)?))
+        },
+        "Clamp" => Box::new(ClampNode::new()),
+        "Comparison" => {
+            let comparisons = [
+                Comparison::Equal,
+                Comparison::NotEqual,
+                Comparison::Less,
+                Comparison::LessEqual,
+                Comparison::Greater,
+                Comparison::GreaterEqual,
+            ];
+
+            Box::new(ComparisonNode::new(get_variant(options, "Comparison", &comparisons)?))
+        },
+        "Between" => Box::new(BetweenNode::new()),
+        "IsNull" => Box::new(IsNullNode::new()),
+        "Logic" => Box::new(LogicNode::new(get_variant(options, "Operation", &[LogicOperation::And, LogicOperation::Or, LogicOperation::Xor])?)),
+        "Not" => Box::new(NotNode::new()),
+        "If" => Box::new(IfNode::new()),
+        "Switch" => Box::new(SwitchNode::new(get_list(options, "Cases").to_vec())),
+        "Expression" => Box::new(ExpressionNode::new(variant("Formula")?)),
+        "Statistic" => Box::new(StatisticNode::new(get_variant(options, "Statistic", &Statistic::ALL)?, get_null_handling(options))),
+        "Summary" => Box::new(SummaryNode::new(get_null_handling(options))),
+        "Quantile" => Box::new(QuantileNode::new(get_null_handling(options))),
+        "Histogram" => Box::new(HistogramNode::new(get_bin_strategy(options)?, get_flag(options, "Density"), get_null_handling(options))),
+        "Correlation" | "CorrelationMatrix" => {
+            let method = get_variant(options, "Method", &[CorrelationMethod::Pearson, CorrelationMethod::Spearman, CorrelationMethod::Kendall])?;
+
+            match kind {
+                "Correlation" => Box::new(CorrelationNode::new(method)),
+                _ => Box::new(CorrelationMatrixNode::new(method)),
+            }
+        },
+        "CovarianceMatrix" => Box::new(CovarianceMatrixNode::new()),
+        "Distribution" => {
+            let functions = [DistributionFunction::Density, DistributionFunction::Cumulative, DistributionFunction::Quantile];
+
+            Box::new(DistributionNode::new(get_variant(options, "Distribution", &DistributionKind::ALL)?, get_variant(options, "Function", &functions)?))
+        },
+        "Sample" => Box::new(SampleNode::new(get_variant(options, "Distribution", &DistributionKind::ALL)?)),
+        "RandomSample" => Box::new(RandomSampleNode::new(get_flag(options, "Replace"))),
+        "Shuffle" => Box::new(ShuffleNode::new()),
+        "BootstrapResample" => Box::new(BootstrapResampleNode::new()),
+        "TrainTestSplit" => Box::new(TrainTestSplitNode::new()),
+        "Regression" => {
+            let model = match options.get_field("Degree").and_then(get_number) {
+                Some(degree) => RegressionModel::Polynomial(degree.max(1.0) as usize),
+                None => RegressionModel::Linear,
+            };
+
+            Box::new(RegressionNode::new(model, get_flag(options, "Weighted")))
+        },
+        "HypothesisTest" => {
+            let tests = [
+                HypothesisTest::OneSampleT,
+                HypothesisTest::StudentT,
+                HypothesisTest::WelchT,
+                HypothesisTest::PairedT,
+                HypothesisTest::ChiSquaredGoodnessOfFit,
+                HypothesisTest::ChiSquaredIndependence,
+                HypothesisTest::MannWhitneyU,
+                HypothesisTest::KolmogorovSmirnov,
+                HypothesisTest::Anova,
+            ];
+
+            Box::new(HypothesisTestNode::new(get_variant(options, "Test", &tests)?))
+        },
+        "Accumulator" => match variant("Accumulator")? {
+            "KahanSum" => Box::new(AccumulatorNode::new(KahanSum::new())),
+            "Moments" => Box::new(AccumulatorNode::new(Moments::new())),
+            "Extrema" => Box::new(AccumulatorNode::new(Extrema::new())),
+            "TDigest" => {
+                let compression = options.get_field("Compression").and_then(get_number).unwrap_or(100.0);
+                let quantiles = get_list(options, "Quantiles").iter().filter_map(get_number).collect();
+
+                Box::new(AccumulatorNode::new(TDigest::new(compression, quantiles)))
+            },
+            "HyperLogLog" => Box::new(AccumulatorNode::new(HyperLogLog::new(options.get_field("Precision").and_then(get_number).unwrap_or(12.0) as u8))),
+            _ => return None,
+        },
+        "Shift" => Box::new(ShiftNode::new(get_variant(options, "Direction", &[Shift::Lag, Shift::Lead])?)),
+        "Difference" => Box::new(DifferenceNode::new()),
+        "PercentChange" => Box::new(PercentChangeNode::new()),
+        "Cumulative" => Box::new(CumulativeNode::new(get_variant(options, "Cumulative", &[Cumulative::Sum, Cumulative::Product, Cumulative::Min, Cumulative::Max])?)),
+        "Resample" => Box::new(ResampleNode::new(get_variant(options, "Statistic", &Statistic::ALL)?, get_null_handling(options))),
+        "Rolling" => {
+            let aggregations = [
+                RollingAggregation::Count,
+                RollingAggregation::Sum,
+                RollingAggregation::Mean,
+                RollingAggregation::Min,
+                RollingAggregation::Max,
+                RollingAggregation::StandardDeviation,
+            ];
+
+            Box::new(RollingNode::new(get_variant(options, "Aggregation", &aggregations)?, get_variant(options, "Window", &[Window::Count, Window::Time])?))
+        },
+        "ExponentialSmoothing" => Box::new(ExponentialSmoothingNode::new(get_smoothing_model(options)?)),
+        "Join" => {
+            let kinds = [JoinKind::Inner, JoinKind::Left, JoinKind::Right, JoinKind::Full, JoinKind::Semi, JoinKind::Anti];
+
+            Box::new(JoinNode::new(get_variant(options, "Join", &kinds)?))
+        },
+        "Filter" => Box::new(FilterNode::new()),
+        "Sort" => {
+            let keys = get_records(options, "Keys")?.iter().map(|key| {
+                let nulls = get_variant(key, "Nulls", &[NullPlacement::First, NullPlacement::Last]).unwrap_or(NullPlacement::Last);
+
+                Some(SortKey::new(get_text(key, "Column")?, get_flag(key, "Descending"), nulls))
+            }).collect::<Option<_>>()?;
+
+            Box::new(SortNode::new(keys))
+        },
+        "GroupBy" => {
+            let aggregations = get_records(options, "Aggregations")?.iter()
+                .map(|aggregation| Some(ColumnAggregation::new(get_text(aggregation, "Column")?, get_aggregation(aggregation)?)))
+                .collect::<Option<_>>()?;
+
+            Box::new(GroupByNode::new(aggregations))
+        },
+        "SelectColumns" => Box::new(SelectColumnsNode::new()),
+        "DropColumns" => Box::new(DropColumnsNode::new()),
+        "RenameColumns" => Box::new(RenameColumnsNode::new()),
+        "Pivot" => Box::new(PivotNode::new(get_aggregation(options)?)),
+        "Unpivot" => Box::new(UnpivotNode::new()),
+        "Take" => Box::new(TakeNode::new(get_variant(options, "End", &[End::Head, End::Tail])?)),
+        "Slice" => Box::new(SliceNode::new()),
+        "Distinct" => Box::new(DistinctNode::new()),
+        "Text" => {
+            let operations = [
+                TextOperation::Upper,
+                TextOperation::Lower,
+                TextOperation::Trim,
+                TextOperation::TrimStart,
+                TextOperation::TrimEnd,
+                TextOperation::Length,
+            ];
+
+            Box::new(TextNode::new(get_variant(options, "Operation", &operations)?))
+        },
+        "Concat" => Box::new(ConcatNode::new()),
+        "Split" => Box::new(SplitNode::new()),
+        "JoinText" => Box::new(JoinTextNode::new()),
+        "Substring" => Box::new(SubstringNode::new()),
+        "Replace" => Box::new(ReplaceNode::new()),
+        "Search" => Box::new(SearchNode::new(get_variant(options, "Search", &[Search::Contains, Search::StartsWith, Search::EndsWith])?)),
+        "Pad" => Box::new(PadNode::new(get_variant(options, "Side", &[Side::Start, Side::End])?)),
+        "Regex" => Box::new(RegexNode::new(get_variant(options, "Operation", &[RegexOperation::Match, RegexOperation::Extract, RegexOperation::Replace])?)),
+        "FormatNumber" => Box::new(FormatNumberNode::new()),
+        "FormatDate" => Box::new(FormatDateNode::new()),
+        "CsvImport" => Box::new(CsvImportNode::new()),
+        "JsonImport" => Box::new(JsonImportNode::new()),
+        "ParquetImport" => Box::new(ParquetImportNode::new()),
+        "ArrowImport" => Box::new(ArrowImportNode::new()),
+        "SqliteImport" => Box::new(SqliteImportNode::new()),
+        _ => return None,
+    };
+
+    Some(node)
+}
+
+/// Creates a node computed by a graph of nodes, with the same options as `create_node`.\
+/// Returns `None` for kinds that don't take a graph.
+pub fn create_graph_node(kind: &str, options: &Record, graph: StatisticGraph) -> Option<Box<dyn Node>> {
+    let node: Box<dyn Node> = match kind {
+        "DeriveColumn" => Box::new(DeriveColumnNode::new(graph)),
+        "Bootstrap" => Box::new(BootstrapNode::new(graph)),
+        "PermutationTest" => {
+            let alternative = get_variant(options, "Alternative", &[Alternative::TwoSided, Alternative::Less, Alternative::Greater]);
+
+            Box::new(PermutationTestNode::new(graph, alternative.unwrap_or(Alternative::TwoSided)))
+        },
+        _ => return None,
+    };
+
+    Some(node)
+}
+
+#[cfg(test)]
+mod registry_test {
+    use super::*;
+    use crate::helper::testing::evaluate_table;
+    use crate::nodes::connector_manager::table::{Column, Table};
+    use crate::nodes::connector_manager::value::ConnectorTypeId;
+    use crate::nodes::io::json::parse_json;
+    use crate::nodes::node_manager::Manager;
+
+    fn get_options(json: &str) -> Record {
+        match parse_json(json).unwrap() {
+            ConnectorType::Record(record) => record,
+            value => panic!("{:?} should be a record", value),
+        }
+    }
+
+    #[test]
+    fn test_create_node() {
+        let name = |kind: &str, json: &str| create_node(kind, &get_options(json)).map(|node| node.get_name());
+
+        assert_eq!(name("Binary", r#"{"Operation": "Add"}"#), Some("Add".to_string()));
+        assert_eq!(name("Distribution", r#"{"Distribution": "Poisson", "Function": "Density"}"#), Some("Poisson PMF".to_string()));
+        assert_eq!(name("Regression", r#"{"Degree": 2, "Weighted": true}"#), Some("Weighted Polynomial Regression (2)".to_string()));
+        assert_eq!(name("ExponentialSmoothing", r#"{"Model": "HoltWinters", "Seasonality": "Additive"}"#), Some("Holt-Winters Additive Smoothing".to_string()));
+        assert_eq!(name("Accumulator", r#"{"Accumulator": "HyperLogLog"}"#), Some("Running Distinct Count".to_string()));
+        assert_eq!(name("SqliteImport", "{}"), Some("SQLite Import".to_string()));
+        assert_eq!(name("Binary", r#"{"Operation": "Unknown"}"#), None);
+        assert_eq!(name("Binary", "{}"), None);
+        assert_eq!(name("Unknown", "{}"), None);
+        assert_eq!(name("Bootstrap", "{}"), None);
+    }
+
+    #[test]
+    fn test_configured_nodes() {
+        let mut manager = Manager::new();
+        let sort = manager.add_node(create_node("Sort", &get_options(r#"{"Keys": [{"Column": "A", "Descending": true}]}"#)).unwrap());
+        let group = manager.add_node(create_node("GroupBy", &get_options(r#"{"Aggregations": [{"Column": "A", "Aggregation": "Concat", "Separator": "/"}]}"#)).unwrap());
+
+        manager.set_input_value(sort, 0, Table::from_columns(vec![
+            Column::new("A", ConnectorTypeId::INT, vec![1, 2]),
+            Column::new("B", ConnectorTypeId::STRING, vec!["x", "x"]),
+        ]).unwrap());
+        manager.connect(sort, 0, group, 0);
+        manager.set_input_value(group, 1, "B");
+
+        assert_eq!(evaluate_table(&mut manager, group).get_column("A Concat").unwrap().get_values(), [ConnectorType::new("2/1")]);
+        assert!(create_node("Sort", &get_options(r#"{"Keys": [{"Descending": true}]}"#)).is_none());
+    }
+}
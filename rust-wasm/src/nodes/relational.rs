@@ -0,0 +1,86 @@
+pub mod columns;
+pub mod derive;
+pub mod filter;
+pub mod group;
+pub mod join;
+pub mod pivot;
+pub mod rows;
+pub mod sort;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use super::connector_manager::table::{Column, Table};
+use super::connector_manager::value::ConnectorType;
+use super::statistics::online::hash_value;
+use self::sort::total_cmp;
+
+/// Value a key value is compared and hashed as, `None` when it is compared as it is.\
+/// Whole floats that fit in an `Integer` are integers, so `1.0` is the key `1` and `-0.0` is `0`, and every `NaN` is the same.
+fn normalise(value: &ConnectorType) -> Option<ConnectorType> {
+    match value {
+        ConnectorType::Float(value) if value.is_nan() => Some(ConnectorType::Float(f32::NAN)),
+        ConnectorType::Float(value) if value.fract() == 0.0 && *value >= i32::MIN as f32 && *value < -(i32::MIN as f32) => Some(ConnectorType::Integer(*value as i32)),
+        _ => None,
+    }
+}
+
+/// Values of the key columns of a row, usable as a hash map key.\
+/// Keys are equal when their values are, so `Null` keys are equal to each other.\
+/// Numbers are equal when their values are, whether `Integer` or `Float`, and `NaN` keys are equal to each other.
+/// The values themselves are kept as they are.
+#[derive(Clone, Debug)]
+pub struct Key {
+    values: Vec<ConnectorType>,
+}
+
+impl Key {
+    pub fn new(values: Vec<ConnectorType>) -> Key {
+        Key {
+            values,
+        }
+    }
+
+    /// Key of a row from the given columns.
+    pub fn from_row(columns: &[&Column], row: usize) -> Key {
+        Key::new(columns.iter().map(|column| column.get_values()[row].clone()).collect())
+    }
+
+    pub fn get_values(&self) -> &[ConnectorType] {
+        &self.values
+    }
+
+    pub fn has_null(&self) -> bool {
+        self.values.iter().any(|value| value.is_null())
+    }
+}
+
+impl PartialEq for Key {
+    fn eq(&self, other: &Self) -> bool {
+        self.values.len() == other.values.len() && self.values.iter().zip(&other.values).all(|(lhs, rhs)| {
+            total_cmp(normalise(lhs).as_ref().unwrap_or(lhs), normalise(rhs).as_ref().unwrap_or(rhs)) == Ordering::Equal
+        })
+    }
+}
+
+impl Eq for Key {}
+
+impl Hash for Key {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for value in &self.values {
+            state.write_u64(hash_value(normalise(value).as_ref().unwrap_or(value)));
+        }
+    }
+}
+
+/// Reads a list of column names, a single name is read as a list of one.
+pub fn get_names(value: &ConnectorType) -> Vec<String> {
+    match value {
+        ConnectorType::List(names) => names.iter().map(|name| name.to_string()).collect(),
+        ConnectorType::Null(_) => Vec::new(),
+        name => vec![name.to_string()],
+    }
+}
+
+/// Columns of a table by name, `None` when one of them is missing.
+pub fn get_columns<'a>(table: &'a Table, names: &[String]) -> Option<Vec<&'a Column>> {
+    names.iter().map(|name| table.get_column(name)).collect()
+}
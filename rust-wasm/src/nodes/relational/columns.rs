@@ -0,0 +1,212 @@
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::table::Table;
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+use super::get_names;
+
+/// Table of the named columns, in the order of the names. Returns `None` when a column is missing.
+pub fn select(table: &Table, names: &[String]) -> Option<Table> {
+    Table::from_columns(names.iter().map(|name| table.get_column(name).cloned()).collect::<Option<_>>()?)
+}
+
+/// Table without the named columns, names that aren't in the table are ignored.
+pub fn drop_columns(table: &Table, names: &[String]) -> Table {
+    let kept = table.get_columns().iter().filter(|column| !names.contains(&column.get_name())).cloned().collect();
+
+    Table::from_columns(kept).unwrap_or_default()
+}
+
+/// Renames the `from` columns to the `to` names, pairwise.\
+/// Returns `None` when the lists have different lengths, a column is missing or two columns would share a name.
+pub fn rename(table: &Table, from: &[String], to: &[String]) -> Option<Table> {
+    if from.len() != to.len() {
+        return None;
+    }
+
+    let mut columns = table.get_columns().to_vec();
+
+    for (from, to) in from.iter().zip(to) {
+        columns[table.get_column_index(from)?].set_name(to);
+    }
+
+    let names: Vec<String> = columns.iter().map(|column| column.get_name()).collect();
+
+    match names.iter().enumerate().all(|(index, name)| !names[..index].contains(name)) {
+        true => Table::from_columns(columns),
+        false => None,
+    }
+}
+
+/// Keeps the `Columns` of a `Table`, in the order they are listed.
+pub struct SelectColumnsNode {}
+
+impl SelectColumnsNode {
+    pub fn new() -> SelectColumnsNode {
+        SelectColumnsNode {}
+    }
+}
+
+impl Default for SelectColumnsNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for SelectColumnsNode {
+    fn get_name(&self) -> String {
+        "Select Columns".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Table", Table::new(), ConnectorTypeId::TABLE),
+            Port::new("Columns", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Selected", Table::new(), ConnectorTypeId::TABLE)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let table = match context.get_input(0) {
+            ConnectorType::Table(table) => table,
+            _ => return vec![ConnectorType::Null(Null::new())],
+        };
+
+        match select(&table, &get_names(&context.get_input(1))) {
+            Some(selected) => vec![ConnectorType::Table(selected)],
+            None => vec![ConnectorType::Null(Null::new())],
+        }
+    }
+}
+
+/// Removes the `Columns` from a `Table`.
+pub struct DropColumnsNode {}
+
+impl DropColumnsNode {
+    pub fn new() -> DropColumnsNode {
+        DropColumnsNode {}
+    }
+}
+
+impl Default for DropColumnsNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for DropColumnsNode {
+    fn get_name(&self) -> String {
+        "Drop Columns".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Table", Table::new(), ConnectorTypeId::TABLE),
+            Port::new("Columns", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Remaining", Table::new(), ConnectorTypeId::TABLE)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        match context.get_input(0) {
+            ConnectorType::Table(table) => vec![ConnectorType::Table(drop_columns(&table, &get_names(&context.get_input(1))))],
+            _ => vec![ConnectorType::Null(Null::new())],
+        }
+    }
+}
+
+/// Renames the `Columns` of a `Table` to the `Names` at the same positions.
+pub struct RenameColumnsNode {}
+
+impl RenameColumnsNode {
+    pub fn new() -> RenameColumnsNode {
+        RenameColumnsNode {}
+    }
+}
+
+impl Default for RenameColumnsNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for RenameColumnsNode {
+    fn get_name(&self) -> String {
+        "Rename Columns".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Table", Table::new(), ConnectorTypeId::TABLE),
+            Port::new("Columns", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+            Port::new("Names", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Renamed", Table::new(), ConnectorTypeId::TABLE)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let table = match context.get_input(0) {
+            ConnectorType::Table(table) => table,
+            _ => return vec![ConnectorType::Null(Null::new())],
+        };
+
+        match rename(&table, &get_names(&context.get_input(1)), &get_names(&context.get_input(2))) {
+            Some(renamed) => vec![ConnectorType::Table(renamed)],
+            None => vec![ConnectorType::Null(Null::new())],
+        }
+    }
+}
+
+#[cfg(test)]
+mod columns_test {
+    use super::*;
+    use crate::helper::testing::evaluate_table;
+    use crate::nodes::connector_manager::table::Column;
+    use crate::nodes::node_manager::Manager;
+
+    fn get_table() -> Table {
+        Table::from_columns(vec![
+            Column::new("A", ConnectorTypeId::INT, vec![1, 2]),
+            Column::new("B", ConnectorTypeId::STRING, vec!["x", "y"]),
+            Column::new("C", ConnectorTypeId::FLOAT, vec![0.5, 1.5]),
+        ]).unwrap()
+    }
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_columns() {
+        assert_eq!(select(&get_table(), &names(&["C", "A"])).unwrap().get_column_names(), names(&["C", "A"]));
+        assert_eq!(select(&get_table(), &names(&["D"])), None);
+        assert_eq!(drop_columns(&get_table(), &names(&["B", "D"])).get_column_names(), names(&["A", "C"]));
+        assert_eq!(rename(&get_table(), &names(&["A", "B"]), &names(&["B", "A"])).unwrap().get_column_names(), names(&["B", "A", "C"]));
+        assert_eq!(rename(&get_table(), &names(&["A"]), &names(&["C"])), None);
+        assert_eq!(rename(&get_table(), &names(&["A"]), &[]), None);
+    }
+
+    #[test]
+    fn test_nodes() {
+        let mut manager = Manager::new();
+        let select = manager.add_node(SelectColumnsNode::new());
+        let rename = manager.add_node(RenameColumnsNode::new());
+
+        manager.set_input_value(select, 0, get_table());
+        manager.set_input_value(select, 1, vec!["B", "A"]);
+        manager.connect(select, 0, rename, 0);
+        manager.set_input_value(rename, 1, "B");
+        manager.set_input_value(rename, 2, "Label");
+
+        assert_eq!(evaluate_table(&mut manager, rename).get_column_names(), names(&["Label", "A"]));
+    }
+}
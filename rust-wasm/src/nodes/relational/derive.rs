@@ -0,0 +1,122 @@
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::table::{Column, Table};
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+use crate::nodes::statistics::resampling::StatisticGraph;
+use super::{Key, get_columns, get_names};
+
+/// Table with a new column `name`, computed by the graph for each row from the values of the `columns`.\
+/// A column with the same name is replaced in place.
+/// Returns `None` when a column is missing or their number doesn't match the inputs of the graph.
+pub fn derive(table: &Table, columns: &[String], name: &str, graph: &StatisticGraph) -> Option<Table> {
+    let inputs = get_columns(table, columns)?;
+
+    if inputs.len() != graph.get_input_count() {
+        return None;
+    }
+
+    let values: Vec<ConnectorType> = (0..table.get_row_count())
+        .map(|row| graph.get_output(Key::from_row(&inputs, row).get_values()))
+        .collect();
+    let derived = Column::new(name, ConnectorTypeId::ANY, values);
+
+    let mut columns = table.get_columns().to_vec();
+
+    match table.get_column_index(name) {
+        Some(index) => columns[index] = derived,
+        None => columns.push(derived),
+    }
+
+    Table::from_columns(columns)
+}
+
+/// Adds a column to a `Table` computed from other `Columns` by a sub-graph of nodes.\
+/// The values of the columns in a row are written to the inputs of the graph, in order,
+/// and its output becomes the value of the new column in that row.
+pub struct DeriveColumnNode {
+    graph: StatisticGraph,
+}
+
+impl DeriveColumnNode {
+    pub fn new(graph: StatisticGraph) -> DeriveColumnNode {
+        DeriveColumnNode {
+            graph,
+        }
+    }
+}
+
+impl Node for DeriveColumnNode {
+    fn get_name(&self) -> String {
+        "Derive Column".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Table", Table::new(), ConnectorTypeId::TABLE),
+            Port::new("Columns", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+            Port::new("Name", "Derived", ConnectorTypeId::STRING),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Table", Table::new(), ConnectorTypeId::TABLE)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let table = match context.get_input(0) {
+            ConnectorType::Table(table) => table,
+            _ => return vec![ConnectorType::Null(Null::new())],
+        };
+
+        match derive(&table, &get_names(&context.get_input(1)), &context.get_input(2).to_string(), &self.graph) {
+            Some(derived) => vec![ConnectorType::Table(derived)],
+            None => vec![ConnectorType::Null(Null::new())],
+        }
+    }
+}
+
+#[cfg(test)]
+mod derive_test {
+    use super::*;
+    use crate::helper::testing::evaluate_table;
+    use crate::nodes::math::{BinaryNode, BinaryOperation};
+    use crate::nodes::node_manager::Manager;
+
+    fn get_graph() -> StatisticGraph {
+        let mut manager = Manager::new();
+        let multiply = manager.add_node(BinaryNode::new(BinaryOperation::Multiply));
+
+        StatisticGraph::new(manager, vec![(multiply, 0), (multiply, 1)], (multiply, 0))
+    }
+
+    fn get_table() -> Table {
+        Table::from_columns(vec![
+            Column::new("Price", ConnectorTypeId::FLOAT, vec![2.5, 4.0]),
+            Column::new("Quantity", ConnectorTypeId::INT, vec![2, 3]),
+        ]).unwrap()
+    }
+
+    #[test]
+    fn test_derive() {
+        let columns = ["Price".to_string(), "Quantity".to_string()];
+        let table = derive(&get_table(), &columns, "Total", &get_graph()).unwrap();
+
+        assert_eq!(table.get_column_names(), vec!["Price", "Quantity", "Total"]);
+        assert_eq!(table.get_column("Total").unwrap().get_values(), [ConnectorType::new(5.0), ConnectorType::new(12.0)]);
+        assert_eq!(derive(&get_table(), &columns, "Price", &get_graph()).unwrap().get_column_names(), vec!["Price", "Quantity"]);
+        assert_eq!(derive(&get_table(), &columns[..1], "Total", &get_graph()), None);
+    }
+
+    #[test]
+    fn test_node() {
+        let mut manager = Manager::new();
+        let node = manager.add_node(DeriveColumnNode::new(get_graph()));
+
+        manager.set_input_value(node, 0, get_table());
+        manager.set_input_value(node, 1, vec!["Quantity", "Quantity"]);
+        manager.set_input_value(node, 2, "Square");
+
+        assert_eq!(evaluate_table(&mut manager, node).get_column("Square").unwrap().get_values(), [ConnectorType::new(4), ConnectorType::new(9)]);
+    }
+}
@@ -0,0 +1,114 @@
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::table::Table;
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+
+/// Whether a predicate value keeps its row, `Null` and values that aren't true drop it.
+fn is_kept(value: &ConnectorType) -> bool {
+    match value {
+        ConnectorType::Null(_) => false,
+        value => value.get_variant(ConnectorTypeId::BOOL) == ConnectorType::Boolean(true),
+    }
+}
+
+/// Which rows of a table a predicate keeps.\
+/// The predicate is either the name of a column of the table or a list with a value per row.
+/// Returns `None` when the column is missing or the list has a different length.
+pub fn get_mask(table: &Table, predicate: &ConnectorType) -> Option<Vec<bool>> {
+    let values = match predicate {
+        ConnectorType::List(values) => values.as_slice(),
+        ConnectorType::String(name) => table.get_column(name)?.get_values(),
+        _ => return None,
+    };
+
+    match values.len() == table.get_row_count() {
+        true => Some(values.iter().map(is_kept).collect()),
+        false => None,
+    }
+}
+
+/// Rows of a table where the mask is true.
+pub fn filter(table: &Table, mask: &[bool]) -> Table {
+    let rows: Vec<usize> = mask.iter().enumerate().filter(|(_, keep)| **keep).map(|(row, _)| row).collect();
+
+    table.take_rows(&rows)
+}
+
+/// Keeps the rows of a `Table` where the `Predicate` is true.\
+/// The predicate is the name of a boolean column, or a list of booleans such as the result of an expression.
+pub struct FilterNode {}
+
+impl FilterNode {
+    pub fn new() -> FilterNode {
+        FilterNode {}
+    }
+}
+
+impl Default for FilterNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for FilterNode {
+    fn get_name(&self) -> String {
+        "Filter".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Table", Table::new(), ConnectorTypeId::TABLE),
+            Port::new("Predicate", "Keep", ConnectorTypeId::ANY),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Filtered", Table::new(), ConnectorTypeId::TABLE)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let table = match context.get_input(0) {
+            ConnectorType::Table(table) => table,
+            _ => return vec![ConnectorType::Null(Null::new())],
+        };
+
+        match get_mask(&table, &context.get_input(1)) {
+            Some(mask) => vec![ConnectorType::Table(filter(&table, &mask))],
+            None => vec![ConnectorType::Null(Null::new())],
+        }
+    }
+}
+
+#[cfg(test)]
+mod filter_test {
+    use super::*;
+    use crate::helper::testing::evaluate_table;
+    use crate::nodes::connector_manager::table::Column;
+    use crate::nodes::node_manager::Manager;
+
+    fn get_table() -> Table {
+        Table::from_columns(vec![
+            Column::new("Name", ConnectorTypeId::STRING, vec!["a", "b", "c"]),
+            Column::new("Keep", ConnectorTypeId::BOOL, vec![ConnectorType::new(true), ConnectorType::new(false), ConnectorType::Null(Null::new())]),
+        ]).unwrap()
+    }
+
+    #[test]
+    fn test_mask() {
+        assert_eq!(get_mask(&get_table(), &ConnectorType::new("Keep")), Some(vec![true, false, false]));
+        assert_eq!(get_mask(&get_table(), &ConnectorType::new(vec![false, true, true])), Some(vec![false, true, true]));
+        assert_eq!(get_mask(&get_table(), &ConnectorType::new(vec![true])), None);
+        assert_eq!(get_mask(&get_table(), &ConnectorType::new("Missing")), None);
+    }
+
+    #[test]
+    fn test_node() {
+        let mut manager = Manager::new();
+        let node = manager.add_node(FilterNode::new());
+
+        manager.set_input_value(node, 0, get_table());
+
+        assert_eq!(evaluate_table(&mut manager, node).get_column("Name").unwrap().get_values(), [ConnectorType::new("a")]);
+    }
+}
@@ -0,0 +1,274 @@
+use std::collections::{HashMap, HashSet};
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::table::{Column, Table};
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+use crate::nodes::statistics::NullHandling;
+use crate::nodes::statistics::descriptive::Statistic;
+use super::{Key, get_columns, get_names};
+use super::sort::total_cmp;
+
+/// Aggregations of the values of a column within a group.\
+/// Nulls are left out of every aggregation.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Aggregation {
+    /// Number of values that are not `Null`.
+    Count,
+    Sum,
+    Mean,
+    Min,
+    Max,
+    Median,
+    /// Number of different values.
+    DistinctCount,
+    First,
+    Last,
+    /// Values joined into a string with the separator.
+    Concat(String),
+}
+
+impl Aggregation {
+    pub fn get_name(&self) -> String {
+        match self {
+            Aggregation::DistinctCount => "Distinct Count".to_string(),
+            Aggregation::Concat(_) => "Concat".to_string(),
+            aggregation => format!("{:?}", aggregation),
+        }
+    }
+
+    /// Type of the aggregated column, `ANY` keeps the type of the values.
+    pub fn get_type(&self, column: &Column) -> i32 {
+        match self {
+            Aggregation::Count | Aggregation::DistinctCount => ConnectorTypeId::INT,
+            Aggregation::Sum | Aggregation::Mean | Aggregation::Median => ConnectorTypeId::ANY,
+            Aggregation::Min | Aggregation::Max | Aggregation::First | Aggregation::Last => column.get_type(),
+            Aggregation::Concat(_) => ConnectorTypeId::STRING,
+        }
+    }
+
+    pub fn apply(&self, values: &[&ConnectorType]) -> ConnectorType {
+        let present: Vec<&ConnectorType> = values.iter().copied().filter(|value| !value.is_null()).collect();
+        let statistic = |statistic: Statistic| statistic.compute(&ConnectorType::List(present.iter().copied().cloned().collect()), NullHandling::Skip);
+        let null = ConnectorType::Null(Null::new());
+
+        match self {
+            Aggregation::Count => ConnectorType::Integer(present.len() as i32),
+            Aggregation::Sum => statistic(Statistic::Sum),
+            Aggregation::Mean => statistic(Statistic::Mean),
+            Aggregation::Median => statistic(Statistic::Median),
+            Aggregation::Min => present.iter().min_by(|lhs, rhs| total_cmp(lhs, rhs)).map(|value| (*value).clone()).unwrap_or(null),
+            Aggregation::Max => present.iter().max_by(|lhs, rhs| total_cmp(lhs, rhs)).map(|value| (*value).clone()).unwrap_or(null),
+            Aggregation::DistinctCount => ConnectorType::Integer(present.iter().map(|value| Key::new(vec![(*value).clone()])).collect::<HashSet<Key>>().len() as i32),
+            Aggregation::First => present.first().map(|value| (*value).clone()).unwrap_or(null),
+            Aggregation::Last => present.last().map(|value| (*value).clone()).unwrap_or(null),
+            Aggregation::Concat(separator) => ConnectorType::String(present.iter().map(|value| value.to_string()).collect::<Vec<String>>().join(separator)),
+        }
+    }
+}
+
+/// An aggregation of a column, output as a column named after both, such as `Sales Sum`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnAggregation {
+    column: String,
+    aggregation: Aggregation,
+}
+
+impl ColumnAggregation {
+    pub fn new(column: &str, aggregation: Aggregation) -> ColumnAggregation {
+        ColumnAggregation {
+            column: column.to_string(),
+            aggregation,
+        }
+    }
+
+    pub fn get_column(&self) -> String {
+        self.column.clone()
+    }
+
+    pub fn get_aggregation(&self) -> &Aggregation {
+        &self.aggregation
+    }
+
+    pub fn get_name(&self) -> String {
+        format!("{} {}", self.column, self.aggregation.get_name())
+    }
+}
+
+/// Rows of each group of equal keys, groups in the order their first row appears.
+pub fn get_groups(columns: &[&Column], rows: usize) -> Vec<(Key, Vec<usize>)> {
+    let mut indices: HashMap<Key, usize> = HashMap::new();
+    let mut groups: Vec<(Key, Vec<usize>)> = Vec::new();
+
+    for row in 0..rows {
+        let key = Key::from_row(columns, row);
+
+        match indices.get(&key) {
+            Some(index) => groups[*index].1.push(row),
+            None => {
+                indices.insert(key.clone(), groups.len());
+                groups.push((key, vec![row]));
+            },
+        }
+    }
+
+    groups
+}
+
+/// Groups the rows of a table by the `keys` columns and aggregates each group.\
+/// The result has the key columns followed by one column per aggregation, and a row per group.
+/// `Null` keys form a group of their own, without keys the whole table is a single group.\
+/// Returns `None` when a column is missing.
+pub fn group_by(table: &Table, keys: &[String], aggregations: &[ColumnAggregation]) -> Option<Table> {
+    let key_columns = get_columns(table, keys)?;
+    let aggregated = aggregations.iter().map(|aggregation| table.get_column(&aggregation.get_column())).collect::<Option<Vec<_>>>()?;
+    let groups = get_groups(&key_columns, table.get_row_count());
+
+    let mut columns: Vec<Column> = key_columns.iter().enumerate().map(|(index, column)| {
+        Column::new(&column.get_name(), column.get_type(), groups.iter().map(|group| group.0.get_values()[index].clone()).collect())
+    }).collect();
+
+    for (aggregation, column) in aggregations.iter().zip(aggregated) {
+        let values = groups.iter().map(|(_, rows)| {
+            let values: Vec<&ConnectorType> = rows.iter().map(|row| &column.get_values()[*row]).collect();
+
+            aggregation.get_aggregation().apply(&values)
+        }).collect();
+
+        columns.push(Column::new(&aggregation.get_name(), aggregation.get_aggregation().get_type(column), values));
+    }
+
+    Table::from_columns(columns)
+}
+
+/// Aggregates the columns of a `Table` per group of equal values in the `Keys` columns.
+pub struct GroupByNode {
+    aggregations: Vec<ColumnAggregation>,
+}
+
+impl GroupByNode {
+    pub fn new(aggregations: Vec<ColumnAggregation>) -> GroupByNode {
+        GroupByNode {
+            aggregations,
+        }
+    }
+}
+
+impl Node for GroupByNode {
+    fn get_name(&self) -> String {
+        "Group By".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Table", Table::new(), ConnectorTypeId::TABLE),
+            Port::new("Keys", vec!["Key"], ConnectorTypeId::LIST),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Grouped", Table::new(), ConnectorTypeId::TABLE)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let table = match context.get_input(0) {
+            ConnectorType::Table(table) => table,
+            _ => return vec![ConnectorType::Null(Null::new())],
+        };
+
+        match group_by(&table, &get_names(&context.get_input(1)), &self.aggregations) {
+            Some(grouped) => vec![ConnectorType::Table(grouped)],
+            None => vec![ConnectorType::Null(Null::new())],
+        }
+    }
+}
+
+#[cfg(test)]
+mod group_test {
+    use super::*;
+    use crate::helper::testing::{evaluate_table, null};
+    use crate::nodes::node_manager::Manager;
+
+    fn get_table() -> Table {
+        Table::from_columns(vec![
+            Column::new("Region", ConnectorTypeId::STRING, vec![ConnectorType::new("North"), ConnectorType::new("South"), ConnectorType::new("North"), null(), ConnectorType::new("South")]),
+            Column::new("Product", ConnectorTypeId::STRING, vec!["a", "b", "b", "a", "b"]),
+            Column::new("Sales", ConnectorTypeId::INT, vec![ConnectorType::new(10), ConnectorType::new(5), ConnectorType::new(30), ConnectorType::new(7), null()]),
+        ]).unwrap()
+    }
+
+    fn get_values(table: &Table, column: &str) -> Vec<ConnectorType> {
+        table.get_column(column).unwrap().get_values().to_vec()
+    }
+
+    #[test]
+    fn test_aggregations() {
+        let values = [ConnectorType::new(3), null(), ConnectorType::new(1), ConnectorType::new(3)];
+        let values: Vec<&ConnectorType> = values.iter().collect();
+
+        assert_eq!(Aggregation::Count.apply(&values), 3);
+        assert_eq!(Aggregation::Sum.apply(&values), Statistic::Sum.compute(&ConnectorType::new(vec![3, 1, 3]), NullHandling::Skip));
+        assert_eq!(Aggregation::Min.apply(&values), 1);
+        assert_eq!(Aggregation::Max.apply(&values), 3);
+        assert_eq!(Aggregation::DistinctCount.apply(&values), 2);
+        assert_eq!(Aggregation::First.apply(&values), 3);
+        assert_eq!(Aggregation::Last.apply(&values), 3);
+        assert_eq!(Aggregation::Concat(", ".to_string()).apply(&values), "3, 1, 3");
+        assert_eq!(Aggregation::Max.apply(&[]), null());
+        assert_eq!(Aggregation::Count.apply(&[]), 0);
+
+        let floats = [ConnectorType::new(2.0), ConnectorType::new(f32::NAN), ConnectorType::new(-1.0)];
+        let floats: Vec<&ConnectorType> = floats.iter().collect();
+
+        assert_eq!(Aggregation::Min.apply(&floats), -1.0, "NaN shouldn't hide the smaller values");
+        assert!(matches!(Aggregation::Max.apply(&floats), ConnectorType::Float(value) if value.is_nan()));
+        assert_eq!(Aggregation::Min.apply(&floats[1..]), Aggregation::Min.apply(&[floats[2], floats[1]]));
+    }
+
+    #[test]
+    fn test_nan_keys() {
+        let table = Table::from_columns(vec![
+            Column::new("Key", ConnectorTypeId::FLOAT, vec![f32::NAN, 0.0, f32::NAN, -0.0]),
+            Column::new("Value", ConnectorTypeId::INT, vec![1, 2, 3, 4]),
+        ]).unwrap();
+        let grouped = group_by(&table, &["Key".to_string()], &[ColumnAggregation::new("Value", Aggregation::Sum)]).unwrap();
+
+        assert_eq!(grouped.get_row_count(), 2);
+        assert_eq!(get_values(&grouped, "Value Sum")[0], Statistic::Sum.compute(&ConnectorType::new(vec![1, 3]), NullHandling::Skip));
+    }
+
+    #[test]
+    fn test_group_by() {
+        let aggregations = vec![
+            ColumnAggregation::new("Sales", Aggregation::Sum),
+            ColumnAggregation::new("Sales", Aggregation::Count),
+            ColumnAggregation::new("Product", Aggregation::Concat("/".to_string())),
+        ];
+        let grouped = group_by(&get_table(), &["Region".to_string()], &aggregations).unwrap();
+
+        assert_eq!(grouped.get_column_names(), vec!["Region", "Sales Sum", "Sales Count", "Product Concat"]);
+        assert_eq!(get_values(&grouped, "Region"), vec![ConnectorType::new("North"), ConnectorType::new("South"), null()]);
+        assert_eq!(get_values(&grouped, "Sales Sum"), vec![ConnectorType::new(40), ConnectorType::new(5), ConnectorType::new(7)]);
+        assert_eq!(get_values(&grouped, "Sales Count"), vec![ConnectorType::new(2), ConnectorType::new(1), ConnectorType::new(1)]);
+        assert_eq!(get_values(&grouped, "Product Concat"), vec![ConnectorType::new("a/b"), ConnectorType::new("b/b"), ConnectorType::new("a")]);
+
+        let by_both = group_by(&get_table(), &["Region".to_string(), "Product".to_string()], &[]).unwrap();
+
+        assert_eq!(by_both.get_row_count(), 4);
+        assert_eq!(group_by(&get_table(), &[], &[ColumnAggregation::new("Sales", Aggregation::Max)]).unwrap().get_rows(), vec![vec![ConnectorType::new(30)]]);
+        assert_eq!(group_by(&get_table(), &["Store".to_string()], &[]), None);
+    }
+
+    #[test]
+    fn test_node() {
+        let mut manager = Manager::new();
+        let node = manager.add_node(GroupByNode::new(vec![ColumnAggregation::new("Sales", Aggregation::Mean)]));
+
+        manager.set_input_value(node, 0, get_table());
+        manager.set_input_value(node, 1, "Product");
+
+        let grouped = evaluate_table(&mut manager, node);
+
+        assert_eq!(get_values(&grouped, "Sales Mean"), vec![ConnectorType::new(8.5), ConnectorType::new(17.5)]);
+    }
+}
@@ -0,0 +1,344 @@
+use std::collections::HashMap;
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::table::{Column, Table};
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+use crate::nodes::io::get_unique_names;
+use super::{Key, get_columns, get_names};
+
+/// Which rows a join keeps.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum JoinKind {
+    /// Matching pairs of rows.
+    Inner,
+    /// Matching pairs, and left rows without a match.
+    Left,
+    /// Matching pairs, and right rows without a match.
+    Right,
+    /// Matching pairs, and rows of either side without a match.
+    Full,
+    /// Left rows with a match, with the left columns only.
+    Semi,
+    /// Left rows without a match, with the left columns only.
+    Anti,
+}
+
+impl JoinKind {
+    pub fn get_name(&self) -> String {
+        match self {
+            JoinKind::Full => "Full Outer Join".to_string(),
+            kind => format!("{:?} Join", kind),
+        }
+    }
+}
+
+/// Suffixes added to the names of columns present on both sides.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Suffixes {
+    left: String,
+    right: String,
+}
+
+impl Suffixes {
+    pub fn new(left: &str, right: &str) -> Suffixes {
+        Suffixes {
+            left: left.to_string(),
+            right: right.to_string(),
+        }
+    }
+}
+
+impl Default for Suffixes {
+    fn default() -> Self {
+        Suffixes::new("_left", "_right")
+    }
+}
+
+/// Pairs of left and right rows kept by a join, `None` on the side without a match.\
+/// Builds a hash table of the right keys and probes it with the left keys, keys with a `Null` never match.
+/// Pairs follow the order of the left rows, with unmatched right rows at the end.
+pub fn get_join_rows(left: &[&Column], right: &[&Column], left_rows: usize, right_rows: usize, kind: JoinKind) -> Vec<(Option<usize>, Option<usize>)> {
+    let mut index: HashMap<Key, Vec<usize>> = HashMap::new();
+
+    for row in 0..right_rows {
+        let key = Key::from_row(right, row);
+
+        if !key.has_null() {
+            index.entry(key).or_default().push(row);
+        }
+    }
+
+    let mut matched = vec![false; right_rows];
+    let mut pairs = Vec::new();
+
+    for row in 0..left_rows {
+        let key = Key::from_row(left, row);
+        let matches = match key.has_null() {
+            true => None,
+            false => index.get(&key),
+        };
+
+        match (kind, matches) {
+            (JoinKind::Semi, Some(_)) => pairs.push((Some(row), None)),
+            (JoinKind::Anti, None) => pairs.push((Some(row), None)),
+            (JoinKind::Semi, None) | (JoinKind::Anti, Some(_)) => (),
+            (_, Some(matches)) => {
+                for other in matches {
+                    matched[*other] = true;
+                    pairs.push((Some(row), Some(*other)));
+                }
+            },
+            (JoinKind::Left, None) | (JoinKind::Full, None) => pairs.push((Some(row), None)),
+            (_, None) => (),
+        }
+    }
+
+    if matches!(kind, JoinKind::Right | JoinKind::Full) {
+        pairs.extend(matched.iter().enumerate().filter(|(_, matched)| !**matched).map(|(row, _)| (None, Some(row))));
+    }
+
+    pairs
+}
+
+fn gather(column: &Column, name: &str, rows: &[Option<usize>]) -> Column {
+    let values = rows.iter().map(|row| match row {
+        Some(row) => column.get_values()[*row].clone(),
+        None => ConnectorType::Null(Null::new()),
+    }).collect();
+
+    Column::new(name, column.get_type(), values)
+}
+
+/// Joins two tables on equal values of their key columns, compared in order.\
+/// Key columns with the same name on both sides are output once, with the value of whichever side has the row.
+/// Other columns present on both sides get the suffixes, and names still repeated after that get a `_n` suffix.\
+/// Returns `None` when a key column is missing or the sides have a different number of keys.
+pub fn join(left: &Table, right: &Table, left_keys: &[String], right_keys: &[String], kind: JoinKind, suffixes: &Suffixes) -> Option<Table> {
+    let left_columns = get_columns(left, left_keys)?;
+    let right_columns = get_columns(right, right_keys)?;
+
+    if left_keys.len() != right_keys.len() || left_keys.is_empty() {
+        return None;
+    }
+
+    let pairs = get_join_rows(&left_columns, &right_columns, left.get_row_count(), right.get_row_count(), kind);
+    let left_rows: Vec<Option<usize>> = pairs.iter().map(|pair| pair.0).collect();
+    let right_rows: Vec<Option<usize>> = pairs.iter().map(|pair| pair.1).collect();
+
+    if matches!(kind, JoinKind::Semi | JoinKind::Anti) {
+        return Table::from_columns(left.get_columns().iter().map(|column| gather(column, &column.get_name(), &left_rows)).collect());
+    }
+
+    // Right key columns merged into the left key column of the same name
+    let shared: Vec<(String, usize)> = left_keys.iter().zip(right_keys)
+        .filter(|(left, right)| left == right)
+        .map(|(name, _)| (name.clone(), right.get_column_index(name).unwrap()))
+        .collect();
+    let kept: Vec<&Column> = right.get_columns().iter().enumerate()
+        .filter(|(index, _)| !shared.iter().any(|(_, shared)| shared == index))
+        .map(|(_, column)| column)
+        .collect();
+
+    let left_names = left.get_column_names();
+    let right_names: Vec<String> = kept.iter().map(|column| column.get_name()).collect();
+    let mut columns = Vec::new();
+
+    for column in left.get_columns() {
+        let name = column.get_name();
+
+        let gathered = match shared.iter().find(|(shared, _)| *shared == name) {
+            Some((_, index)) => {
+                let other = &right.get_columns()[*index];
+                let values = left_rows.iter().zip(&right_rows).map(|pair| match pair {
+                    (Some(row), _) => column.get_values()[*row].clone(),
+                    (None, Some(row)) => other.get_values()[*row].clone(),
+                    (None, None) => ConnectorType::Null(Null::new()),
+                }).collect();
+
+                Column::new(&name, column.get_type(), values)
+            },
+            None if right_names.contains(&name) => gather(column, &format!("{}{}", name, suffixes.left), &left_rows),
+            None => gather(column, &name, &left_rows),
+        };
+
+        columns.push(gathered);
+    }
+
+    for column in kept {
+        let name = column.get_name();
+
+        match left_names.contains(&name) {
+            true => columns.push(gather(column, &format!("{}{}", name, suffixes.right), &right_rows)),
+            false => columns.push(gather(column, &name, &right_rows)),
+        }
+    }
+
+    let names: Vec<String> = columns.iter().map(|column| column.get_name()).collect();
+
+    for (column, name) in columns.iter_mut().zip(get_unique_names(&names)) {
+        column.set_name(&name);
+    }
+
+    Table::from_columns(columns)
+}
+
+/// Joins the `Left` and `Right` tables on their key columns.\
+/// `Right Keys` default to the `Keys` of the left table.
+pub struct JoinNode {
+    kind: JoinKind,
+}
+
+impl JoinNode {
+    pub fn new(kind: JoinKind) -> JoinNode {
+        JoinNode {
+            kind,
+        }
+    }
+}
+
+impl Node for JoinNode {
+    fn get_name(&self) -> String {
+        self.kind.get_name()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Left", Table::new(), ConnectorTypeId::TABLE),
+            Port::new("Right", Table::new(), ConnectorTypeId::TABLE),
+            Port::new("Keys", vec!["Key"], ConnectorTypeId::LIST),
+            Port::new("Right Keys", Null::new(), ConnectorTypeId::ANY),
+            Port::new("Left Suffix", "_left", ConnectorTypeId::STRING),
+            Port::new("Right Suffix", "_right", ConnectorTypeId::STRING),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Joined", Table::new(), ConnectorTypeId::TABLE)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let (left, right) = match (context.get_input(0), context.get_input(1)) {
+            (ConnectorType::Table(left), ConnectorType::Table(right)) => (left, right),
+            _ => return vec![ConnectorType::Null(Null::new())],
+        };
+
+        let left_keys = get_names(&context.get_input(2));
+        let right_keys = match get_names(&context.get_input(3)) {
+            keys if keys.is_empty() => left_keys.clone(),
+            keys => keys,
+        };
+        let suffixes = Suffixes::new(&context.get_input(4).to_string(), &context.get_input(5).to_string());
+
+        match join(&left, &right, &left_keys, &right_keys, self.kind, &suffixes) {
+            Some(joined) => vec![ConnectorType::Table(joined)],
+            None => vec![ConnectorType::Null(Null::new())],
+        }
+    }
+}
+
+#[cfg(test)]
+mod join_test {
+    use super::*;
+    use crate::helper::testing::{evaluate_table, null};
+    use crate::nodes::node_manager::Manager;
+
+    fn get_left() -> Table {
+        Table::from_columns(vec![
+            Column::new("Id", ConnectorTypeId::INT, vec![ConnectorType::new(1), ConnectorType::new(2), ConnectorType::new(3), null()]),
+            Column::new("Name", ConnectorTypeId::STRING, vec!["a", "b", "c", "d"]),
+        ]).unwrap()
+    }
+
+    fn get_right() -> Table {
+        Table::from_columns(vec![
+            Column::new("Id", ConnectorTypeId::INT, vec![ConnectorType::new(2), ConnectorType::new(2), ConnectorType::new(4), null()]),
+            Column::new("Name", ConnectorTypeId::STRING, vec!["x", "y", "z", "w"]),
+        ]).unwrap()
+    }
+
+    fn joined(kind: JoinKind) -> Table {
+        join(&get_left(), &get_right(), &["Id".to_string()], &["Id".to_string()], kind, &Suffixes::default()).unwrap()
+    }
+
+    fn get_values(table: &Table, column: &str) -> Vec<ConnectorType> {
+        table.get_column(column).unwrap().get_values().to_vec()
+    }
+
+    #[test]
+    fn test_kinds() {
+        let inner = joined(JoinKind::Inner);
+
+        assert_eq!(inner.get_column_names(), vec!["Id", "Name_left", "Name_right"]);
+        assert_eq!(get_values(&inner, "Name_right"), vec![ConnectorType::new("x"), ConnectorType::new("y")]);
+        assert_eq!(joined(JoinKind::Left).get_row_count(), 5);
+        assert_eq!(get_values(&joined(JoinKind::Right), "Id"), vec![ConnectorType::new(2), ConnectorType::new(2), ConnectorType::new(4), null()]);
+        assert_eq!(get_values(&joined(JoinKind::Full), "Name_left"), vec!["a", "b", "b", "c", "d"].into_iter().map(ConnectorType::new).chain([null(), null()]).collect::<Vec<_>>());
+        assert_eq!(get_values(&joined(JoinKind::Semi), "Name"), vec![ConnectorType::new("b")]);
+        // The null key never matches, so its row is kept by the anti join
+        assert_eq!(get_values(&joined(JoinKind::Anti), "Name"), vec![ConnectorType::new("a"), ConnectorType::new("c"), ConnectorType::new("d")]);
+    }
+
+    #[test]
+    fn test_keys() {
+        let right = Table::from_columns(vec![
+            Column::new("Person", ConnectorTypeId::INT, vec![3, 1]),
+            Column::new("Label", ConnectorTypeId::STRING, vec!["c", "a"]),
+            Column::new("Score", ConnectorTypeId::INT, vec![30, 10]),
+        ]).unwrap();
+
+        let table = join(&get_left(), &right, &["Id".to_string(), "Name".to_string()], &["Person".to_string(), "Label".to_string()], JoinKind::Inner, &Suffixes::default()).unwrap();
+
+        assert_eq!(table.get_column_names(), vec!["Id", "Name", "Person", "Label", "Score"]);
+        assert_eq!(get_values(&table, "Score"), vec![ConnectorType::new(10), ConnectorType::new(30)]);
+        assert_eq!(join(&get_left(), &right, &["Id".to_string()], &["Missing".to_string()], JoinKind::Inner, &Suffixes::default()), None);
+        assert_eq!(join(&get_left(), &right, &["Id".to_string()], &[], JoinKind::Inner, &Suffixes::default()), None);
+    }
+
+    #[test]
+    fn test_mixed_keys() {
+        let left = Table::from_columns(vec![
+            Column::new("Id", ConnectorTypeId::INT, vec![0, 2, 3]),
+            Column::new("Name", ConnectorTypeId::STRING, vec!["a", "b", "c"]),
+            Column::new("Name_left", ConnectorTypeId::STRING, vec!["d", "e", "f"]),
+        ]).unwrap();
+        let right = Table::from_columns(vec![
+            Column::new("Id", ConnectorTypeId::FLOAT, vec![2.0, 3.5, -0.0]),
+            Column::new("Name", ConnectorTypeId::STRING, vec!["x", "y", "z"]),
+        ]).unwrap();
+
+        let table = join(&left, &right, &["Id".to_string()], &["Id".to_string()], JoinKind::Inner, &Suffixes::default()).unwrap();
+
+        assert_eq!(get_values(&table, "Id"), vec![ConnectorType::new(0), ConnectorType::new(2)]);
+        assert_eq!(table.get_column_names(), vec!["Id", "Name_left", "Name_left_2", "Name_right"]);
+        assert_eq!(get_values(&table, "Name_left_2"), vec![ConnectorType::new("d"), ConnectorType::new("e")]);
+        assert_eq!(join(&left, &right, &["Id".to_string()], &["Id".to_string()], JoinKind::Inner, &Suffixes::new("", "")).unwrap().get_column_names(), vec!["Id", "Name", "Name_left", "Name_2"]);
+    }
+
+    #[test]
+    fn test_large() {
+        let rows = 200_000;
+        let left = Table::from_columns(vec![Column::new("Key", ConnectorTypeId::INT, (0..rows).collect::<Vec<i32>>())]).unwrap();
+        let right = Table::from_columns(vec![
+            Column::new("Key", ConnectorTypeId::INT, (0..rows).rev().step_by(2).collect::<Vec<i32>>()),
+            Column::new("Value", ConnectorTypeId::INT, (0..rows / 2).collect::<Vec<i32>>()),
+        ]).unwrap();
+
+        let table = join(&left, &right, &["Key".to_string()], &["Key".to_string()], JoinKind::Inner, &Suffixes::default()).unwrap();
+
+        assert_eq!(table.get_row_count(), rows as usize / 2);
+    }
+
+    #[test]
+    fn test_node() {
+        let mut manager = Manager::new();
+        let node = manager.add_node(JoinNode::new(JoinKind::Left));
+
+        manager.set_input_value(node, 0, get_left());
+        manager.set_input_value(node, 1, get_right());
+        manager.set_input_value(node, 2, "Id");
+        manager.set_input_value(node, 5, " (right)");
+
+        assert_eq!(evaluate_table(&mut manager, node).get_column_names(), vec!["Id", "Name_left", "Name (right)"]);
+    }
+}
@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::table::{Column, Table};
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+use super::group::{Aggregation, get_groups};
+use crate::nodes::io::get_unique_names;
+use super::{Key, get_columns, get_names};
+
+/// Spreads a table into a cross-tab, with a row per group of the `rows` key columns
+/// and a column per value of the `columns` key column, named after the value with a `_n` suffix when the name is taken.\
+/// Each cell aggregates the `values` of the rows of its group and column, cells without rows get the `fill` value.
+/// Rows with a `Null` column key are left out.\
+/// Returns `None` when a column is missing.
+pub fn pivot(table: &Table, rows: &[String], columns: &str, values: &str, aggregation: &Aggregation, fill: &ConnectorType) -> Option<Table> {
+    let row_columns = get_columns(table, rows)?;
+    let column_key = table.get_column(columns)?;
+    let value_column = table.get_column(values)?;
+    let groups = get_groups(&row_columns, table.get_row_count());
+
+    // Column keys in the order they first appear
+    let mut keys: Vec<&ConnectorType> = Vec::new();
+    let mut indices: HashMap<Key, usize> = HashMap::new();
+
+    for key in column_key.get_values().iter().filter(|key| !key.is_null()) {
+        indices.entry(Key::new(vec![key.clone()])).or_insert_with(|| {
+            keys.push(key);
+            keys.len() - 1
+        });
+    }
+
+    let mut pivoted: Vec<Column> = row_columns.iter().enumerate().map(|(index, column)| {
+        Column::new(&column.get_name(), column.get_type(), groups.iter().map(|group| group.0.get_values()[index].clone()).collect())
+    }).collect();
+    let mut cells: Vec<Vec<Vec<&ConnectorType>>> = vec![vec![Vec::new(); groups.len()]; keys.len()];
+
+    for (group, (_, group_rows)) in groups.iter().enumerate() {
+        for row in group_rows {
+            let key = &column_key.get_values()[*row];
+
+            if !key.is_null() {
+                cells[indices[&Key::new(vec![key.clone()])]][group].push(&value_column.get_values()[*row]);
+            }
+        }
+    }
+
+    for (key, cells) in keys.iter().zip(cells) {
+        let values = cells.iter().map(|cell| match cell.is_empty() {
+            true => fill.clone(),
+            false => aggregation.apply(cell),
+        }).collect();
+
+        pivoted.push(Column::new(&key.to_string(), aggregation.get_type(value_column), values));
+    }
+
+    let names: Vec<String> = pivoted.iter().map(|column| column.get_name()).collect();
+
+    for (column, name) in pivoted.iter_mut().zip(get_unique_names(&names)) {
+        column.set_name(&name);
+    }
+
+    Table::from_columns(pivoted)
+}
+
+/// Gathers columns of a table into rows, the inverse of a pivot.\
+/// Each row becomes a row per `values` column, or per column other than the `ids` when empty,
+/// with the `ids` columns, the name of the column in a `variable` column and its value in a `value` column.
+/// The value column keeps the type of the gathered columns when they share one.\
+/// Returns `None` when a column is missing.
+pub fn unpivot(table: &Table, ids: &[String], values: &[String], variable: &str, value: &str, drop_nulls: bool) -> Option<Table> {
+    let id_columns = get_columns(table, ids)?;
+    let value_columns: Vec<&Column> = match values.is_empty() {
+        true => table.get_columns().iter().filter(|column| !ids.contains(&column.get_name())).collect(),
+        false => get_columns(table, values)?,
+    };
+
+    let mut rows: Vec<(usize, usize)> = Vec::new();
+
+    for row in 0..table.get_row_count() {
+        for (index, column) in value_columns.iter().enumerate() {
+            if !drop_nulls || !column.get_values()[row].is_null() {
+                rows.push((row, index));
+            }
+        }
+    }
+
+    let value_type = match value_columns.first() {
+        Some(first) if value_columns.iter().all(|column| column.get_type() == first.get_type()) => first.get_type(),
+        _ => ConnectorTypeId::ANY,
+    };
+
+    let mut columns: Vec<Column> = id_columns.iter().map(|column| {
+        Column::new(&column.get_name(), column.get_type(), rows.iter().map(|(row, _)| column.get_values()[*row].clone()).collect())
+    }).collect();
+
+    columns.push(Column::new(variable, ConnectorTypeId::STRING, rows.iter().map(|(_, index)| value_columns[*index].get_name()).collect()));
+    columns.push(Column::new(value, value_type, rows.iter().map(|(row, index)| value_columns[*index].get_values()[*row].clone()).collect()));
+
+    Table::from_columns(columns)
+}
+
+/// Spreads the `Values` of a `Table` into a column per value of the `Columns` key, with a row per group of the `Rows` keys.
+pub struct PivotNode {
+    aggregation: Aggregation,
+}
+
+impl PivotNode {
+    pub fn new(aggregation: Aggregation) -> PivotNode {
+        PivotNode {
+            aggregation,
+        }
+    }
+}
+
+impl Node for PivotNode {
+    fn get_name(&self) -> String {
+        "Pivot".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Table", Table::new(), ConnectorTypeId::TABLE),
+            Port::new("Rows", vec!["Key"], ConnectorTypeId::LIST),
+            Port::new("Columns", "Category", ConnectorTypeId::STRING),
+            Port::new("Values", "Value", ConnectorTypeId::STRING),
+            Port::new("Fill", Null::new(), ConnectorTypeId::ANY),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Pivoted", Table::new(), ConnectorTypeId::TABLE)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let table = match context.get_input(0) {
+            ConnectorType::Table(table) => table,
+            _ => return vec![ConnectorType::Null(Null::new())],
+        };
+
+        let rows = get_names(&context.get_input(1));
+        let (columns, values) = (context.get_input(2).to_string(), context.get_input(3).to_string());
+
+        match pivot(&table, &rows, &columns, &values, &self.aggregation, &context.get_input(4)) {
+            Some(pivoted) => vec![ConnectorType::Table(pivoted)],
+            None => vec![ConnectorType::Null(Null::new())],
+        }
+    }
+}
+
+/// Gathers the `Columns` of a `Table`, or all but the `Ids` when empty, into `Variable` and `Value` columns.
+pub struct UnpivotNode {}
+
+impl UnpivotNode {
+    pub fn new() -> UnpivotNode {
+        UnpivotNode {}
+    }
+}
+
+impl Default for UnpivotNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for UnpivotNode {
+    fn get_name(&self) -> String {
+        "Unpivot".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Table", Table::new(), ConnectorTypeId::TABLE),
+            Port::new("Ids", vec!["Key"], ConnectorTypeId::LIST),
+            Port::new("Columns", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+            Port::new("Variable", "Variable", ConnectorTypeId::STRING),
+            Port::new("Value", "Value", ConnectorTypeId::STRING),
+            Port::new("Drop Nulls", false, ConnectorTypeId::BOOL),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Unpivoted", Table::new(), ConnectorTypeId::TABLE)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let table = match context.get_input(0) {
+            ConnectorType::Table(table) => table,
+            _ => return vec![ConnectorType::Null(Null::new())],
+        };
+
+        let (ids, values) = (get_names(&context.get_input(1)), get_names(&context.get_input(2)));
+        let (variable, value) = (context.get_input(3).to_string(), context.get_input(4).to_string());
+        let drop_nulls = context.get_input(5) == ConnectorType::Boolean(true);
+
+        match unpivot(&table, &ids, &values, &variable, &value, drop_nulls) {
+            Some(unpivoted) => vec![ConnectorType::Table(unpivoted)],
+            None => vec![ConnectorType::Null(Null::new())],
+        }
+    }
+}
+
+#[cfg(test)]
+mod pivot_test {
+    use super::*;
+    use crate::helper::testing::{evaluate_table, null};
+    use crate::nodes::node_manager::Manager;
+
+    fn get_table() -> Table {
+        Table::from_columns(vec![
+            Column::new("Key", ConnectorTypeId::STRING, vec!["north", "north", "south", "north", "south"]),
+            Column::new("Category", ConnectorTypeId::INT, vec![ConnectorType::new(2023), ConnectorType::new(2024), ConnectorType::new(2023), ConnectorType::new(2024), null()]),
+            Column::new("Value", ConnectorTypeId::INT, vec![1, 2, 3, 4, 5]),
+        ]).unwrap()
+    }
+
+    fn get_values(table: &Table, column: &str) -> Vec<ConnectorType> {
+        table.get_column(column).unwrap().get_values().to_vec()
+    }
+
+    #[test]
+    fn test_pivot() {
+        let pivoted = pivot(&get_table(), &["Key".to_string()], "Category", "Value", &Aggregation::Max, &ConnectorType::new(0)).unwrap();
+
+        assert_eq!(pivoted.get_column_names(), vec!["Key", "2023", "2024"]);
+        assert_eq!(pivoted.get_column("2024").unwrap().get_type(), ConnectorTypeId::INT);
+        assert_eq!(get_values(&pivoted, "2023"), vec![1, 3]);
+        assert_eq!(get_values(&pivoted, "2024"), vec![4, 0]);
+
+        let counted = pivot(&get_table(), &[], "Category", "Value", &Aggregation::Count, &null()).unwrap();
+
+        assert_eq!(counted.get_rows(), vec![vec![ConnectorType::new(2), ConnectorType::new(2)]]);
+        assert_eq!(pivot(&get_table(), &["Key".to_string()], "Missing", "Value", &Aggregation::Max, &null()), None);
+    }
+
+    #[test]
+    fn test_name_collisions() {
+        let table = Table::from_columns(vec![
+            Column::new("Key", ConnectorTypeId::STRING, vec!["north", "north", "south"]),
+            Column::new("Category", ConnectorTypeId::ANY, vec![ConnectorType::new(1), ConnectorType::new("1"), ConnectorType::new("Key")]),
+            Column::new("Value", ConnectorTypeId::INT, vec![1, 2, 3]),
+        ]).unwrap();
+        let pivoted = pivot(&table, &["Key".to_string()], "Category", "Value", &Aggregation::Sum, &null()).unwrap();
+
+        assert_eq!(pivoted.get_column_names(), vec!["Key", "1", "1_2", "Key_2"]);
+        assert_eq!(get_values(&pivoted, "1_2"), vec![ConnectorType::new(2), null()]);
+        assert_eq!(get_values(&pivoted, "Key_2"), vec![null(), ConnectorType::new(3)]);
+    }
+
+    #[test]
+    fn test_unpivot() {
+        let pivoted = pivot(&get_table(), &["Key".to_string()], "Category", "Value", &Aggregation::Sum, &null()).unwrap();
+        let unpivoted = unpivot(&pivoted, &["Key".to_string()], &[], "Year", "Total", true).unwrap();
+
+        assert_eq!(unpivoted.get_column_names(), vec!["Key", "Year", "Total"]);
+        assert_eq!(get_values(&unpivoted, "Key"), vec!["north", "north", "south"]);
+        assert_eq!(get_values(&unpivoted, "Year"), vec!["2023", "2024", "2023"]);
+        assert_eq!(unpivot(&pivoted, &["Key".to_string()], &["2024".to_string()], "Year", "Total", false).unwrap().get_row_count(), 2);
+        assert_eq!(unpivot(&pivoted, &["Missing".to_string()], &[], "Year", "Total", false), None);
+
+        let typed = unpivot(&get_table(), &["Key".to_string()], &[], "Variable", "Value", false).unwrap();
+
+        assert_eq!(typed.get_column("Value").unwrap().get_type(), ConnectorTypeId::INT);
+        assert_eq!(typed.get_row_count(), 10);
+    }
+
+    #[test]
+    fn test_nodes() {
+        let mut manager = Manager::new();
+        let pivot = manager.add_node(PivotNode::new(Aggregation::Sum));
+        let unpivot = manager.add_node(UnpivotNode::new());
+
+        manager.set_input_value(pivot, 0, get_table());
+        manager.connect(pivot, 0, unpivot, 0);
+        manager.set_input_value(unpivot, 5, true);
+
+        assert_eq!(evaluate_table(&mut manager, unpivot).get_row_count(), 3);
+    }
+}
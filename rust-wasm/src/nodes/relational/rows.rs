@@ -0,0 +1,232 @@
+use std::collections::HashSet;
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::table::{Column, Table};
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+use crate::nodes::statistics::get_number;
+use super::{Key, get_columns, get_names};
+
+/// End of a table rows are taken from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum End {
+    Head,
+    Tail,
+}
+
+/// Rows from `start` up to `end` excluded, like slices in Python.\
+/// Negative positions count from the last row, `None` for `end` goes to the last row and positions out of range are clamped.
+pub fn slice(table: &Table, start: i64, end: Option<i64>) -> Table {
+    let count = table.get_row_count() as i64;
+    let position = |position: i64| match position < 0 {
+        true => (count + position).max(0),
+        false => position.min(count),
+    };
+    let (start, end) = (position(start), end.map(position).unwrap_or(count));
+
+    table.take_rows(&(start..end.max(start)).map(|row| row as usize).collect::<Vec<usize>>())
+}
+
+/// First or last `count` rows of a table.
+pub fn take(table: &Table, end: End, count: usize) -> Table {
+    let rows = table.get_row_count();
+
+    match end {
+        End::Head => table.take_rows(&(0..count.min(rows)).collect::<Vec<usize>>()),
+        End::Tail => table.take_rows(&(rows.saturating_sub(count)..rows).collect::<Vec<usize>>()),
+    }
+}
+
+/// Table with only the first row of each set of rows with equal values in the `keys` columns, all columns without keys.\
+/// `Null` values are equal to each other. Returns `None` when a key column is missing.
+pub fn distinct(table: &Table, keys: &[String]) -> Option<Table> {
+    let columns: Vec<&Column> = match keys.is_empty() {
+        true => table.get_columns().iter().collect(),
+        false => get_columns(table, keys)?,
+    };
+    let mut seen: HashSet<Key> = HashSet::new();
+    let rows: Vec<usize> = (0..table.get_row_count()).filter(|row| seen.insert(Key::from_row(&columns, *row))).collect();
+
+    Some(table.take_rows(&rows))
+}
+
+fn get_position(value: &ConnectorType) -> Option<i64> {
+    get_number(value).filter(|value| value.is_finite()).map(|value| value as i64)
+}
+
+/// First or last `Count` rows of a `Table`.
+pub struct TakeNode {
+    end: End,
+}
+
+impl TakeNode {
+    pub fn new(end: End) -> TakeNode {
+        TakeNode {
+            end,
+        }
+    }
+}
+
+impl Node for TakeNode {
+    fn get_name(&self) -> String {
+        format!("{:?}", self.end)
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Table", Table::new(), ConnectorTypeId::TABLE),
+            Port::new("Count", 5, ConnectorTypeId::INT),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Rows", Table::new(), ConnectorTypeId::TABLE)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        match (context.get_input(0), get_position(&context.get_input(1))) {
+            (ConnectorType::Table(table), Some(count)) if count >= 0 => vec![ConnectorType::Table(take(&table, self.end, count as usize))],
+            _ => vec![ConnectorType::Null(Null::new())],
+        }
+    }
+}
+
+/// Rows of a `Table` from `Start` up to `End` excluded, negative positions count from the last row.\
+/// A `Null` end goes to the last row.
+pub struct SliceNode {}
+
+impl SliceNode {
+    pub fn new() -> SliceNode {
+        SliceNode {}
+    }
+}
+
+impl Default for SliceNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for SliceNode {
+    fn get_name(&self) -> String {
+        "Slice".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Table", Table::new(), ConnectorTypeId::TABLE),
+            Port::new("Start", 0, ConnectorTypeId::INT),
+            Port::new("End", Null::new(), ConnectorTypeId::ANY),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Rows", Table::new(), ConnectorTypeId::TABLE)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let (table, start) = match (context.get_input(0), get_position(&context.get_input(1))) {
+            (ConnectorType::Table(table), Some(start)) => (table, start),
+            _ => return vec![ConnectorType::Null(Null::new())],
+        };
+
+        let end = match context.get_input(2) {
+            ConnectorType::Null(_) => None,
+            end => match get_position(&end) {
+                Some(end) => Some(end),
+                None => return vec![ConnectorType::Null(Null::new())],
+            },
+        };
+
+        vec![ConnectorType::Table(slice(&table, start, end))]
+    }
+}
+
+/// Removes the rows of a `Table` repeating an earlier row, compared on the `Columns`, or on every column when empty.
+pub struct DistinctNode {}
+
+impl DistinctNode {
+    pub fn new() -> DistinctNode {
+        DistinctNode {}
+    }
+}
+
+impl Default for DistinctNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for DistinctNode {
+    fn get_name(&self) -> String {
+        "Distinct".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Table", Table::new(), ConnectorTypeId::TABLE),
+            Port::new("Columns", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Distinct", Table::new(), ConnectorTypeId::TABLE)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let table = match context.get_input(0) {
+            ConnectorType::Table(table) => table,
+            _ => return vec![ConnectorType::Null(Null::new())],
+        };
+
+        match distinct(&table, &get_names(&context.get_input(1))) {
+            Some(table) => vec![ConnectorType::Table(table)],
+            None => vec![ConnectorType::Null(Null::new())],
+        }
+    }
+}
+
+#[cfg(test)]
+mod rows_test {
+    use super::*;
+    use crate::helper::testing::evaluate_table;
+    use crate::nodes::node_manager::Manager;
+
+    fn get_table() -> Table {
+        Table::from_columns(vec![
+            Column::new("Row", ConnectorTypeId::INT, vec![0, 1, 2, 3, 4]),
+            Column::new("Group", ConnectorTypeId::STRING, vec![ConnectorType::new("a"), ConnectorType::new("b"), ConnectorType::new("a"), ConnectorType::Null(Null::new()), ConnectorType::Null(Null::new())]),
+        ]).unwrap()
+    }
+
+    fn get_order(table: &Table) -> Vec<ConnectorType> {
+        table.get_column("Row").unwrap().get_values().to_vec()
+    }
+
+    #[test]
+    fn test_rows() {
+        assert_eq!(get_order(&take(&get_table(), End::Head, 2)), vec![0, 1]);
+        assert_eq!(get_order(&take(&get_table(), End::Tail, 2)), vec![3, 4]);
+        assert_eq!(get_order(&take(&get_table(), End::Tail, 10)).len(), 5);
+        assert_eq!(get_order(&slice(&get_table(), 1, Some(-1))), vec![1, 2, 3]);
+        assert_eq!(get_order(&slice(&get_table(), -2, None)), vec![3, 4]);
+        assert_eq!(get_order(&slice(&get_table(), 4, Some(2))), Vec::<ConnectorType>::new());
+        assert_eq!(get_order(&distinct(&get_table(), &["Group".to_string()]).unwrap()), vec![0, 1, 3]);
+        assert_eq!(distinct(&get_table(), &[]).unwrap().get_row_count(), 5);
+        assert_eq!(distinct(&get_table(), &["Missing".to_string()]), None);
+    }
+
+    #[test]
+    fn test_nodes() {
+        let mut manager = Manager::new();
+        let slice = manager.add_node(SliceNode::new());
+        let head = manager.add_node(TakeNode::new(End::Head));
+
+        manager.set_input_value(slice, 0, get_table());
+        manager.set_input_value(slice, 1, 1);
+        manager.connect(slice, 0, head, 0);
+        manager.set_input_value(head, 1, 2);
+
+        assert_eq!(get_order(&evaluate_table(&mut manager, head)), vec![1, 2]);
+    }
+}
@@ -0,0 +1,185 @@
+use std::cmp::Ordering;
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::table::{Column, Table};
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+
+/// Where `Null` values go in a sorted column, whatever the direction.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NullPlacement {
+    First,
+    Last,
+}
+
+/// Total order of values: by type first, in the order of their type ids, then by value.\
+/// Floats use `f32::total_cmp`, so `NaN` sorts after every other float, and lists, records
+/// and tables are compared item by item.
+pub fn total_cmp(lhs: &ConnectorType, rhs: &ConnectorType) -> Ordering {
+    fn compare_all<'a>(lhs: impl IntoIterator<Item = &'a ConnectorType>, rhs: impl IntoIterator<Item = &'a ConnectorType>) -> Ordering {
+        let (mut lhs, mut rhs) = (lhs.into_iter(), rhs.into_iter());
+
+        loop {
+            match (lhs.next(), rhs.next()) {
+                (Some(lhs), Some(rhs)) => match total_cmp(lhs, rhs) {
+                    Ordering::Equal => continue,
+                    ordering => return ordering,
+                },
+                (lhs, rhs) => return lhs.is_some().cmp(&rhs.is_some()),
+            }
+        }
+    }
+
+    match (lhs, rhs) {
+        (ConnectorType::Float(lhs), ConnectorType::Float(rhs)) => lhs.total_cmp(rhs),
+        (ConnectorType::List(lhs), ConnectorType::List(rhs)) => compare_all(lhs, rhs),
+        (ConnectorType::Record(lhs), ConnectorType::Record(rhs)) => lhs.get_field_names().cmp(&rhs.get_field_names())
+            .then_with(|| compare_all(lhs.get_fields().iter().map(|(_, value)| value), rhs.get_fields().iter().map(|(_, value)| value))),
+        (ConnectorType::Table(lhs), ConnectorType::Table(rhs)) => lhs.get_column_names().cmp(&rhs.get_column_names())
+            .then_with(|| compare_all(lhs.get_columns().iter().flat_map(|column| column.get_values()), rhs.get_columns().iter().flat_map(|column| column.get_values()))),
+        (lhs, rhs) if lhs.get_type() == rhs.get_type() => lhs.cmp(rhs),
+        (lhs, rhs) => lhs.get_type().cmp(&rhs.get_type()),
+    }
+}
+
+/// A column to sort by, with its direction and the placement of its nulls.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SortKey {
+    column: String,
+    descending: bool,
+    nulls: NullPlacement,
+}
+
+impl SortKey {
+    pub fn new(column: &str, descending: bool, nulls: NullPlacement) -> SortKey {
+        SortKey {
+            column: column.to_string(),
+            descending,
+            nulls,
+        }
+    }
+
+    pub fn get_column(&self) -> String {
+        self.column.clone()
+    }
+
+    fn compare(&self, lhs: &ConnectorType, rhs: &ConnectorType) -> Ordering {
+        match (lhs.is_null(), rhs.is_null(), self.nulls) {
+            (true, true, _) => Ordering::Equal,
+            (true, false, NullPlacement::First) | (false, true, NullPlacement::Last) => Ordering::Less,
+            (true, false, NullPlacement::Last) | (false, true, NullPlacement::First) => Ordering::Greater,
+            (false, false, _) if self.descending => total_cmp(rhs, lhs),
+            (false, false, _) => total_cmp(lhs, rhs),
+        }
+    }
+}
+
+/// Sorts the rows of a table by the keys, later keys breaking the ties of earlier ones.\
+/// The sort is stable, rows equal on every key keep their order.
+/// Returns `None` when a key column is missing.
+pub fn sort(table: &Table, keys: &[SortKey]) -> Option<Table> {
+    let columns: Vec<&Column> = keys.iter().map(|key| table.get_column(&key.get_column())).collect::<Option<_>>()?;
+    let mut rows: Vec<usize> = (0..table.get_row_count()).collect();
+
+    rows.sort_by(|lhs, rhs| {
+        keys.iter().zip(&columns)
+            .map(|(key, column)| key.compare(&column.get_values()[*lhs], &column.get_values()[*rhs]))
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    });
+
+    Some(table.take_rows(&rows))
+}
+
+/// Sorts the rows of a `Table` by one or more columns.
+pub struct SortNode {
+    keys: Vec<SortKey>,
+}
+
+impl SortNode {
+    pub fn new(keys: Vec<SortKey>) -> SortNode {
+        SortNode {
+            keys,
+        }
+    }
+}
+
+impl Node for SortNode {
+    fn get_name(&self) -> String {
+        "Sort".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![Port::new("Table", Table::new(), ConnectorTypeId::TABLE)]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Sorted", Table::new(), ConnectorTypeId::TABLE)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let table = match context.get_input(0) {
+            ConnectorType::Table(table) => table,
+            _ => return vec![ConnectorType::Null(Null::new())],
+        };
+
+        match sort(&table, &self.keys) {
+            Some(sorted) => vec![ConnectorType::Table(sorted)],
+            None => vec![ConnectorType::Null(Null::new())],
+        }
+    }
+}
+
+#[cfg(test)]
+mod sort_test {
+    use super::*;
+    use crate::helper::testing::{evaluate_table, null};
+    use crate::nodes::node_manager::Manager;
+
+    fn get_table() -> Table {
+        Table::from_columns(vec![
+            Column::new("Group", ConnectorTypeId::STRING, vec!["b", "a", "b", "a", "b"]),
+            Column::new("Score", ConnectorTypeId::INT, vec![ConnectorType::new(1), ConnectorType::new(5), null(), ConnectorType::new(2), ConnectorType::new(3)]),
+            Column::new("Row", ConnectorTypeId::INT, vec![0, 1, 2, 3, 4]),
+        ]).unwrap()
+    }
+
+    fn get_order(table: &Table) -> Vec<ConnectorType> {
+        table.get_column("Row").unwrap().get_values().to_vec()
+    }
+
+    #[test]
+    fn test_sort() {
+        let keys = [SortKey::new("Group", false, NullPlacement::Last), SortKey::new("Score", true, NullPlacement::Last)];
+
+        assert_eq!(get_order(&sort(&get_table(), &keys).unwrap()), vec![1, 3, 4, 0, 2]);
+        assert_eq!(get_order(&sort(&get_table(), &[SortKey::new("Score", false, NullPlacement::First)]).unwrap()), vec![2, 0, 3, 4, 1]);
+        // Stable, the order of the rows is kept within each group
+        assert_eq!(get_order(&sort(&get_table(), &[SortKey::new("Group", true, NullPlacement::Last)]).unwrap()), vec![0, 2, 4, 1, 3]);
+        assert_eq!(sort(&get_table(), &[SortKey::new("Missing", false, NullPlacement::Last)]), None);
+    }
+
+    #[test]
+    fn test_total_cmp() {
+        let table = Table::from_columns(vec![
+            Column::new("Value", ConnectorTypeId::ANY, vec![ConnectorType::new(3.0), ConnectorType::new(f32::NAN), ConnectorType::new("a"), ConnectorType::new(1), ConnectorType::new(-1.0)]),
+            Column::new("Row", ConnectorTypeId::INT, vec![0, 1, 2, 3, 4]),
+        ]).unwrap();
+
+        assert_eq!(get_order(&sort(&table, &[SortKey::new("Value", false, NullPlacement::Last)]).unwrap()), vec![3, 4, 0, 1, 2]);
+        assert_eq!(get_order(&sort(&table, &[SortKey::new("Value", true, NullPlacement::Last)]).unwrap()), vec![2, 1, 0, 4, 3]);
+        assert_eq!(total_cmp(&ConnectorType::new(f32::NAN), &ConnectorType::new(f32::NAN)), Ordering::Equal);
+        assert_eq!(total_cmp(&ConnectorType::new(vec![1.0, f32::NAN]), &ConnectorType::new(vec![1.0, 2.0])), Ordering::Greater);
+        assert_eq!(total_cmp(&ConnectorType::new(vec![1]), &ConnectorType::new(vec![1, 0])), Ordering::Less);
+    }
+
+    #[test]
+    fn test_node() {
+        let mut manager = Manager::new();
+        let node = manager.add_node(SortNode::new(vec![SortKey::new("Score", true, NullPlacement::First)]));
+
+        manager.set_input_value(node, 0, get_table());
+
+        assert_eq!(get_order(&evaluate_table(&mut manager, node)), vec![2, 1, 4, 3, 0]);
+    }
+}
@@ -0,0 +1,80 @@
+pub mod correlation;
+pub mod descriptive;
+pub mod distribution;
+pub mod histogram;
+pub mod hypothesis;
+pub mod online;
+pub mod regression;
+pub mod resampling;
+pub mod sampling;
+pub mod special;
+use crate::helper::types::Null;
+use super::connector_manager::value::ConnectorType;
+
+/// How statistics treat `Null` values in their input.\
+/// Values that are not numeric are treated as `Null`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NullHandling {
+    /// Nulls are left out of the statistic.
+    Skip,
+    /// Any null makes the statistic `Null`.
+    Propagate,
+    /// Nulls count as zero.
+    Zero,
+}
+
+/// Reads the numeric values of a list, a scalar is read as a list of one value.\
+/// Booleans count as integers. Returns `None` when a null propagates.
+pub fn get_values(values: &ConnectorType, null_handling: NullHandling) -> Option<Vec<ConnectorType>> {
+    let values = match values {
+        ConnectorType::List(values) => values.as_slice(),
+        value => std::slice::from_ref(value),
+    };
+
+    let mut numbers = Vec::with_capacity(values.len());
+
+    for value in values {
+        match value {
+            ConnectorType::Integer(_) | ConnectorType::Float(_) => numbers.push(value.clone()),
+            ConnectorType::Boolean(value) => numbers.push(ConnectorType::Integer(*value as i32)),
+            _ => match null_handling {
+                NullHandling::Skip => (),
+                NullHandling::Propagate => return None,
+                NullHandling::Zero => numbers.push(ConnectorType::Integer(0)),
+            },
+        }
+    }
+
+    Some(numbers)
+}
+
+/// Same as `get_values`, read as floats.
+pub fn get_numbers(values: &ConnectorType, null_handling: NullHandling) -> Option<Vec<f64>> {
+    get_values(values, null_handling).map(|values| values.iter().map(to_number).collect())
+}
+
+/// Reads a numeric scalar as a float, anything else is `NaN`.
+pub fn to_number(value: &ConnectorType) -> f64 {
+    match value {
+        ConnectorType::Integer(value) => *value as f64,
+        ConnectorType::Float(value) => *value as f64,
+        ConnectorType::Boolean(value) => *value as i32 as f64,
+        _ => f64::NAN,
+    }
+}
+
+/// Reads a numeric scalar as a float, `None` for anything else.
+pub fn get_number(value: &ConnectorType) -> Option<f64> {
+    match value {
+        ConnectorType::Integer(_) | ConnectorType::Float(_) | ConnectorType::Boolean(_) => Some(to_number(value)),
+        _ => None,
+    }
+}
+
+/// Converts a statistic to a connector value, `None` and `NaN` become `Null`.
+pub fn from_number(value: Option<f64>) -> ConnectorType {
+    match value {
+        Some(value) if !value.is_nan() => ConnectorType::Float(value as f32),
+        _ => ConnectorType::Null(Null::new()),
+    }
+}
@@ -0,0 +1,459 @@
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::table::{Column, Table};
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::io::get_unique_names;
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+use super::special::{normal_p_value, student_t_p_value};
+use super::{from_number, get_number};
+
+/// Measures of association between two variables.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CorrelationMethod {
+    /// Linear correlation of the values.
+    Pearson,
+    /// Pearson correlation of the ranks, ties get their average rank.
+    Spearman,
+    /// Kendall's tau-b, corrected for ties.
+    Kendall,
+}
+
+impl CorrelationMethod {
+    pub fn get_name(&self) -> String {
+        format!("{:?}", self)
+    }
+
+    /// Correlation coefficient and its two-sided p-value against no correlation.\
+    /// Returns `None` with fewer than two pairs or when a variable is constant.
+    pub fn correlate(&self, x: &[f64], y: &[f64]) -> Option<(f64, f64)> {
+        let count = x.len().min(y.len());
+
+        match self {
+            CorrelationMethod::Pearson => pearson(x, y).map(|r| (r, pearson_p_value(r, count))),
+            CorrelationMethod::Spearman => pearson(&ranks(x), &ranks(y)).map(|r| (r, pearson_p_value(r, count))),
+            CorrelationMethod::Kendall => kendall(x, y),
+        }
+    }
+}
+
+/// Pairs of numbers at the same position, rows where either value is not a number are left out.
+pub fn get_pairs(x: &[ConnectorType], y: &[ConnectorType]) -> (Vec<f64>, Vec<f64>) {
+    x.iter().zip(y).filter_map(|(x, y)| get_number(x).zip(get_number(y))).unzip()
+}
+
+/// Ranks starting at 1, tied values get the average of their ranks.
+pub fn ranks(values: &[f64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    let mut ranks = vec![0.0; values.len()];
+
+    order.sort_by(|lhs, rhs| values[*lhs].total_cmp(&values[*rhs]));
+
+    let mut start = 0;
+
+    while start < order.len() {
+        let value = values[order[start]];
+        let end = start + order[start..].iter().take_while(|index| values[**index] == value).count().max(1);
+        let rank = (start + end + 1) as f64 / 2.0;
+
+        order[start..end].iter().for_each(|index| ranks[*index] = rank);
+        start = end;
+    }
+
+    ranks
+}
+
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+/// Covariance of paired values, the sample covariance divides by `n - 1`.
+pub fn covariance(x: &[f64], y: &[f64], sample: bool) -> Option<f64> {
+    let count = x.len().min(y.len());
+
+    if count < 1 + sample as usize {
+        return None;
+    }
+
+    let (x, y) = (&x[..count], &y[..count]);
+    let (x_mean, y_mean) = (mean(x), mean(y));
+    let sum: f64 = x.iter().zip(y).map(|(x, y)| (x - x_mean) * (y - y_mean)).sum();
+
+    Some(sum / (count - sample as usize) as f64)
+}
+
+/// Pearson correlation coefficient of paired values.
+pub fn pearson(x: &[f64], y: &[f64]) -> Option<f64> {
+    let count = x.len().min(y.len());
+
+    if count < 2 {
+        return None;
+    }
+
+    let (x, y) = (&x[..count], &y[..count]);
+    let (x_mean, y_mean) = (mean(x), mean(y));
+    let mut products = 0.0;
+    let mut x_squares = 0.0;
+    let mut y_squares = 0.0;
+
+    for (x, y) in x.iter().zip(y) {
+        products += (x - x_mean) * (y - y_mean);
+        x_squares += (x - x_mean) * (x - x_mean);
+        y_squares += (y - y_mean) * (y - y_mean);
+    }
+
+    match x_squares > 0.0 && y_squares > 0.0 {
+        true => Some((products / (x_squares * y_squares).sqrt()).clamp(-1.0, 1.0)),
+        false => None,
+    }
+}
+
+/// P-value of a Pearson or Spearman coefficient, from a t distribution with `n - 2` degrees of freedom.
+fn pearson_p_value(r: f64, count: usize) -> f64 {
+    if count < 3 {
+        return f64::NAN;
+    }
+
+    if r.abs() >= 1.0 {
+        return 0.0;
+    }
+
+    let degrees_of_freedom = (count - 2) as f64;
+
+    student_t_p_value(r * (degrees_of_freedom / (1.0 - r * r)).sqrt(), degrees_of_freedom)
+}
+
+/// Sums of `t(t - 1)`, `t(t - 1)(2t + 5)` and `t(t - 1)(t - 2)` over the sizes `t` of the groups of ties.
+fn get_tie_sums(values: &[f64]) -> (f64, f64, f64) {
+    let mut sorted = values.to_vec();
+    let mut sums = (0.0, 0.0, 0.0);
+    let mut start = 0;
+
+    sorted.sort_by(|lhs, rhs| lhs.total_cmp(rhs));
+
+    while start < sorted.len() {
+        let size = sorted[start..].iter().take_while(|value| **value == sorted[start]).count().max(1);
+        let t = size as f64;
+
+        sums.0 += t * (t - 1.0);
+        sums.1 += t * (t - 1.0) * (2.0 * t + 5.0);
+        sums.2 += t * (t - 1.0) * (t - 2.0);
+        start += size;
+    }
+
+    sums
+}
+
+/// Kendall's tau-b and its p-value from the normal approximation, with the variance corrected for ties.\
+/// Compares every pair of observations.
+pub fn kendall(x: &[f64], y: &[f64]) -> Option<(f64, f64)> {
+    let count = x.len().min(y.len());
+    let (x, y) = (&x[..count], &y[..count]);
+    let mut score = 0.0;
+
+    for first in 0..count {
+        for second in first + 1..count {
+            let sign = (x[first] - x[second]).signum() * (y[first] - y[second]).signum();
+
+            if x[first] != x[second] && y[first] != y[second] {
+                score += sign;
+            }
+        }
+    }
+
+    let n = count as f64;
+    let pairs = n * (n - 1.0) / 2.0;
+    let (x_ties, x_variance, x_triples) = get_tie_sums(x);
+    let (y_ties, y_variance, y_triples) = get_tie_sums(y);
+    let denominator = ((pairs - x_ties / 2.0) * (pairs - y_ties / 2.0)).sqrt();
+
+    if count < 2 || denominator <= 0.0 {
+        return None;
+    }
+
+    let tau = (score / denominator).clamp(-1.0, 1.0);
+    let mut variance = (n * (n - 1.0) * (2.0 * n + 5.0) - x_variance - y_variance) / 18.0 + x_ties * y_ties / (2.0 * n * (n - 1.0));
+
+    if count > 2 {
+        variance += x_triples * y_triples / (9.0 * n * (n - 1.0) * (n - 2.0));
+    }
+
+    let p_value = match variance > 0.0 {
+        true => normal_p_value(score / variance.sqrt()),
+        false => f64::NAN,
+    };
+
+    Some((tau, p_value))
+}
+
+/// Whether a column holds numbers, untyped columns need at least one number and nothing else besides nulls.
+pub fn is_numeric(column: &Column) -> bool {
+    match column.get_type() {
+        ConnectorTypeId::INT | ConnectorTypeId::FLOAT | ConnectorTypeId::BOOL => true,
+        ConnectorTypeId::ANY => {
+            let values = column.get_values().iter().filter(|value| !value.is_null());
+
+            values.clone().count() > 0 && values.clone().all(|value| get_number(value).is_some())
+        },
+        _ => false,
+    }
+}
+
+/// Square matrix over the numeric columns of a table, each cell computed from the pairwise complete rows of two columns.\
+/// The first column holds the names of the columns, it is named `Column` with a `_n` suffix when a numeric column has that name.
+pub fn get_matrix<F>(table: &Table, cell: F) -> Table
+where
+    F: Fn(&[f64], &[f64]) -> Option<f64>
+{
+    let columns: Vec<&Column> = table.get_columns().iter().filter(|column| is_numeric(column)).collect();
+    let names: Vec<String> = columns.iter().map(|column| column.get_name()).collect();
+    let mut labels = names.clone();
+
+    // Named after the columns so their names are kept and this one is made unique
+    labels.push("Column".to_string());
+
+    let label = get_unique_names(&labels).pop().unwrap();
+    let mut matrix = vec![Column::new(&label, ConnectorTypeId::STRING, names)];
+
+    for column in &columns {
+        let values = columns.iter().map(|other| {
+            let (x, y) = get_pairs(other.get_values(), column.get_values());
+
+            from_number(cell(&x, &y))
+        }).collect();
+
+        matrix.push(Column::new(&column.get_name(), ConnectorTypeId::FLOAT, values));
+    }
+
+    Table::from_columns(matrix).unwrap()
+}
+
+/// Correlation between the values of `X` and `Y` at the same positions.\
+/// Pairs with a missing value are left out, `Count` is the number of pairs used.
+pub struct CorrelationNode {
+    method: CorrelationMethod,
+}
+
+impl CorrelationNode {
+    pub fn new(method: CorrelationMethod) -> CorrelationNode {
+        CorrelationNode {
+            method,
+        }
+    }
+}
+
+impl Node for CorrelationNode {
+    fn get_name(&self) -> String {
+        format!("{} Correlation", self.method.get_name())
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("X", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+            Port::new("Y", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Coefficient", Null::new(), ConnectorTypeId::ANY),
+            Port::new("P-Value", Null::new(), ConnectorTypeId::ANY),
+            Port::new("Count", 0, ConnectorTypeId::INT),
+        ]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let x = context.get_input(0).get_variant(ConnectorTypeId::LIST);
+        let y = context.get_input(1).get_variant(ConnectorTypeId::LIST);
+
+        let (x, y) = match (x, y) {
+            (ConnectorType::List(x), ConnectorType::List(y)) => get_pairs(&x, &y),
+            _ => (Vec::new(), Vec::new()),
+        };
+
+        let result = self.method.correlate(&x, &y);
+
+        vec![
+            from_number(result.map(|result| result.0)),
+            from_number(result.map(|result| result.1)),
+            ConnectorType::Integer(x.len() as i32),
+        ]
+    }
+}
+
+/// Correlation between every pair of numeric columns of a table, with the p-values in a second matrix.
+pub struct CorrelationMatrixNode {
+    method: CorrelationMethod,
+}
+
+impl CorrelationMatrixNode {
+    pub fn new(method: CorrelationMethod) -> CorrelationMatrixNode {
+        CorrelationMatrixNode {
+            method,
+        }
+    }
+}
+
+impl Node for CorrelationMatrixNode {
+    fn get_name(&self) -> String {
+        format!("{} Correlation Matrix", self.method.get_name())
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![Port::new("Table", Table::new(), ConnectorTypeId::TABLE)]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Correlation", Table::new(), ConnectorTypeId::TABLE),
+            Port::new("P-Values", Table::new(), ConnectorTypeId::TABLE),
+        ]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let table = match context.get_input(0) {
+            ConnectorType::Table(table) => table,
+            _ => return vec![ConnectorType::Null(Null::new()); 2],
+        };
+
+        vec![
+            ConnectorType::Table(get_matrix(&table, |x, y| self.method.correlate(x, y).map(|result| result.0))),
+            ConnectorType::Table(get_matrix(&table, |x, y| self.method.correlate(x, y).map(|result| result.1))),
+        ]
+    }
+}
+
+/// Sample covariance between every pair of numeric columns of a table.
+pub struct CovarianceMatrixNode {}
+
+impl CovarianceMatrixNode {
+    pub fn new() -> CovarianceMatrixNode {
+        CovarianceMatrixNode {}
+    }
+}
+
+impl Default for CovarianceMatrixNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for CovarianceMatrixNode {
+    fn get_name(&self) -> String {
+        "Covariance Matrix".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![Port::new("Table", Table::new(), ConnectorTypeId::TABLE)]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Covariance", Table::new(), ConnectorTypeId::TABLE)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        match context.get_input(0) {
+            ConnectorType::Table(table) => vec![ConnectorType::Table(get_matrix(&table, |x, y| covariance(x, y, true)))],
+            _ => vec![ConnectorType::Null(Null::new())],
+        }
+    }
+}
+
+#[cfg(test)]
+mod correlation_test {
+    use super::*;
+    use crate::helper::testing::evaluate_table;
+    use crate::nodes::node_manager::Manager;
+
+    fn assert_close(value: f64, expected: f64) {
+        assert!((value - expected).abs() < 1e-6, "{} should be close to {}", value, expected);
+    }
+
+    #[test]
+    fn test_ranks() {
+        assert_eq!(ranks(&[10.0, 30.0, 20.0, 20.0]), vec![1.0, 4.0, 2.5, 2.5]);
+        assert_eq!(ranks(&[]), Vec::<f64>::new());
+    }
+
+    #[test]
+    fn test_pearson() {
+        let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let y = [2.0, 4.0, 5.0, 4.0, 5.0];
+        let (r, p) = CorrelationMethod::Pearson.correlate(&x, &y).unwrap();
+
+        assert_close(r, 0.774_596_669);
+        assert_close(p, 0.124_027_063);
+        assert_eq!(pearson(&x, &[1.0; 5]), None);
+        assert_close(covariance(&x, &y, true).unwrap(), 1.5);
+        assert_close(covariance(&x, &y, false).unwrap(), 1.2);
+    }
+
+    #[test]
+    fn test_spearman() {
+        let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let (r, p) = CorrelationMethod::Spearman.correlate(&x, &[1.0, 4.0, 9.0, 16.0, 100.0]).unwrap();
+
+        assert_close(r, 1.0);
+        assert_close(p, 0.0);
+
+        let (r, _) = CorrelationMethod::Spearman.correlate(&x, &[5.0, 6.0, 7.0, 8.0, 7.0]).unwrap();
+
+        assert_close(r, 0.820_782_681);
+    }
+
+    #[test]
+    fn test_kendall() {
+        let (tau, p) = kendall(&[1.0, 2.0, 3.0, 4.0, 5.0], &[3.0, 1.0, 2.0, 5.0, 4.0]).unwrap();
+
+        assert_close(tau, 0.4);
+        assert_close(p, 0.327_186_878);
+
+        let (tau, _) = kendall(&[1.0, 2.0, 2.0, 3.0], &[1.0, 2.0, 3.0, 3.0]).unwrap();
+
+        assert_close(tau, 0.8);
+        assert_eq!(kendall(&[1.0], &[1.0]), None);
+    }
+
+    #[test]
+    fn test_matrix() {
+        let null = ConnectorType::Null(Null::new());
+        let table = Table::from_columns(vec![
+            Column::new("A", ConnectorTypeId::INT, vec![ConnectorType::new(1), ConnectorType::new(2), ConnectorType::new(3), null.clone()]),
+            Column::new("Name", ConnectorTypeId::STRING, vec!["a", "b", "c", "d"]),
+            Column::new("B", ConnectorTypeId::ANY, vec![ConnectorType::new(2.0), null, ConnectorType::new(6.0), ConnectorType::new(8.0)]),
+        ]).unwrap();
+
+        let matrix = get_matrix(&table, |x, y| covariance(x, y, true));
+
+        assert_eq!(matrix.get_column_names(), vec!["Column", "A", "B"]);
+        assert_eq!(matrix.get_column("Column").unwrap().get_values(), &[ConnectorType::new("A"), ConnectorType::new("B")]);
+        // A and B only share the first and third rows
+        assert_eq!(matrix.get_row(0).unwrap(), vec![ConnectorType::new("A"), ConnectorType::new(1.0), ConnectorType::new(4.0)]);
+        assert_eq!(matrix.get_row(1).unwrap(), vec![ConnectorType::new("B"), ConnectorType::new(4.0), ConnectorType::new(9.333_333)]);
+
+        let table = Table::from_columns(vec![
+            Column::new("Column", ConnectorTypeId::INT, vec![1, 2, 3]),
+            Column::new("Column_2", ConnectorTypeId::INT, vec![3, 2, 1]),
+        ]).unwrap();
+
+        assert_eq!(get_matrix(&table, |x, y| covariance(x, y, true)).get_column_names(), vec!["Column_3", "Column", "Column_2"]);
+    }
+
+    #[test]
+    fn test_nodes() {
+        let mut manager = Manager::new();
+        let correlation = manager.add_node(CorrelationNode::new(CorrelationMethod::Pearson));
+        let matrix = manager.add_node(CorrelationMatrixNode::new(CorrelationMethod::Spearman));
+
+        manager.set_input_value(correlation, 0, vec![1, 2, 3, 4]);
+        manager.set_input_value(correlation, 1, vec![ConnectorType::new(2), ConnectorType::Null(Null::new()), ConnectorType::new(6), ConnectorType::new(8)]);
+
+        assert_eq!(manager.evaluate(correlation), vec![ConnectorType::new(1.0), ConnectorType::new(0.0), ConnectorType::new(3)]);
+
+        manager.set_input_value(matrix, 0, Table::from_columns(vec![
+            Column::new("X", ConnectorTypeId::FLOAT, vec![1.0, 2.0, 3.0]),
+            Column::new("Y", ConnectorTypeId::FLOAT, vec![3.0, 2.0, 1.0]),
+        ]).unwrap());
+
+        assert_eq!(evaluate_table(&mut manager, matrix).get_row(0).unwrap(), vec![ConnectorType::new("X"), ConnectorType::new(1.0), ConnectorType::new(-1.0)]);
+    }
+}
@@ -0,0 +1,642 @@
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::math::broadcast;
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+use crate::helper::bytes::{ByteReader, ByteWriter};
+use super::online::Accumulator;
+use super::{NullHandling, from_number, get_number, get_values, to_number};
+
+/// Statistics describing a list of numbers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Statistic {
+    Count,
+    Sum,
+    Mean,
+    Median,
+    Mode,
+    Min,
+    Max,
+    Range,
+    /// Population variance.
+    Variance,
+    /// Sample variance, with Bessel's correction.
+    SampleVariance,
+    /// Population standard deviation.
+    StandardDeviation,
+    /// Sample standard deviation, with Bessel's correction.
+    SampleStandardDeviation,
+    /// Adjusted Fisher-Pearson skewness.
+    Skewness,
+    /// Sample excess kurtosis.
+    Kurtosis,
+}
+
+impl Statistic {
+    pub const ALL: [Statistic; 14] = [
+        Statistic::Count,
+        Statistic::Sum,
+        Statistic::Mean,
+        Statistic::Median,
+        Statistic::Mode,
+        Statistic::Min,
+        Statistic::Max,
+        Statistic::Range,
+        Statistic::Variance,
+        Statistic::SampleVariance,
+        Statistic::StandardDeviation,
+        Statistic::SampleStandardDeviation,
+        Statistic::Skewness,
+        Statistic::Kurtosis,
+    ];
+
+    pub fn get_name(&self) -> String {
+        match self {
+            Statistic::SampleVariance => "Sample Variance".to_string(),
+            Statistic::StandardDeviation => "Standard Deviation".to_string(),
+            Statistic::SampleStandardDeviation => "Sample Standard Deviation".to_string(),
+            statistic => format!("{:?}", statistic),
+        }
+    }
+
+    pub fn compute(&self, values: &ConnectorType, null_handling: NullHandling) -> ConnectorType {
+        match Summary::new(values, null_handling) {
+            Some(summary) => summary.get(*self),
+            None => ConnectorType::Null(Null::new()),
+        }
+    }
+}
+
+/// Compensated (Kahan-Babuska-Neumaier) sum, keeps the rounding error of every addition.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct KahanSum {
+    sum: f64,
+    compensation: f64,
+}
+
+impl KahanSum {
+    pub fn new() -> KahanSum {
+        KahanSum {
+            sum: 0.0,
+            compensation: 0.0,
+        }
+    }
+
+    pub fn add(&mut self, value: f64) {
+        let sum = self.sum + value;
+
+        if self.sum.abs() >= value.abs() {
+            self.compensation += (self.sum - sum) + value;
+        } else {
+            self.compensation += (value - sum) + self.sum;
+        }
+
+        self.sum = sum;
+    }
+
+    pub fn get_sum(&self) -> f64 {
+        self.sum + self.compensation
+    }
+}
+
+impl Accumulator for KahanSum {
+    fn get_name(&self) -> String {
+        "Running Sum".to_string()
+    }
+
+    fn update(&mut self, value: &ConnectorType) {
+        if let Some(value) = get_number(value) {
+            self.add(value);
+        }
+    }
+
+    fn merge(&mut self, other: &Self) {
+        self.add(other.sum);
+        self.add(other.compensation);
+    }
+
+    fn get_result_names(&self) -> Vec<String> {
+        vec!["Sum".to_string()]
+    }
+
+    fn get_results(&self) -> Vec<ConnectorType> {
+        vec![from_number(Some(self.get_sum()))]
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut writer = ByteWriter::new();
+
+        writer.write_f64(self.sum);
+        writer.write_f64(self.compensation);
+        writer.into_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut reader = ByteReader::new(bytes);
+
+        Some(KahanSum {
+            sum: reader.read_f64()?,
+            compensation: reader.read_f64()?,
+        })
+    }
+}
+
+/// Running central moments of a stream of numbers, updated with Welford's algorithm
+/// extended to the third and fourth moments.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Moments {
+    count: u64,
+    mean: f64,
+    m2: f64,
+    m3: f64,
+    m4: f64,
+}
+
+impl Moments {
+    pub fn new() -> Moments {
+        Moments {
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+            m3: 0.0,
+            m4: 0.0,
+        }
+    }
+
+    pub fn push(&mut self, value: f64) {
+        let previous = self.count as f64;
+
+        self.count += 1;
+
+        let count = self.count as f64;
+        let delta = value - self.mean;
+        let delta_n = delta / count;
+        let delta_n2 = delta_n * delta_n;
+        let term = delta * delta_n * previous;
+
+        self.mean += delta_n;
+        self.m4 += term * delta_n2 * (count * count - 3.0 * count + 3.0) + 6.0 * delta_n2 * self.m2 - 4.0 * delta_n * self.m3;
+        self.m3 += term * delta_n * (count - 2.0) - 3.0 * delta_n * self.m2;
+        self.m2 += term;
+    }
+
+    /// Combines the moments of two partitions as if all values were pushed to one (Pebay, 2008).
+    pub fn merge(&mut self, other: &Moments) {
+        if other.count == 0 {
+            return;
+        }
+
+        if self.count == 0 {
+            *self = *other;
+            return;
+        }
+
+        let lhs = self.count as f64;
+        let rhs = other.count as f64;
+        let count = lhs + rhs;
+        let delta = other.mean - self.mean;
+        let delta2 = delta * delta;
+
+        let m2 = self.m2 + other.m2 + delta2 * lhs * rhs / count;
+        let m3 = self.m3 + other.m3
+            + delta2 * delta * lhs * rhs * (lhs - rhs) / (count * count)
+            + 3.0 * delta * (lhs * other.m2 - rhs * self.m2) / count;
+        let m4 = self.m4 + other.m4
+            + delta2 * delta2 * lhs * rhs * (lhs * lhs - lhs * rhs + rhs * rhs) / (count * count * count)
+            + 6.0 * delta2 * (lhs * lhs * other.m2 + rhs * rhs * self.m2) / (count * count)
+            + 4.0 * delta * (lhs * other.m3 - rhs * self.m3) / count;
+
+        self.count += other.count;
+        self.mean += delta * rhs / count;
+        self.m2 = m2;
+        self.m3 = m3;
+        self.m4 = m4;
+    }
+
+    pub fn get_count(&self) -> u64 {
+        self.count
+    }
+
+    pub fn get_mean(&self) -> Option<f64> {
+        match self.count {
+            0 => None,
+            _ => Some(self.mean),
+        }
+    }
+
+    /// Population variance, or sample variance with Bessel's correction.
+    pub fn get_variance(&self, sample: bool) -> Option<f64> {
+        let count = self.count as f64;
+
+        match (sample, self.count) {
+            (false, 0) | (true, 0..=1) => None,
+            (false, _) => Some(self.m2 / count),
+            (true, _) => Some(self.m2 / (count - 1.0)),
+        }
+    }
+
+    /// Adjusted Fisher-Pearson skewness, needs at least three values that are not all equal.
+    pub fn get_skewness(&self) -> Option<f64> {
+        let count = self.count as f64;
+
+        if self.count < 3 || self.m2 == 0.0 {
+            return None;
+        }
+
+        let skewness = count.sqrt() * self.m3 / self.m2.powf(1.5);
+
+        Some(skewness * (count * (count - 1.0)).sqrt() / (count - 2.0))
+    }
+
+    /// Sample excess kurtosis, needs at least four values that are not all equal.
+    pub fn get_kurtosis(&self) -> Option<f64> {
+        let count = self.count as f64;
+
+        if self.count < 4 || self.m2 == 0.0 {
+            return None;
+        }
+
+        let kurtosis = count * self.m4 / (self.m2 * self.m2) - 3.0;
+
+        Some(((count + 1.0) * kurtosis + 6.0) * (count - 1.0) / ((count - 2.0) * (count - 3.0)))
+    }
+}
+
+impl Accumulator for Moments {
+    fn get_name(&self) -> String {
+        "Running Moments".to_string()
+    }
+
+    fn update(&mut self, value: &ConnectorType) {
+        if let Some(value) = get_number(value) {
+            self.push(value);
+        }
+    }
+
+    fn merge(&mut self, other: &Self) {
+        Moments::merge(self, other);
+    }
+
+    fn get_result_names(&self) -> Vec<String> {
+        ["Count", "Mean", "Variance", "Sample Variance", "Standard Deviation", "Skewness", "Kurtosis"].iter().map(|name| name.to_string()).collect()
+    }
+
+    fn get_results(&self) -> Vec<ConnectorType> {
+        vec![
+            ConnectorType::Integer(self.count as i32),
+            from_number(self.get_mean()),
+            from_number(self.get_variance(false)),
+            from_number(self.get_variance(true)),
+            from_number(self.get_variance(false).map(f64::sqrt)),
+            from_number(self.get_skewness()),
+            from_number(self.get_kurtosis()),
+        ]
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut writer = ByteWriter::new();
+
+        writer.write_u64(self.count);
+
+        for value in [self.mean, self.m2, self.m3, self.m4] {
+            writer.write_f64(value);
+        }
+
+        writer.into_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut reader = ByteReader::new(bytes);
+
+        Some(Moments {
+            count: reader.read_u64()?,
+            mean: reader.read_f64()?,
+            m2: reader.read_f64()?,
+            m3: reader.read_f64()?,
+            m4: reader.read_f64()?,
+        })
+    }
+}
+
+/// Quantile of sorted values, linearly interpolating between the closest ranks.
+pub fn quantile(sorted: &[f64], quantile: f64) -> Option<f64> {
+    if sorted.is_empty() || !(0.0..=1.0).contains(&quantile) {
+        return None;
+    }
+
+    let position = quantile * (sorted.len() - 1) as f64;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+
+    Some(sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64))
+}
+
+/// All descriptive statistics of a list, computed in a single pass plus a sort.
+pub struct Summary {
+    sorted: Vec<f64>,
+    sum: KahanSum,
+    moments: Moments,
+    is_integer: bool,
+}
+
+impl Summary {
+    /// Returns `None` when a null propagates.
+    pub fn new(values: &ConnectorType, null_handling: NullHandling) -> Option<Summary> {
+        let values = get_values(values, null_handling)?;
+        let mut sum = KahanSum::new();
+        let mut moments = Moments::new();
+
+        for value in values.iter().map(to_number) {
+            sum.add(value);
+            moments.push(value);
+        }
+
+        let mut sorted: Vec<f64> = values.iter().map(to_number).collect();
+
+        sorted.sort_by(|lhs, rhs| lhs.total_cmp(rhs));
+
+        Some(Summary {
+            sorted,
+            sum,
+            moments,
+            is_integer: values.iter().all(|value| matches!(value, ConnectorType::Integer(_))),
+        })
+    }
+
+    /// Integer statistics of integer lists stay integers when they fit.
+    fn get_number(&self, value: Option<f64>) -> ConnectorType {
+        match value {
+            Some(value) if self.is_integer && value.abs() <= i32::MAX as f64 => ConnectorType::Integer(value as i32),
+            value => from_number(value),
+        }
+    }
+
+    pub fn get_quantile(&self, value: f64) -> ConnectorType {
+        from_number(quantile(&self.sorted, value))
+    }
+
+    pub fn get(&self, statistic: Statistic) -> ConnectorType {
+        let min = self.sorted.first().copied();
+        let max = self.sorted.last().copied();
+
+        match statistic {
+            Statistic::Count => ConnectorType::Integer(self.moments.get_count() as i32),
+            Statistic::Sum => self.get_number(Some(self.sum.get_sum())),
+            Statistic::Mean => from_number(self.moments.get_mean()),
+            Statistic::Median => from_number(quantile(&self.sorted, 0.5)),
+            Statistic::Mode => self.get_number(self.get_mode()),
+            Statistic::Min => self.get_number(min),
+            Statistic::Max => self.get_number(max),
+            Statistic::Range => self.get_number(min.zip(max).map(|(min, max)| max - min)),
+            Statistic::Variance => from_number(self.moments.get_variance(false)),
+            Statistic::SampleVariance => from_number(self.moments.get_variance(true)),
+            Statistic::StandardDeviation => from_number(self.moments.get_variance(false).map(f64::sqrt)),
+            Statistic::SampleStandardDeviation => from_number(self.moments.get_variance(true).map(f64::sqrt)),
+            Statistic::Skewness => from_number(self.moments.get_skewness()),
+            Statistic::Kurtosis => from_number(self.moments.get_kurtosis()),
+        }
+    }
+
+    /// Most frequent value, the smallest one on ties.
+    fn get_mode(&self) -> Option<f64> {
+        let mut mode = None;
+        let mut mode_count = 0;
+        let mut index = 0;
+
+        while index < self.sorted.len() {
+            let value = self.sorted[index];
+            let count = self.sorted[index..].iter().take_while(|other| **other == value).count().max(1);
+
+            if count > mode_count {
+                mode = Some(value);
+                mode_count = count;
+            }
+
+            index += count;
+        }
+
+        mode
+    }
+}
+
+/// Computes a single `Statistic` of `Values`.
+pub struct StatisticNode {
+    statistic: Statistic,
+    null_handling: NullHandling,
+}
+
+impl StatisticNode {
+    pub fn new(statistic: Statistic, null_handling: NullHandling) -> StatisticNode {
+        StatisticNode {
+            statistic,
+            null_handling,
+        }
+    }
+}
+
+impl Node for StatisticNode {
+    fn get_name(&self) -> String {
+        self.statistic.get_name()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![Port::new("Values", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST)]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Result", Null::new(), ConnectorTypeId::ANY)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        vec![self.statistic.compute(&context.get_input(0), self.null_handling)]
+    }
+}
+
+/// Outputs every `Statistic` of `Values`, in the order of `Statistic::ALL`.
+pub struct SummaryNode {
+    null_handling: NullHandling,
+}
+
+impl SummaryNode {
+    pub fn new(null_handling: NullHandling) -> SummaryNode {
+        SummaryNode {
+            null_handling,
+        }
+    }
+}
+
+impl Node for SummaryNode {
+    fn get_name(&self) -> String {
+        "Summary".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![Port::new("Values", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST)]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        Statistic::ALL.iter().map(|statistic| Port::new(&statistic.get_name(), Null::new(), ConnectorTypeId::ANY)).collect()
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        match Summary::new(&context.get_input(0), self.null_handling) {
+            Some(summary) => Statistic::ALL.iter().map(|statistic| summary.get(*statistic)).collect(),
+            None => Statistic::ALL.iter().map(|_| ConnectorType::Null(Null::new())).collect(),
+        }
+    }
+}
+
+/// Quantiles of `Values`, `Quantile` can be a single value in `[0, 1]` or a list of them.
+pub struct QuantileNode {
+    null_handling: NullHandling,
+}
+
+impl QuantileNode {
+    pub fn new(null_handling: NullHandling) -> QuantileNode {
+        QuantileNode {
+            null_handling,
+        }
+    }
+}
+
+impl Node for QuantileNode {
+    fn get_name(&self) -> String {
+        "Quantile".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Values", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+            Port::new("Quantile", 0.5, ConnectorTypeId::ANY),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Result", Null::new(), ConnectorTypeId::ANY)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let summary = match Summary::new(&context.get_input(0), self.null_handling) {
+            Some(summary) => summary,
+            None => return vec![ConnectorType::Null(Null::new())],
+        };
+
+        vec![broadcast(&[context.get_input(1)], &|operands| match &operands[0] {
+            ConnectorType::Null(_) => ConnectorType::Null(Null::new()),
+            value => summary.get_quantile(to_number(value)),
+        })]
+    }
+}
+
+#[cfg(test)]
+mod descriptive_test {
+    use super::*;
+    use crate::nodes::node_manager::Manager;
+
+    fn assert_close(value: ConnectorType, expected: f32) {
+        match value {
+            ConnectorType::Float(value) => assert!((value - expected).abs() < 1e-4, "{} should be close to {}", value, expected),
+            value => panic!("{:?} should be a float", value),
+        }
+    }
+
+    #[test]
+    fn test_statistics() {
+        let values = ConnectorType::new(vec![2, 4, 4, 4, 5, 5, 7, 9]);
+        let summary = Summary::new(&values, NullHandling::Skip).unwrap();
+
+        assert_eq!(summary.get(Statistic::Count), 8);
+        assert_eq!(summary.get(Statistic::Sum), 40);
+        assert_eq!(summary.get(Statistic::Mean), 5.0);
+        assert_eq!(summary.get(Statistic::Median), 4.5);
+        assert_eq!(summary.get(Statistic::Mode), 4);
+        assert_eq!(summary.get(Statistic::Min), 2);
+        assert_eq!(summary.get(Statistic::Max), 9);
+        assert_eq!(summary.get(Statistic::Range), 7);
+        assert_eq!(summary.get(Statistic::Variance), 4.0);
+        assert_eq!(summary.get(Statistic::StandardDeviation), 2.0);
+        assert_close(summary.get(Statistic::SampleVariance), 4.571429);
+        assert_close(summary.get(Statistic::SampleStandardDeviation), 2.13809);
+        assert_close(summary.get(Statistic::Skewness), 0.818487);
+        assert_close(summary.get(Statistic::Kurtosis), 0.940625);
+        assert_close(summary.get_quantile(0.25), 4.0);
+        assert_close(summary.get_quantile(0.9), 7.6);
+    }
+
+    #[test]
+    fn test_float_statistics() {
+        let values = ConnectorType::new(vec![1.5, 2.5, 2.5, 10.0]);
+
+        assert_eq!(Statistic::Sum.compute(&values, NullHandling::Skip), 16.5);
+        assert_eq!(Statistic::Mode.compute(&values, NullHandling::Skip), 2.5);
+        assert_eq!(Statistic::Range.compute(&values, NullHandling::Skip), 8.5);
+        assert_eq!(Statistic::Median.compute(&ConnectorType::new(3), NullHandling::Skip), 3.0);
+    }
+
+    #[test]
+    fn test_null_handling() {
+        let values = ConnectorType::new(vec![ConnectorType::new(4), ConnectorType::Null(Null::new()), ConnectorType::new(2)]);
+
+        assert_eq!(Statistic::Mean.compute(&values, NullHandling::Skip), 3.0);
+        assert_eq!(Statistic::Mean.compute(&values, NullHandling::Zero), 2.0);
+        assert_eq!(Statistic::Mean.compute(&values, NullHandling::Propagate), Null::new());
+        assert_eq!(Statistic::Count.compute(&values, NullHandling::Skip), 2);
+    }
+
+    #[test]
+    fn test_empty() {
+        let values = ConnectorType::List(Vec::new());
+
+        assert_eq!(Statistic::Count.compute(&values, NullHandling::Skip), 0);
+        assert_eq!(Statistic::Sum.compute(&values, NullHandling::Skip), 0);
+        assert_eq!(Statistic::Mean.compute(&values, NullHandling::Skip), Null::new());
+        assert_eq!(Statistic::Min.compute(&values, NullHandling::Skip), Null::new());
+        assert_eq!(Statistic::SampleVariance.compute(&ConnectorType::new(vec![1]), NullHandling::Skip), Null::new());
+        assert_eq!(Statistic::Skewness.compute(&ConnectorType::new(vec![1, 1, 1]), NullHandling::Skip), Null::new());
+    }
+
+    #[test]
+    fn test_numerical_stability() {
+        let values: Vec<f64> = (0..1000).map(|index| 1e9 + (index % 4) as f64).collect();
+        let mut moments = Moments::new();
+        let mut sum = KahanSum::new();
+
+        for value in &values {
+            moments.push(*value);
+            sum.add(*value);
+        }
+
+        assert!((moments.get_mean().unwrap() - (1e9 + 1.5)).abs() < 1e-6);
+        assert!((moments.get_variance(false).unwrap() - 1.25).abs() < 1e-6);
+
+        let mut small = KahanSum::new();
+
+        for value in [1.0, 1e100, 1.0, -1e100] {
+            small.add(value);
+        }
+
+        assert_eq!(small.get_sum(), 2.0);
+    }
+
+    #[test]
+    fn test_nodes() {
+        let mut manager = Manager::new();
+
+        let summary = manager.add_node(SummaryNode::new(NullHandling::Skip));
+        let quantile = manager.add_node(QuantileNode::new(NullHandling::Skip));
+        let median = manager.add_node(StatisticNode::new(Statistic::Median, NullHandling::Skip));
+
+        manager.set_input_value(summary, 0, vec![3, 1, 2]);
+        manager.set_input_value(quantile, 0, vec![3, 1, 2]);
+        manager.set_input_value(quantile, 1, vec![0.0, 0.25, 2.0]);
+        manager.set_input_value(median, 0, vec![3, 1, 2, 10]);
+
+        let outputs = manager.evaluate(summary);
+
+        assert_eq!(outputs.len(), Statistic::ALL.len());
+        assert_eq!(outputs[0], 3);
+        assert_eq!(outputs[2], 2.0);
+        assert_eq!(manager.evaluate(quantile), vec![ConnectorType::new(vec![ConnectorType::new(1.0), ConnectorType::new(1.5), ConnectorType::Null(Null::new())])]);
+        assert_eq!(manager.evaluate(median), vec![ConnectorType::new(2.5)]);
+    }
+}
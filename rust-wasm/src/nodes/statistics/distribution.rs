@@ -0,0 +1,640 @@
+use crate::helper::random::Random;
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::math::broadcast;
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+use super::special::{beta_regularized, gamma_p, gamma_q, ln_beta, ln_gamma, normal_cdf, student_t_cdf};
+use super::{from_number, get_number};
+
+/// Families of probability distributions.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DistributionKind {
+    Normal,
+    StudentT,
+    ChiSquared,
+    F,
+    Binomial,
+    Poisson,
+    Exponential,
+    Uniform,
+    Beta,
+    Gamma,
+}
+
+impl DistributionKind {
+    pub const ALL: [DistributionKind; 10] = [
+        DistributionKind::Normal,
+        DistributionKind::StudentT,
+        DistributionKind::ChiSquared,
+        DistributionKind::F,
+        DistributionKind::Binomial,
+        DistributionKind::Poisson,
+        DistributionKind::Exponential,
+        DistributionKind::Uniform,
+        DistributionKind::Beta,
+        DistributionKind::Gamma,
+    ];
+
+    pub fn get_name(&self) -> String {
+        match self {
+            DistributionKind::StudentT => "Student's T".to_string(),
+            DistributionKind::ChiSquared => "Chi-Squared".to_string(),
+            kind => format!("{:?}", kind),
+        }
+    }
+
+    /// Names and default values of the parameters, in the order `Distribution::new` takes them.
+    pub fn get_parameters(&self) -> Vec<(&'static str, f64)> {
+        match self {
+            DistributionKind::Normal => vec![("Mean", 0.0), ("Standard Deviation", 1.0)],
+            DistributionKind::StudentT | DistributionKind::ChiSquared => vec![("Degrees of Freedom", 1.0)],
+            DistributionKind::F => vec![("Numerator Degrees of Freedom", 1.0), ("Denominator Degrees of Freedom", 1.0)],
+            DistributionKind::Binomial => vec![("Trials", 1.0), ("Probability", 0.5)],
+            DistributionKind::Poisson | DistributionKind::Exponential => vec![("Rate", 1.0)],
+            DistributionKind::Uniform => vec![("Min", 0.0), ("Max", 1.0)],
+            DistributionKind::Beta => vec![("Alpha", 1.0), ("Beta", 1.0)],
+            DistributionKind::Gamma => vec![("Shape", 1.0), ("Scale", 1.0)],
+        }
+    }
+
+    /// Discrete distributions take integer values and have a probability mass function.
+    pub fn is_discrete(&self) -> bool {
+        matches!(self, DistributionKind::Binomial | DistributionKind::Poisson)
+    }
+}
+
+/// A distribution of a `DistributionKind` with its parameters.
+///
+/// # Example
+/// ```
+/// let normal = Distribution::new(DistributionKind::Normal, &[0.0, 1.0]).unwrap();
+///
+/// assert!((normal.cdf(1.96) - 0.975).abs() < 1e-4);
+/// assert!((normal.quantile(0.975) - 1.96).abs() < 1e-2);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Distribution {
+    kind: DistributionKind,
+    parameters: Vec<f64>,
+}
+
+impl Distribution {
+    /// Returns `None` when the parameters are missing or outside their valid range.
+    pub fn new(kind: DistributionKind, parameters: &[f64]) -> Option<Distribution> {
+        if parameters.len() != kind.get_parameters().len() || parameters.iter().any(|parameter| !parameter.is_finite()) {
+            return None;
+        }
+
+        let valid = match kind {
+            DistributionKind::Normal => parameters[1] > 0.0,
+            DistributionKind::Binomial => parameters[0] >= 0.0 && parameters[0].fract() == 0.0 && (0.0..=1.0).contains(&parameters[1]),
+            DistributionKind::Uniform => parameters[0] < parameters[1],
+            _ => parameters.iter().all(|parameter| *parameter > 0.0),
+        };
+
+        valid.then(|| Distribution {
+            kind,
+            parameters: parameters.to_vec(),
+        })
+    }
+
+    /// Smallest and largest values the distribution can take.
+    pub fn get_support(&self) -> (f64, f64) {
+        match self.kind {
+            DistributionKind::Normal | DistributionKind::StudentT => (f64::NEG_INFINITY, f64::INFINITY),
+            DistributionKind::Binomial => (0.0, self.parameters[0]),
+            DistributionKind::Uniform => (self.parameters[0], self.parameters[1]),
+            DistributionKind::Beta => (0.0, 1.0),
+            _ => (0.0, f64::INFINITY),
+        }
+    }
+
+    /// Probability density, or probability mass for discrete distributions.
+    pub fn pdf(&self, x: f64) -> f64 {
+        let (min, max) = self.get_support();
+        let parameters = &self.parameters;
+
+        if x.is_nan() {
+            return f64::NAN;
+        }
+
+        if x < min || x > max || (self.kind.is_discrete() && x.fract() != 0.0) {
+            return 0.0;
+        }
+
+        match self.kind {
+            DistributionKind::Normal => {
+                let z = (x - parameters[0]) / parameters[1];
+
+                (-0.5 * z * z).exp() / (parameters[1] * (2.0 * std::f64::consts::PI).sqrt())
+            },
+            DistributionKind::StudentT => {
+                let freedom = parameters[0];
+
+                (ln_gamma((freedom + 1.0) / 2.0) - ln_gamma(freedom / 2.0) - 0.5 * (freedom * std::f64::consts::PI).ln() - (freedom + 1.0) / 2.0 * (1.0 + x * x / freedom).ln()).exp()
+            },
+            DistributionKind::ChiSquared => gamma_pdf(x, parameters[0] / 2.0, 2.0),
+            DistributionKind::F => {
+                let (numerator, denominator) = (parameters[0], parameters[1]);
+
+                if x == 0.0 {
+                    return match numerator {
+                        2.0 => 1.0,
+                        numerator if numerator < 2.0 => f64::INFINITY,
+                        _ => 0.0,
+                    };
+                }
+
+                (0.5 * (numerator * (numerator * x).ln() + denominator * denominator.ln() - (numerator + denominator) * (numerator * x + denominator).ln()) - x.ln() - ln_beta(numerator / 2.0, denominator / 2.0)).exp()
+            },
+            DistributionKind::Binomial => {
+                let (trials, probability) = (parameters[0], parameters[1]);
+
+                match probability {
+                    0.0 => (x == 0.0) as i32 as f64,
+                    1.0 => (x == trials) as i32 as f64,
+                    _ => (ln_gamma(trials + 1.0) - ln_gamma(x + 1.0) - ln_gamma(trials - x + 1.0) + x * probability.ln() + (trials - x) * (1.0 - probability).ln()).exp(),
+                }
+            },
+            DistributionKind::Poisson => (x * parameters[0].ln() - parameters[0] - ln_gamma(x + 1.0)).exp(),
+            DistributionKind::Exponential => parameters[0] * (-parameters[0] * x).exp(),
+            DistributionKind::Uniform => 1.0 / (max - min),
+            DistributionKind::Beta => {
+                let (alpha, beta) = (parameters[0], parameters[1]);
+
+                match x {
+                    0.0 if alpha != 1.0 => if alpha < 1.0 { f64::INFINITY } else { 0.0 },
+                    1.0 if beta != 1.0 => if beta < 1.0 { f64::INFINITY } else { 0.0 },
+                    _ => ((alpha - 1.0) * x.ln() + (beta - 1.0) * (1.0 - x).ln() - ln_beta(alpha, beta)).exp(),
+                }
+            },
+            DistributionKind::Gamma => gamma_pdf(x, parameters[0], parameters[1]),
+        }
+    }
+
+    /// Probability of a value at most `x`.
+    pub fn cdf(&self, x: f64) -> f64 {
+        let (min, max) = self.get_support();
+        let parameters = &self.parameters;
+
+        if x.is_nan() {
+            return f64::NAN;
+        }
+
+        if x < min {
+            return 0.0;
+        }
+
+        if x >= max {
+            return 1.0;
+        }
+
+        match self.kind {
+            DistributionKind::Normal => normal_cdf((x - parameters[0]) / parameters[1]),
+            DistributionKind::StudentT => student_t_cdf(x, parameters[0]),
+            DistributionKind::ChiSquared => gamma_p(parameters[0] / 2.0, x / 2.0),
+            DistributionKind::F => {
+                let scaled = parameters[0] * x;
+
+                beta_regularized(parameters[0] / 2.0, parameters[1] / 2.0, scaled / (scaled + parameters[1]))
+            },
+            DistributionKind::Binomial => {
+                let k = x.floor();
+
+                beta_regularized(parameters[0] - k, k + 1.0, 1.0 - parameters[1])
+            },
+            DistributionKind::Poisson => gamma_q(x.floor() + 1.0, parameters[0]),
+            DistributionKind::Exponential => 1.0 - (-parameters[0] * x).exp(),
+            DistributionKind::Uniform => (x - min) / (max - min),
+            DistributionKind::Beta => beta_regularized(parameters[0], parameters[1], x),
+            DistributionKind::Gamma => gamma_p(parameters[0], x / parameters[1]),
+        }
+    }
+
+    /// Inverse of the cdf, the smallest value whose cdf is at least `probability`.\
+    /// `NaN` for probabilities outside `[0, 1]`.
+    pub fn quantile(&self, probability: f64) -> f64 {
+        let (min, max) = self.get_support();
+
+        if !(0.0..=1.0).contains(&probability) {
+            return f64::NAN;
+        }
+
+        if probability == 0.0 {
+            return min;
+        }
+
+        if probability == 1.0 {
+            return max;
+        }
+
+        match self.kind {
+            DistributionKind::Exponential => -(-probability).ln_1p() / self.parameters[0],
+            DistributionKind::Uniform => min + probability * (max - min),
+            _ => {
+                // Bracket the quantile, then bisect the cdf
+                let mut lower = if min.is_finite() { min } else { -1.0 };
+                let mut upper = if max.is_finite() { max } else { 1.0 };
+
+                while self.cdf(lower) >= probability && !min.is_finite() {
+                    lower *= 2.0;
+                }
+
+                while self.cdf(upper) < probability {
+                    upper = upper.max(1.0) * 2.0;
+                }
+
+                match self.kind.is_discrete() {
+                    true => {
+                        let (mut lower, mut upper) = (lower.floor() - 1.0, upper.ceil());
+
+                        // Allow for rounding in the cdf, so probabilities read from the cdf map back to their value
+                        let probability = probability * (1.0 - 1e-12);
+
+                        while upper - lower > 1.0 {
+                            let middle = ((lower + upper) / 2.0).floor();
+
+                            match self.cdf(middle) >= probability {
+                                true => upper = middle,
+                                false => lower = middle,
+                            }
+                        }
+
+                        upper
+                    },
+                    false => {
+                        for _ in 0..200 {
+                            let middle = (lower + upper) / 2.0;
+
+                            if middle == lower || middle == upper {
+                                break;
+                            }
+
+                            match self.cdf(middle) >= probability {
+                                true => upper = middle,
+                                false => lower = middle,
+                            }
+                        }
+
+                        (lower + upper) / 2.0
+                    },
+                }
+            },
+        }
+    }
+
+    /// Draws a value from the distribution.
+    pub fn sample(&self, random: &mut Random) -> f64 {
+        let parameters = &self.parameters;
+
+        match self.kind {
+            DistributionKind::Normal => parameters[0] + parameters[1] * sample_normal(random),
+            DistributionKind::StudentT => sample_normal(random) / (sample_gamma(random, parameters[0] / 2.0) * 2.0 / parameters[0]).sqrt(),
+            DistributionKind::ChiSquared => 2.0 * sample_gamma(random, parameters[0] / 2.0),
+            DistributionKind::F => {
+                let numerator = 2.0 * sample_gamma(random, parameters[0] / 2.0) / parameters[0];
+
+                numerator / (2.0 * sample_gamma(random, parameters[1] / 2.0) / parameters[1])
+            },
+            DistributionKind::Exponential => -random.next_open_f64().ln() / parameters[0],
+            DistributionKind::Uniform => parameters[0] + random.next_f64() * (parameters[1] - parameters[0]),
+            DistributionKind::Beta => {
+                let alpha = sample_gamma(random, parameters[0]);
+
+                alpha / (alpha + sample_gamma(random, parameters[1]))
+            },
+            DistributionKind::Gamma => parameters[1] * sample_gamma(random, parameters[0]),
+            // Inversion keeps discrete samples exact at any parameter size
+            DistributionKind::Binomial | DistributionKind::Poisson => self.quantile(random.next_open_f64()),
+        }
+    }
+}
+
+fn gamma_pdf(x: f64, shape: f64, scale: f64) -> f64 {
+    if x == 0.0 {
+        return match shape {
+            1.0 => 1.0 / scale,
+            shape if shape < 1.0 => f64::INFINITY,
+            _ => 0.0,
+        };
+    }
+
+    ((shape - 1.0) * x.ln() - x / scale - ln_gamma(shape) - shape * scale.ln()).exp()
+}
+
+/// Standard normal draw with the Box–Muller transform.
+fn sample_normal(random: &mut Random) -> f64 {
+    let radius = (-2.0 * random.next_open_f64().ln()).sqrt();
+
+    radius * (2.0 * std::f64::consts::PI * random.next_f64()).cos()
+}
+
+/// Gamma draw with unit scale, using Marsaglia and Tsang's method.
+fn sample_gamma(random: &mut Random, shape: f64) -> f64 {
+    if shape < 1.0 {
+        return sample_gamma(random, shape + 1.0) * random.next_open_f64().powf(1.0 / shape);
+    }
+
+    let d = shape - 1.0 / 3.0;
+    let c = 1.0 / (9.0 * d).sqrt();
+
+    loop {
+        let x = sample_normal(random);
+        let v = (1.0 + c * x).powi(3);
+
+        if v > 0.0 && random.next_open_f64().ln() < 0.5 * x * x + d - d * v + d * v.ln() {
+            return d * v;
+        }
+    }
+}
+
+/// Functions of a distribution that nodes compute.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DistributionFunction {
+    /// Probability density, or mass for discrete distributions.
+    Density,
+    /// Cumulative distribution function.
+    Cumulative,
+    /// Inverse of the cumulative distribution function.
+    Quantile,
+}
+
+fn get_parameter_ports(kind: DistributionKind) -> Vec<Port> {
+    kind.get_parameters().into_iter().map(|(name, value)| match kind.is_discrete() && name == "Trials" {
+        true => Port::new(name, value as i32, ConnectorTypeId::ANY),
+        false => Port::new(name, value as f32, ConnectorTypeId::ANY),
+    }).collect()
+}
+
+fn get_distribution(kind: DistributionKind, parameters: &[ConnectorType]) -> Option<Distribution> {
+    let parameters = parameters.iter().map(get_number).collect::<Option<Vec<f64>>>()?;
+
+    Distribution::new(kind, &parameters)
+}
+
+/// Evaluates a `DistributionFunction` at `X`, or at `Probability` for quantiles.\
+/// Inputs can be lists, the function is then applied element-wise.
+/// Invalid parameters give `Null`.
+pub struct DistributionNode {
+    kind: DistributionKind,
+    function: DistributionFunction,
+}
+
+impl DistributionNode {
+    pub fn new(kind: DistributionKind, function: DistributionFunction) -> DistributionNode {
+        DistributionNode {
+            kind,
+            function,
+        }
+    }
+}
+
+impl Node for DistributionNode {
+    fn get_name(&self) -> String {
+        let function = match self.function {
+            DistributionFunction::Density if self.kind.is_discrete() => "PMF",
+            DistributionFunction::Density => "PDF",
+            DistributionFunction::Cumulative => "CDF",
+            DistributionFunction::Quantile => "Quantile",
+        };
+
+        format!("{} {}", self.kind.get_name(), function)
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        let mut inputs = get_parameter_ports(self.kind);
+
+        inputs.push(match self.function {
+            DistributionFunction::Quantile => Port::new("Probability", 0.5, ConnectorTypeId::ANY),
+            _ => Port::new("X", 0.0, ConnectorTypeId::ANY),
+        });
+
+        inputs
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Result", Null::new(), ConnectorTypeId::ANY)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let inputs = context.get_inputs();
+
+        vec![broadcast(&inputs, &|operands| {
+            let (x, parameters) = operands.split_last().unwrap();
+
+            let (distribution, x) = match (get_distribution(self.kind, parameters), get_number(x)) {
+                (Some(distribution), Some(x)) => (distribution, x),
+                _ => return ConnectorType::Null(Null::new()),
+            };
+
+            match self.function {
+                DistributionFunction::Density => from_number(Some(distribution.pdf(x))),
+                DistributionFunction::Cumulative => from_number(Some(distribution.cdf(x))),
+                DistributionFunction::Quantile => to_value(self.kind, distribution.quantile(x)),
+            }
+        })]
+    }
+}
+
+/// Values of discrete distributions are integers.
+fn to_value(kind: DistributionKind, value: f64) -> ConnectorType {
+    match kind.is_discrete() && value.abs() <= i32::MAX as f64 {
+        true => ConnectorType::Integer(value as i32),
+        false => from_number(Some(value)),
+    }
+}
+
+/// Upper bound on the number of samples, so a huge count can't exhaust memory.
+pub const MAX_SAMPLES: usize = 1000000;
+
+/// Draws `Count` values from a distribution, using the random stream of the node.\
+/// A count above `MAX_SAMPLES` gives null.
+pub struct SampleNode {
+    kind: DistributionKind,
+}
+
+impl SampleNode {
+    pub fn new(kind: DistributionKind) -> SampleNode {
+        SampleNode {
+            kind,
+        }
+    }
+}
+
+impl Node for SampleNode {
+    fn get_name(&self) -> String {
+        format!("{} Sample", self.kind.get_name())
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        let mut inputs = get_parameter_ports(self.kind);
+
+        inputs.push(Port::new("Count", 1, ConnectorTypeId::INT));
+
+        inputs
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Samples", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let inputs = context.get_inputs();
+        let (count, parameters) = inputs.split_last().unwrap();
+
+        let (distribution, count) = match (get_distribution(self.kind, parameters), get_number(count)) {
+            (Some(distribution), Some(count)) if count <= MAX_SAMPLES as f64 => (distribution, count.max(0.0) as usize),
+            _ => return vec![ConnectorType::Null(Null::new())],
+        };
+
+        let mut random = context.get_random();
+
+        vec![ConnectorType::List((0..count).map(|_| to_value(self.kind, distribution.sample(&mut random))).collect())]
+    }
+}
+
+#[cfg(test)]
+mod distribution_test {
+    use super::*;
+    use crate::nodes::node_manager::Manager;
+
+    fn assert_close(value: f64, expected: f64, tolerance: f64) {
+        assert!((value - expected).abs() < tolerance, "{} should be close to {}", value, expected);
+    }
+
+    fn distribution(kind: DistributionKind, parameters: &[f64]) -> Distribution {
+        Distribution::new(kind, parameters).unwrap()
+    }
+
+    #[test]
+    fn test_validation() {
+        assert_eq!(Distribution::new(DistributionKind::Normal, &[0.0, 0.0]), None);
+        assert_eq!(Distribution::new(DistributionKind::Normal, &[0.0]), None);
+        assert_eq!(Distribution::new(DistributionKind::Binomial, &[2.5, 0.5]), None);
+        assert_eq!(Distribution::new(DistributionKind::Uniform, &[1.0, 1.0]), None);
+        assert_eq!(Distribution::new(DistributionKind::Gamma, &[1.0, f64::NAN]), None);
+        assert!(Distribution::new(DistributionKind::Binomial, &[0.0, 1.0]).is_some());
+    }
+
+    #[test]
+    fn test_continuous() {
+        let normal = distribution(DistributionKind::Normal, &[1.0, 2.0]);
+
+        assert_close(normal.pdf(1.0), 0.199_471_140, 1e-9);
+        assert_close(normal.cdf(1.0 + 2.0 * 1.959_963_985), 0.975, 1e-9);
+        assert_close(normal.quantile(0.975), 1.0 + 2.0 * 1.959_963_985, 1e-8);
+
+        let t = distribution(DistributionKind::StudentT, &[1.0]);
+
+        assert_close(t.pdf(0.0), 1.0 / std::f64::consts::PI, 1e-9);
+        assert_close(t.quantile(0.75), 1.0, 1e-9);
+
+        let chi_squared = distribution(DistributionKind::ChiSquared, &[2.0]);
+
+        assert_close(chi_squared.pdf(2.0), 0.5 * (-1f64).exp(), 1e-9);
+        assert_close(chi_squared.quantile(0.95), 5.991_464_547, 1e-8);
+
+        let f = distribution(DistributionKind::F, &[2.0, 4.0]);
+
+        // F(2, 4) has cdf 1 - (1 + x / 2)^-2
+        assert_close(f.cdf(2.0), 0.75, 1e-9);
+        assert_close(f.pdf(2.0), 0.125, 1e-9);
+        assert_close(f.quantile(0.75), 2.0, 1e-8);
+
+        let exponential = distribution(DistributionKind::Exponential, &[2.0]);
+
+        assert_close(exponential.cdf(1.0), 1.0 - (-2f64).exp(), 1e-12);
+        assert_close(exponential.quantile(0.5), 2f64.ln() / 2.0, 1e-12);
+
+        let uniform = distribution(DistributionKind::Uniform, &[2.0, 4.0]);
+
+        assert_eq!(uniform.pdf(3.0), 0.5);
+        assert_eq!(uniform.pdf(5.0), 0.0);
+        assert_eq!(uniform.quantile(0.25), 2.5);
+
+        let beta = distribution(DistributionKind::Beta, &[2.0, 3.0]);
+
+        assert_close(beta.pdf(0.5), 1.5, 1e-9);
+        assert_close(beta.cdf(0.4), 0.5248, 1e-9);
+        assert_close(beta.quantile(0.5248), 0.4, 1e-8);
+
+        let gamma = distribution(DistributionKind::Gamma, &[2.0, 3.0]);
+
+        assert_close(gamma.pdf(3.0), (-1f64).exp() / 3.0, 1e-9);
+        assert_close(gamma.cdf(3.0), 1.0 - 2.0 * (-1f64).exp(), 1e-9);
+    }
+
+    #[test]
+    fn test_discrete() {
+        let binomial = distribution(DistributionKind::Binomial, &[4.0, 0.5]);
+
+        assert_close(binomial.pdf(2.0), 0.375, 1e-12);
+        assert_eq!(binomial.pdf(2.5), 0.0);
+        assert_close(binomial.cdf(1.0), 0.3125, 1e-12);
+        assert_close(binomial.cdf(1.5), 0.3125, 1e-12);
+        assert_eq!(binomial.quantile(0.3125), 1.0);
+        assert_eq!(binomial.quantile(0.32), 2.0);
+        assert_eq!(binomial.quantile(1.0), 4.0);
+
+        let poisson = distribution(DistributionKind::Poisson, &[3.0]);
+
+        assert_close(poisson.pdf(2.0), 4.5 * (-3f64).exp(), 1e-12);
+        assert_close(poisson.cdf(1.0), 4.0 * (-3f64).exp(), 1e-12);
+        assert_eq!(poisson.quantile(0.5), 3.0);
+        assert_eq!(distribution(DistributionKind::Poisson, &[1000.0]).quantile(0.5), 1000.0);
+    }
+
+    #[test]
+    fn test_sampling() {
+        for kind in DistributionKind::ALL {
+            let parameters: Vec<f64> = kind.get_parameters().iter().map(|(_, value)| value + 2.0).collect();
+            let parameters = match kind {
+                DistributionKind::Binomial => vec![10.0, 0.3],
+                _ => parameters,
+            };
+            let distribution = distribution(kind, &parameters);
+            let mut random = Random::new(1);
+            let samples: Vec<f64> = (0..5000).map(|_| distribution.sample(&mut random)).collect();
+
+            // The empirical median should sit close to the median of the distribution
+            let below = samples.iter().filter(|sample| **sample <= distribution.quantile(0.5)).count() as f64 / samples.len() as f64;
+            let expected = distribution.cdf(distribution.quantile(0.5));
+
+            assert!((below - expected).abs() < 0.03, "{:?} has {} of its samples below the median", kind, below);
+        }
+    }
+
+    #[test]
+    fn test_nodes() {
+        let mut manager = Manager::new();
+        let cdf = manager.add_node(DistributionNode::new(DistributionKind::Normal, DistributionFunction::Cumulative));
+        let quantile = manager.add_node(DistributionNode::new(DistributionKind::Poisson, DistributionFunction::Quantile));
+        let sample = manager.add_node(SampleNode::new(DistributionKind::Binomial));
+
+        manager.set_input_value(cdf, 2, vec![0.0, -100.0]);
+
+        assert_eq!(manager.evaluate(cdf)[0], ConnectorType::new(vec![0.5, 0.0]));
+
+        manager.set_input_value(cdf, 1, -1.0);
+
+        assert_eq!(manager.evaluate(cdf)[0], ConnectorType::new(vec![Null::new(), Null::new()]));
+        assert_eq!(manager.evaluate(quantile)[0], ConnectorType::new(1));
+
+        manager.set_input_value(sample, 0, 10);
+        manager.set_input_value(sample, 2, 5);
+
+        let first = manager.evaluate(sample)[0].clone();
+
+        assert_eq!(first, manager.evaluate(sample)[0]);
+        assert!(matches!(&first, ConnectorType::List(values) if values.len() == 5 && values.iter().all(|value| matches!(value, ConnectorType::Integer(0..=10)))));
+
+        manager.set_seed(1);
+
+        assert_ne!(first, manager.evaluate(sample)[0]);
+
+        manager.set_input_value(sample, 2, 2000000);
+
+        assert_eq!(manager.evaluate(sample)[0], ConnectorType::List(Vec::new()));
+    }
+}
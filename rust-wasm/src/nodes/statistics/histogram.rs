@@ -0,0 +1,276 @@
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::table::{Column, Table};
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+use super::descriptive::{Moments, quantile};
+use super::{NullHandling, get_numbers};
+
+/// Upper bound on the number of bins, so a tiny width can't exhaust memory.
+pub const MAX_BINS: usize = 10000;
+
+/// How the bin edges of a histogram are chosen.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BinStrategy {
+    /// Fixed number of equally wide bins.
+    Count(usize),
+    /// Equally wide bins of a fixed width.
+    Width(f64),
+    /// `log2(n) + 1` bins.
+    Sturges,
+    /// Bin width `3.49 * sd / n^(1/3)`.
+    Scott,
+    /// Bin width `2 * IQR / n^(1/3)`.
+    FreedmanDiaconis,
+    /// Explicit, increasing bin edges.
+    Edges(Vec<f64>),
+}
+
+impl BinStrategy {
+    /// Bin edges for the sorted values, `None` when no bins can be made.
+    pub fn get_edges(&self, sorted: &[f64]) -> Option<Vec<f64>> {
+        if let BinStrategy::Edges(edges) = self {
+            let increasing = edges.windows(2).all(|pair| pair[0] < pair[1]);
+
+            return (edges.len() >= 2 && increasing).then(|| edges.clone());
+        }
+
+        let (mut min, mut max) = (*sorted.first()?, *sorted.last()?);
+
+        if min == max {
+            min -= 0.5;
+            max += 0.5;
+        }
+
+        let count = sorted.len() as f64;
+        let sturges = count.log2().ceil() as usize + 1;
+        let from_width = |width: f64| match width > 0.0 && width.is_finite() {
+            true => ((max - min) / width).ceil() as usize,
+            false => sturges,
+        };
+
+        let bins = match self {
+            BinStrategy::Count(bins) => *bins,
+            BinStrategy::Width(width) => from_width(*width),
+            BinStrategy::Sturges => sturges,
+            BinStrategy::Scott => {
+                let mut moments = Moments::new();
+
+                sorted.iter().for_each(|value| moments.push(*value));
+
+                from_width(3.49 * moments.get_variance(true).unwrap_or(0.0).sqrt() / count.cbrt())
+            },
+            BinStrategy::FreedmanDiaconis => {
+                let range = quantile(sorted, 0.75)? - quantile(sorted, 0.25)?;
+
+                from_width(2.0 * range / count.cbrt())
+            },
+            BinStrategy::Edges(_) => unreachable!(),
+        }.clamp(1, MAX_BINS);
+
+        let width = match self {
+            BinStrategy::Width(width) if *width > 0.0 && bins < MAX_BINS => *width,
+            _ => (max - min) / bins as f64,
+        };
+
+        Some((0..=bins).map(|index| match index == bins {
+            true if !matches!(self, BinStrategy::Width(_)) => max,
+            _ => min + width * index as f64,
+        }).collect())
+    }
+}
+
+/// Counts how many values fall in each bin.\
+/// Bins include their lower edge, the last bin also includes its upper edge.
+/// Values outside the edges are not counted.
+pub fn get_counts(values: &[f64], edges: &[f64]) -> Vec<u64> {
+    let mut counts = vec![0; edges.len().saturating_sub(1)];
+
+    if counts.is_empty() {
+        return counts;
+    }
+
+    let last = edges[edges.len() - 1];
+
+    for value in values {
+        if *value < edges[0] || *value > last {
+            continue;
+        }
+
+        let bin = edges.partition_point(|edge| edge <= value).saturating_sub(1).min(counts.len() - 1);
+
+        counts[bin] += 1;
+    }
+
+    counts
+}
+
+/// Histogram of the values as a table with `Lower`, `Upper` and `Count` columns,
+/// plus a `Density` column when `density` is set.\
+/// Density is the count divided by the number of values and the bin width, so the bars integrate to one.
+pub fn histogram(values: &[f64], strategy: &BinStrategy, density: bool) -> Table {
+    let mut sorted = values.to_vec();
+
+    sorted.sort_by(|lhs, rhs| lhs.total_cmp(rhs));
+
+    let edges = strategy.get_edges(&sorted).unwrap_or_default();
+    let counts = get_counts(&sorted, &edges);
+    let total: u64 = counts.iter().sum();
+    let bins = edges.windows(2);
+
+    let mut columns = vec![
+        Column::new("Lower", ConnectorTypeId::FLOAT, bins.clone().map(|bin| bin[0] as f32).collect()),
+        Column::new("Upper", ConnectorTypeId::FLOAT, bins.clone().map(|bin| bin[1] as f32).collect()),
+        Column::new("Count", ConnectorTypeId::INT, counts.iter().map(|count| *count as i32).collect()),
+    ];
+
+    if density {
+        let densities = bins.zip(&counts).map(|(bin, count)| match total {
+            0 => 0.0,
+            _ => (*count as f64 / (total as f64 * (bin[1] - bin[0]))) as f32,
+        }).collect();
+
+        columns.push(Column::new("Density", ConnectorTypeId::FLOAT, densities));
+    }
+
+    Table::from_columns(columns).unwrap()
+}
+
+/// Bins `Values` into a histogram.\
+/// Outputs the histogram table, the bin edges and the counts.
+pub struct HistogramNode {
+    strategy: BinStrategy,
+    density: bool,
+    null_handling: NullHandling,
+}
+
+impl HistogramNode {
+    pub fn new(strategy: BinStrategy, density: bool, null_handling: NullHandling) -> HistogramNode {
+        HistogramNode {
+            strategy,
+            density,
+            null_handling,
+        }
+    }
+}
+
+impl Node for HistogramNode {
+    fn get_name(&self) -> String {
+        "Histogram".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![Port::new("Values", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST)]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Histogram", Table::new(), ConnectorTypeId::TABLE),
+            Port::new("Edges", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+            Port::new("Counts", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+        ]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let values = match get_numbers(&context.get_input(0), self.null_handling) {
+            Some(values) => values,
+            None => return vec![ConnectorType::Null(Null::new()); 3],
+        };
+
+        let table = histogram(&values, &self.strategy, self.density);
+        let counts = table.get_column("Count").unwrap().get_values().to_vec();
+        let mut edges = table.get_column("Lower").unwrap().get_values().to_vec();
+
+        edges.extend(table.get_column("Upper").unwrap().get_values().last().cloned());
+
+        vec![ConnectorType::Table(table), ConnectorType::List(edges), ConnectorType::List(counts)]
+    }
+}
+
+#[cfg(test)]
+mod histogram_test {
+    use super::*;
+    use crate::nodes::node_manager::Manager;
+
+    fn get_counts_of(table: &Table) -> Vec<ConnectorType> {
+        table.get_column("Count").unwrap().get_values().to_vec()
+    }
+
+    #[test]
+    fn test_bin_count() {
+        let values = [1.0, 2.0, 2.0, 3.0, 4.0, 5.0];
+        let table = histogram(&values, &BinStrategy::Count(4), false);
+
+        assert_eq!(table.get_column_names(), vec!["Lower", "Upper", "Count"]);
+        assert_eq!(table.get_column("Lower").unwrap().get_values(), &[ConnectorType::new(1.0), ConnectorType::new(2.0), ConnectorType::new(3.0), ConnectorType::new(4.0)]);
+        assert_eq!(get_counts_of(&table), vec![ConnectorType::new(1), ConnectorType::new(2), ConnectorType::new(1), ConnectorType::new(2)]);
+    }
+
+    #[test]
+    fn test_bin_width() {
+        let values = [0.0, 0.5, 1.0, 2.5];
+        let table = histogram(&values, &BinStrategy::Width(1.0), false);
+
+        assert_eq!(table.get_row_count(), 3);
+        assert_eq!(table.get_column("Upper").unwrap().get_values().last().unwrap(), &ConnectorType::new(3.0));
+        assert_eq!(get_counts_of(&table), vec![ConnectorType::new(2), ConnectorType::new(1), ConnectorType::new(1)]);
+    }
+
+    #[test]
+    fn test_automatic_rules() {
+        let values: Vec<f64> = (0..100).map(|value| value as f64).collect();
+
+        assert_eq!(BinStrategy::Sturges.get_edges(&values).unwrap().len(), 9);
+        assert_eq!(BinStrategy::Scott.get_edges(&values).unwrap().len(), 6);
+        assert_eq!(BinStrategy::FreedmanDiaconis.get_edges(&values).unwrap().len(), 6);
+
+        for strategy in [BinStrategy::Sturges, BinStrategy::Scott, BinStrategy::FreedmanDiaconis] {
+            let table = histogram(&values, &strategy, false);
+            let total: i32 = get_counts_of(&table).iter().map(|count| match count { ConnectorType::Integer(count) => *count, _ => 0 }).sum();
+
+            assert_eq!(total, 100, "{:?} should count every value", strategy);
+        }
+    }
+
+    #[test]
+    fn test_custom_edges() {
+        let values = [-1.0, 0.0, 1.0, 5.0, 10.0, 11.0];
+        let table = histogram(&values, &BinStrategy::Edges(vec![0.0, 1.0, 10.0]), false);
+
+        assert_eq!(get_counts_of(&table), vec![ConnectorType::new(1), ConnectorType::new(3)]);
+        assert_eq!(BinStrategy::Edges(vec![1.0, 0.0]).get_edges(&values), None);
+    }
+
+    #[test]
+    fn test_density() {
+        let values = [0.0, 0.0, 0.5, 2.0];
+        let table = histogram(&values, &BinStrategy::Count(2), true);
+        let densities = table.get_column("Density").unwrap().get_values();
+
+        assert_eq!(densities, &[ConnectorType::new(0.75), ConnectorType::new(0.25)]);
+    }
+
+    #[test]
+    fn test_degenerate() {
+        let table = histogram(&[3.0, 3.0], &BinStrategy::Count(1), false);
+
+        assert_eq!(table.get_column("Lower").unwrap().get_values(), &[ConnectorType::new(2.5)]);
+        assert_eq!(get_counts_of(&table), vec![ConnectorType::new(2)]);
+        assert_eq!(histogram(&[], &BinStrategy::Sturges, true).get_row_count(), 0);
+        assert_eq!(BinStrategy::Width(1e-9).get_edges(&[0.0, 1.0]).unwrap().len(), MAX_BINS + 1);
+    }
+
+    #[test]
+    fn test_node() {
+        let mut manager = Manager::new();
+        let node = manager.add_node(HistogramNode::new(BinStrategy::Count(2), false, NullHandling::Skip));
+
+        manager.set_input_value(node, 0, vec![ConnectorType::new(1), ConnectorType::Null(Null::new()), ConnectorType::new(3)]);
+
+        let outputs = manager.evaluate(node);
+
+        assert_eq!(outputs[1], ConnectorType::new(vec![1.0, 2.0, 3.0]));
+        assert_eq!(outputs[2], ConnectorType::new(vec![1, 1]));
+        assert!(matches!(&outputs[0], ConnectorType::Table(table) if table.get_row_count() == 2));
+    }
+}
@@ -0,0 +1,510 @@
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::record::Record;
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+use super::correlation::{get_pairs, is_numeric, ranks};
+use super::descriptive::Moments;
+use super::special::{chi_squared_survival, f_survival, kolmogorov_survival, normal_p_value, student_t_p_value};
+use super::{NullHandling, from_number, get_number, get_numbers};
+
+/// Outcome of a hypothesis test, all p-values are two-sided.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TestResult {
+    statistic: f64,
+    /// Empty for tests without degrees of freedom, two values for F tests.
+    degrees_of_freedom: Vec<f64>,
+    p_value: f64,
+}
+
+impl TestResult {
+    pub fn new(statistic: f64, degrees_of_freedom: Vec<f64>, p_value: f64) -> TestResult {
+        TestResult {
+            statistic,
+            degrees_of_freedom,
+            p_value,
+        }
+    }
+
+    /// Record with the `Statistic`, `Degrees of Freedom` and `P-Value` fields.\
+    /// Degrees of freedom are `Null` when the test has none and a list when it has several.
+    pub fn to_record(&self) -> Record {
+        let mut record = Record::new();
+        let degrees_of_freedom = match self.degrees_of_freedom.as_slice() {
+            [] => ConnectorType::Null(Null::new()),
+            [value] => from_number(Some(*value)),
+            values => ConnectorType::List(values.iter().map(|value| from_number(Some(*value))).collect()),
+        };
+
+        record.set_field("Statistic", from_number(Some(self.statistic)));
+        record.set_field("Degrees of Freedom", degrees_of_freedom);
+        record.set_field("P-Value", from_number(Some(self.p_value)));
+
+        record
+    }
+}
+
+fn get_moments(values: &[f64]) -> Moments {
+    let mut moments = Moments::new();
+
+    values.iter().for_each(|value| moments.push(*value));
+
+    moments
+}
+
+/// Sample size, mean and sample variance, `None` with fewer than two values.
+fn describe(values: &[f64]) -> Option<(f64, f64, f64)> {
+    let moments = get_moments(values);
+
+    Some((moments.get_count() as f64, moments.get_mean()?, moments.get_variance(true)?))
+}
+
+fn t_result(difference: f64, standard_error: f64, degrees_of_freedom: f64) -> Option<TestResult> {
+    if standard_error <= 0.0 || degrees_of_freedom <= 0.0 {
+        return None;
+    }
+
+    let t = difference / standard_error;
+
+    Some(TestResult::new(t, vec![degrees_of_freedom], student_t_p_value(t, degrees_of_freedom)))
+}
+
+/// One sample t-test of the mean against `mean`.
+pub fn t_test(values: &[f64], mean: f64) -> Option<TestResult> {
+    let (count, sample_mean, variance) = describe(values)?;
+
+    t_result(sample_mean - mean, (variance / count).sqrt(), count - 1.0)
+}
+
+/// Student's two sample t-test, assuming both samples have the same variance.
+pub fn student_t_test(a: &[f64], b: &[f64]) -> Option<TestResult> {
+    let (a_count, a_mean, a_variance) = describe(a)?;
+    let (b_count, b_mean, b_variance) = describe(b)?;
+    let degrees_of_freedom = a_count + b_count - 2.0;
+    let pooled = ((a_count - 1.0) * a_variance + (b_count - 1.0) * b_variance) / degrees_of_freedom;
+
+    t_result(a_mean - b_mean, (pooled * (1.0 / a_count + 1.0 / b_count)).sqrt(), degrees_of_freedom)
+}
+
+/// Welch's two sample t-test, with the Welch–Satterthwaite degrees of freedom.
+pub fn welch_t_test(a: &[f64], b: &[f64]) -> Option<TestResult> {
+    let (a_count, a_mean, a_variance) = describe(a)?;
+    let (b_count, b_mean, b_variance) = describe(b)?;
+    let a_error = a_variance / a_count;
+    let b_error = b_variance / b_count;
+    let degrees_of_freedom = (a_error + b_error).powi(2) / (a_error.powi(2) / (a_count - 1.0) + b_error.powi(2) / (b_count - 1.0));
+
+    t_result(a_mean - b_mean, (a_error + b_error).sqrt(), degrees_of_freedom)
+}
+
+/// Paired t-test, a one sample t-test of the differences `a - b` against zero.
+pub fn paired_t_test(a: &[f64], b: &[f64]) -> Option<TestResult> {
+    let differences: Vec<f64> = a.iter().zip(b).map(|(a, b)| a - b).collect();
+
+    t_test(&differences, 0.0)
+}
+
+/// Chi-square goodness of fit of observed counts.\
+/// Expected frequencies are scaled to the observed total, no expected frequencies means a uniform distribution.
+pub fn chi_squared_goodness_of_fit(observed: &[f64], expected: &[f64]) -> Option<TestResult> {
+    let total: f64 = observed.iter().sum();
+    let expected = match expected.is_empty() {
+        true => vec![1.0; observed.len()],
+        false => expected.to_vec(),
+    };
+    let expected_total: f64 = expected.iter().sum();
+
+    if observed.len() < 2 || observed.len() != expected.len() || expected.iter().any(|value| *value <= 0.0) {
+        return None;
+    }
+
+    let statistic = observed.iter().zip(&expected).map(|(observed, expected)| {
+        let expected = expected * total / expected_total;
+
+        (observed - expected).powi(2) / expected
+    }).sum();
+    let degrees_of_freedom = (observed.len() - 1) as f64;
+
+    Some(TestResult::new(statistic, vec![degrees_of_freedom], chi_squared_survival(statistic, degrees_of_freedom)))
+}
+
+/// Chi-square test of independence of the rows and columns of a contingency table of counts.
+pub fn chi_squared_independence(rows: &[Vec<f64>]) -> Option<TestResult> {
+    let columns = rows.first()?.len();
+
+    if rows.len() < 2 || columns < 2 || rows.iter().any(|row| row.len() != columns) {
+        return None;
+    }
+
+    let row_totals: Vec<f64> = rows.iter().map(|row| row.iter().sum()).collect();
+    let column_totals: Vec<f64> = (0..columns).map(|column| rows.iter().map(|row| row[column]).sum()).collect();
+    let total: f64 = row_totals.iter().sum();
+
+    if row_totals.iter().chain(&column_totals).any(|total| *total <= 0.0) {
+        return None;
+    }
+
+    let mut statistic = 0.0;
+
+    for (row, row_total) in rows.iter().zip(&row_totals) {
+        for (observed, column_total) in row.iter().zip(&column_totals) {
+            let expected = row_total * column_total / total;
+
+            statistic += (observed - expected).powi(2) / expected;
+        }
+    }
+
+    let degrees_of_freedom = ((rows.len() - 1) * (columns - 1)) as f64;
+
+    Some(TestResult::new(statistic, vec![degrees_of_freedom], chi_squared_survival(statistic, degrees_of_freedom)))
+}
+
+/// Mann–Whitney U test, the statistic is the U of the first sample.\
+/// The p-value uses the normal approximation with tie and continuity corrections.
+pub fn mann_whitney_u(a: &[f64], b: &[f64]) -> Option<TestResult> {
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+
+    let combined: Vec<f64> = a.iter().chain(b).copied().collect();
+    let ranks = ranks(&combined);
+    let (a_count, b_count, count) = (a.len() as f64, b.len() as f64, combined.len() as f64);
+    let u = ranks[..a.len()].iter().sum::<f64>() - a_count * (a_count + 1.0) / 2.0;
+
+    let mut sorted = combined.clone();
+    let mut ties = 0.0;
+    let mut start = 0;
+
+    sorted.sort_by(|lhs, rhs| lhs.total_cmp(rhs));
+
+    while start < sorted.len() {
+        let size = sorted[start..].iter().take_while(|value| **value == sorted[start]).count().max(1);
+
+        ties += (size.pow(3) - size) as f64;
+        start += size;
+    }
+
+    let variance = a_count * b_count / 12.0 * ((count + 1.0) - ties / (count * (count - 1.0)));
+
+    if variance <= 0.0 {
+        return None;
+    }
+
+    let z = ((u - a_count * b_count / 2.0).abs() - 0.5).max(0.0) / variance.sqrt();
+
+    Some(TestResult::new(u, Vec::new(), normal_p_value(z)))
+}
+
+/// Two sample Kolmogorov–Smirnov test, the statistic is the largest distance between the empirical distributions.\
+/// The p-value uses the asymptotic Kolmogorov distribution with Stephens' small sample correction.
+pub fn kolmogorov_smirnov(a: &[f64], b: &[f64]) -> Option<TestResult> {
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+
+    let mut a = a.to_vec();
+    let mut b = b.to_vec();
+
+    a.sort_by(|lhs, rhs| lhs.total_cmp(rhs));
+    b.sort_by(|lhs, rhs| lhs.total_cmp(rhs));
+
+    let (mut a_index, mut b_index, mut distance) = (0, 0, 0.0f64);
+
+    while a_index < a.len() && b_index < b.len() {
+        let value = a[a_index].min(b[b_index]);
+
+        a_index += a[a_index..].iter().take_while(|other| **other == value).count();
+        b_index += b[b_index..].iter().take_while(|other| **other == value).count();
+        distance = distance.max((a_index as f64 / a.len() as f64 - b_index as f64 / b.len() as f64).abs());
+    }
+
+    let size = (a.len() as f64 * b.len() as f64 / (a.len() + b.len()) as f64).sqrt();
+
+    Some(TestResult::new(distance, Vec::new(), kolmogorov_survival((size + 0.12 + 0.11 / size) * distance)))
+}
+
+/// One-way analysis of variance of the means of several groups.\
+/// Has the between and within groups degrees of freedom.
+pub fn anova(groups: &[Vec<f64>]) -> Option<TestResult> {
+    let groups: Vec<&Vec<f64>> = groups.iter().filter(|group| !group.is_empty()).collect();
+    let count: usize = groups.iter().map(|group| group.len()).sum();
+
+    if groups.len() < 2 || count <= groups.len() {
+        return None;
+    }
+
+    let mean = groups.iter().flat_map(|group| group.iter()).sum::<f64>() / count as f64;
+    let mut between = 0.0;
+    let mut within = 0.0;
+
+    for group in &groups {
+        let moments = get_moments(group);
+        let group_mean = moments.get_mean()?;
+
+        between += group.len() as f64 * (group_mean - mean).powi(2);
+        within += moments.get_variance(false)? * group.len() as f64;
+    }
+
+    let between_freedom = (groups.len() - 1) as f64;
+    let within_freedom = (count - groups.len()) as f64;
+
+    if within <= 0.0 {
+        return None;
+    }
+
+    let statistic = (between / between_freedom) / (within / within_freedom);
+
+    Some(TestResult::new(statistic, vec![between_freedom, within_freedom], f_survival(statistic, between_freedom, within_freedom)))
+}
+
+/// Reads several samples, from the numeric columns of a table or the elements of a list of lists.
+fn get_groups(value: &ConnectorType) -> Vec<Vec<f64>> {
+    let skip = |values: &ConnectorType| get_numbers(values, NullHandling::Skip).unwrap_or_default();
+
+    match value {
+        ConnectorType::Table(table) => table.get_columns().iter()
+            .filter(|column| is_numeric(column))
+            .map(|column| skip(&ConnectorType::List(column.get_values().to_vec())))
+            .collect(),
+        ConnectorType::List(values) => values.iter().map(skip).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Statistical tests available as nodes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HypothesisTest {
+    /// Mean of `Values` against `Mean`.
+    OneSampleT,
+    /// Means of `A` and `B`, assuming equal variances.
+    StudentT,
+    /// Means of `A` and `B`, without assuming equal variances.
+    WelchT,
+    /// Mean difference of the pairs of `A` and `B`.
+    PairedT,
+    /// `Observed` counts against `Expected` frequencies.
+    ChiSquaredGoodnessOfFit,
+    /// Independence of the rows and columns of the `Counts` contingency table.
+    ChiSquaredIndependence,
+    /// Distributions of `A` and `B`, from their ranks.
+    MannWhitneyU,
+    /// Distributions of `A` and `B`, from their empirical distribution functions.
+    KolmogorovSmirnov,
+    /// Means of the `Groups`.
+    Anova,
+}
+
+impl HypothesisTest {
+    pub fn get_name(&self) -> String {
+        match self {
+            HypothesisTest::OneSampleT => "One Sample T-Test",
+            HypothesisTest::StudentT => "Student's T-Test",
+            HypothesisTest::WelchT => "Welch's T-Test",
+            HypothesisTest::PairedT => "Paired T-Test",
+            HypothesisTest::ChiSquaredGoodnessOfFit => "Chi-Squared Goodness of Fit",
+            HypothesisTest::ChiSquaredIndependence => "Chi-Squared Independence",
+            HypothesisTest::MannWhitneyU => "Mann-Whitney U",
+            HypothesisTest::KolmogorovSmirnov => "Kolmogorov-Smirnov",
+            HypothesisTest::Anova => "ANOVA",
+        }.to_string()
+    }
+
+    pub fn get_inputs(&self) -> Vec<Port> {
+        let list = |name: &str| Port::new(name, Vec::<ConnectorType>::new(), ConnectorTypeId::LIST);
+
+        match self {
+            HypothesisTest::OneSampleT => vec![list("Values"), Port::new("Mean", 0.0, ConnectorTypeId::FLOAT)],
+            HypothesisTest::ChiSquaredGoodnessOfFit => vec![list("Observed"), list("Expected")],
+            HypothesisTest::ChiSquaredIndependence => vec![Port::new("Counts", Vec::<ConnectorType>::new(), ConnectorTypeId::ANY)],
+            HypothesisTest::Anova => vec![Port::new("Groups", Vec::<ConnectorType>::new(), ConnectorTypeId::ANY)],
+            _ => vec![list("A"), list("B")],
+        }
+    }
+
+    /// Runs the test on input values in the order of `get_inputs`, nulls are left out of the samples.
+    pub fn run(&self, inputs: &[ConnectorType]) -> Option<TestResult> {
+        let sample = |index: usize| get_numbers(inputs.get(index)?, NullHandling::Skip);
+
+        match self {
+            HypothesisTest::OneSampleT => t_test(&sample(0)?, get_number(inputs.get(1)?)?),
+            HypothesisTest::StudentT => student_t_test(&sample(0)?, &sample(1)?),
+            HypothesisTest::WelchT => welch_t_test(&sample(0)?, &sample(1)?),
+            HypothesisTest::PairedT => match (inputs.first()?, inputs.get(1)?) {
+                (ConnectorType::List(a), ConnectorType::List(b)) => {
+                    let (a, b) = get_pairs(a, b);
+
+                    paired_t_test(&a, &b)
+                },
+                _ => None,
+            },
+            HypothesisTest::ChiSquaredGoodnessOfFit => chi_squared_goodness_of_fit(&sample(0)?, &sample(1)?),
+            HypothesisTest::ChiSquaredIndependence => match inputs.first()? {
+                ConnectorType::Table(_) => {
+                    let columns = get_groups(inputs.first()?);
+                    let rows = (0..columns.first()?.len()).map(|row| columns.iter().map(|column| column.get(row).copied()).collect()).collect::<Option<Vec<_>>>()?;
+
+                    chi_squared_independence(&rows)
+                },
+                value => chi_squared_independence(&get_groups(value)),
+            },
+            HypothesisTest::MannWhitneyU => mann_whitney_u(&sample(0)?, &sample(1)?),
+            HypothesisTest::KolmogorovSmirnov => kolmogorov_smirnov(&sample(0)?, &sample(1)?),
+            HypothesisTest::Anova => anova(&get_groups(inputs.first()?)),
+        }
+    }
+}
+
+/// Runs a `HypothesisTest`, the `Result` record is empty when the test can't be run on the inputs.
+pub struct HypothesisTestNode {
+    test: HypothesisTest,
+}
+
+impl HypothesisTestNode {
+    pub fn new(test: HypothesisTest) -> HypothesisTestNode {
+        HypothesisTestNode {
+            test,
+        }
+    }
+}
+
+impl Node for HypothesisTestNode {
+    fn get_name(&self) -> String {
+        self.test.get_name()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        self.test.get_inputs()
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Result", Record::new(), ConnectorTypeId::RECORD)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let inputs = context.get_inputs();
+
+        vec![ConnectorType::Record(self.test.run(&inputs).map(|result| result.to_record()).unwrap_or_default())]
+    }
+}
+
+#[cfg(test)]
+mod hypothesis_test {
+    use super::*;
+    use crate::nodes::connector_manager::table::{Column, Table};
+    use crate::nodes::node_manager::Manager;
+
+    fn assert_close(value: f64, expected: f64) {
+        assert!((value - expected).abs() < 1e-6, "{} should be close to {}", value, expected);
+    }
+
+    fn assert_result(result: Option<TestResult>, statistic: f64, degrees_of_freedom: &[f64], p_value: f64) {
+        let result = result.unwrap();
+
+        assert_close(result.statistic, statistic);
+        assert_eq!(result.degrees_of_freedom.len(), degrees_of_freedom.len());
+        result.degrees_of_freedom.iter().zip(degrees_of_freedom).for_each(|(value, expected)| assert_close(*value, *expected));
+        assert_close(result.p_value, p_value);
+    }
+
+    const A: [f64; 5] = [5.1, 4.9, 5.6, 5.8, 6.0];
+    const B: [f64; 5] = [6.2, 6.8, 6.1, 7.0, 6.6];
+
+    #[test]
+    fn test_t_tests() {
+        // mean 5.48, sample variance 0.217
+        let t = 0.48 / (0.217f64 / 5.0).sqrt();
+
+        assert_result(t_test(&A, 5.0), t, &[4.0], student_t_p_value(t, 4.0));
+
+        // Equal sample sizes give the same statistic for Student and Welch
+        let t = (5.48 - 6.54) / ((0.217f64 + 0.148) / 5.0).sqrt();
+        let welch_freedom = 0.073f64.powi(2) / ((0.0434f64.powi(2) + 0.0296f64.powi(2)) / 4.0);
+
+        assert_result(student_t_test(&A, &B), t, &[8.0], student_t_p_value(t, 8.0));
+        assert_result(welch_t_test(&A, &B), t, &[welch_freedom], student_t_p_value(t, welch_freedom));
+        assert_eq!(t_test(&[1.0], 0.0), None);
+        assert_eq!(student_t_test(&[1.0, 1.0], &[1.0, 1.0]), None);
+    }
+
+    #[test]
+    fn test_paired() {
+        let before = [10.0, 12.0, 9.0, 11.0];
+        let after = [12.0, 13.0, 11.0, 13.0];
+        // Differences -2, -1, -2, -2
+        let t = -1.75 / (0.25f64 / 4.0).sqrt();
+
+        assert_result(paired_t_test(&before, &after), t, &[3.0], student_t_p_value(t, 3.0));
+    }
+
+    #[test]
+    fn test_chi_squared() {
+        assert_result(chi_squared_goodness_of_fit(&[10.0, 20.0, 30.0], &[]), 10.0, &[2.0], (-5f64).exp());
+        assert_result(chi_squared_goodness_of_fit(&[10.0, 30.0], &[0.5, 1.5]), 0.0, &[1.0], 1.0);
+        assert_eq!(chi_squared_goodness_of_fit(&[10.0, 30.0], &[1.0]), None);
+
+        // Expected counts are all 15
+        let rows = vec![vec![10.0, 20.0], vec![20.0, 10.0]];
+
+        assert_result(chi_squared_independence(&rows), 20.0 / 3.0, &[1.0], chi_squared_survival(20.0 / 3.0, 1.0));
+        assert_eq!(chi_squared_independence(&[vec![1.0, 2.0]]), None);
+    }
+
+    #[test]
+    fn test_mann_whitney() {
+        // Every value of A is below every value of B, U has mean 6 and variance 8
+        let result = mann_whitney_u(&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0, 7.0]).unwrap();
+
+        assert_close(result.statistic, 0.0);
+        assert_close(result.p_value, normal_p_value(5.5 / 8f64.sqrt()));
+        assert!(result.degrees_of_freedom.is_empty());
+        assert_eq!(mann_whitney_u(&[1.0], &[]), None);
+    }
+
+    #[test]
+    fn test_kolmogorov_smirnov() {
+        let result = kolmogorov_smirnov(&[1.0, 2.0, 3.0, 4.0], &[3.0, 4.0, 5.0, 6.0]).unwrap();
+
+        assert_close(result.statistic, 0.5);
+        assert_close(kolmogorov_smirnov(&A, &A).unwrap().statistic, 0.0);
+        assert_close(kolmogorov_smirnov(&A, &A).unwrap().p_value, 1.0);
+    }
+
+    #[test]
+    fn test_anova() {
+        let groups = vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0], vec![7.0, 8.0, 9.0]];
+
+        // Between 54 with 2 degrees of freedom, within 6 with 6
+        assert_result(anova(&groups), 27.0, &[2.0, 6.0], f_survival(27.0, 2.0, 6.0));
+        assert_eq!(anova(&groups[..1]), None);
+    }
+
+    #[test]
+    fn test_node() {
+        let mut manager = Manager::new();
+        let anova = manager.add_node(HypothesisTestNode::new(HypothesisTest::Anova));
+        let independence = manager.add_node(HypothesisTestNode::new(HypothesisTest::ChiSquaredIndependence));
+
+        manager.set_input_value(anova, 0, Table::from_columns(vec![
+            Column::new("A", ConnectorTypeId::INT, vec![1, 2, 3]),
+            Column::new("Label", ConnectorTypeId::STRING, vec!["a", "b", "c"]),
+            Column::new("B", ConnectorTypeId::INT, vec![4, 5, 6]),
+        ]).unwrap());
+
+        let result = match &manager.evaluate(anova)[0] {
+            ConnectorType::Record(record) => record.clone(),
+            output => panic!("{:?} should be a record", output),
+        };
+
+        assert_eq!(result.get_field_names(), vec!["Statistic", "Degrees of Freedom", "P-Value"]);
+        assert_eq!(result.get_field("Statistic"), Some(&ConnectorType::new(13.5)));
+        assert_eq!(result.get_field("Degrees of Freedom"), Some(&ConnectorType::new(vec![1.0, 4.0])));
+
+        manager.set_input_value(independence, 0, vec![vec![10, 20], vec![20, 10]]);
+
+        assert_eq!(manager.evaluate(independence)[0].get_variant(ConnectorTypeId::FLOAT), 20.0 / 3.0);
+
+        manager.set_input_value(independence, 0, vec![1, 2]);
+
+        assert_eq!(manager.evaluate(independence)[0], Record::new());
+    }
+}
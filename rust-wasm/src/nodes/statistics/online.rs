@@ -0,0 +1,830 @@
+use std::cell::RefCell;
+use crate::helper::bytes::{ByteReader, ByteWriter};
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+use super::{from_number, get_number};
+
+/// A statistic that is updated one value at a time.\
+/// Accumulators computed over separate partitions of the data can be merged, and their state can be
+/// saved as bytes so it survives reloading a blueprint.\
+/// Values an accumulator can't use, such as nulls for numeric statistics, are ignored.
+///
+/// # Example
+/// ```
+/// let mut lhs = Moments::new();
+/// let mut rhs = Moments::new();
+///
+/// lhs.update(&ConnectorType::new(1));
+/// rhs.update(&ConnectorType::new(3));
+/// lhs.merge(&rhs);
+///
+/// let restored = Moments::from_bytes(&lhs.to_bytes()).unwrap();
+/// ```
+pub trait Accumulator: Clone {
+    fn get_name(&self) -> String;
+
+    fn update(&mut self, value: &ConnectorType);
+
+    /// Adds the values accumulated by `other`.
+    fn merge(&mut self, other: &Self);
+
+    fn get_result_names(&self) -> Vec<String>;
+
+    /// One value per result name.
+    fn get_results(&self) -> Vec<ConnectorType>;
+
+    fn to_bytes(&self) -> Vec<u8>;
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self>;
+}
+
+/// Smallest and largest value seen.\
+/// Stays an integer statistic while only integers were seen.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Extrema {
+    min: f64,
+    max: f64,
+    count: u64,
+    is_integer: bool,
+}
+
+impl Default for Extrema {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Extrema {
+    pub fn new() -> Extrema {
+        Extrema {
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+            count: 0,
+            is_integer: true,
+        }
+    }
+
+    pub fn get_min(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.min)
+    }
+
+    pub fn get_max(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.max)
+    }
+
+    fn get_value(&self, value: Option<f64>) -> ConnectorType {
+        match value {
+            Some(value) if self.is_integer => ConnectorType::Integer(value as i32),
+            value => from_number(value),
+        }
+    }
+}
+
+impl Accumulator for Extrema {
+    fn get_name(&self) -> String {
+        "Running Extrema".to_string()
+    }
+
+    fn update(&mut self, value: &ConnectorType) {
+        if let Some(number) = get_number(value) {
+            self.min = self.min.min(number);
+            self.max = self.max.max(number);
+            self.count += 1;
+            self.is_integer &= !matches!(value, ConnectorType::Float(_));
+        }
+    }
+
+    fn merge(&mut self, other: &Self) {
+        if other.count > 0 {
+            self.min = self.min.min(other.min);
+            self.max = self.max.max(other.max);
+            self.count += other.count;
+            self.is_integer &= other.is_integer;
+        }
+    }
+
+    fn get_result_names(&self) -> Vec<String> {
+        vec!["Min".to_string(), "Max".to_string()]
+    }
+
+    fn get_results(&self) -> Vec<ConnectorType> {
+        vec![self.get_value(self.get_min()), self.get_value(self.get_max())]
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut writer = ByteWriter::new();
+
+        writer.write_f64(self.min);
+        writer.write_f64(self.max);
+        writer.write_u64(self.count);
+        writer.write_u8(self.is_integer as u8);
+        writer.into_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut reader = ByteReader::new(bytes);
+
+        Some(Extrema {
+            min: reader.read_f64()?,
+            max: reader.read_f64()?,
+            count: reader.read_u64()?,
+            is_integer: reader.read_u8()? != 0,
+        })
+    }
+}
+
+/// Approximate quantiles with a merging t-digest (Dunning, 2019).\
+/// Values are clustered into weighted centroids that are kept small near the tails,
+/// so extreme quantiles stay accurate while memory is bounded by the compression.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TDigest {
+    compression: f64,
+    quantiles: Vec<f64>,
+    centroids: Vec<(f64, f64)>,
+    buffer: Vec<f64>,
+    min: f64,
+    max: f64,
+}
+
+impl Default for TDigest {
+    fn default() -> Self {
+        Self::new(100.0, vec![0.25, 0.5, 0.75])
+    }
+}
+
+impl TDigest {
+    /// A higher compression keeps more centroids and gives more accurate quantiles.\
+    /// `quantiles` are the quantiles reported as results.
+    pub fn new(compression: f64, quantiles: Vec<f64>) -> TDigest {
+        TDigest {
+            compression: compression.max(10.0),
+            quantiles,
+            centroids: Vec::new(),
+            buffer: Vec::new(),
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    pub fn push(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.buffer.push(value);
+
+        if self.buffer.len() as f64 >= self.compression * 5.0 {
+            self.compress();
+        }
+    }
+
+    pub fn get_count(&self) -> f64 {
+        self.centroids.iter().map(|(_, weight)| weight).sum::<f64>() + self.buffer.len() as f64
+    }
+
+    /// Merges buffered values into the centroids.
+    pub fn compress(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+
+        let mut points = std::mem::take(&mut self.centroids);
+
+        points.extend(self.buffer.drain(..).map(|value| (value, 1.0)));
+        self.centroids = self.merge_points(points);
+    }
+
+    fn merge_points(&self, mut points: Vec<(f64, f64)>) -> Vec<(f64, f64)> {
+        points.sort_by(|lhs, rhs| lhs.0.total_cmp(&rhs.0));
+
+        let total: f64 = points.iter().map(|(_, weight)| weight).sum();
+        let mut centroids: Vec<(f64, f64)> = Vec::new();
+        let mut cumulative = 0.0;
+
+        for (mean, weight) in points {
+            if let Some(last) = centroids.last_mut() {
+                let merged = last.1 + weight;
+                let quantile = (cumulative + merged / 2.0) / total;
+                let limit = (4.0 * total * quantile * (1.0 - quantile) / self.compression).max(1.0);
+
+                if merged <= limit {
+                    last.0 += (mean - last.0) * weight / merged;
+                    last.1 = merged;
+                    continue;
+                }
+
+                cumulative += last.1;
+            }
+
+            centroids.push((mean, weight));
+        }
+
+        centroids
+    }
+
+    pub fn get_quantile(&self, quantile: f64) -> Option<f64> {
+        let mut digest = self.clone();
+
+        digest.compress();
+
+        let centroids = &digest.centroids;
+        let total = digest.get_count();
+
+        if centroids.is_empty() || !(0.0..=1.0).contains(&quantile) {
+            return None;
+        }
+
+        if centroids.len() == 1 {
+            return Some(centroids[0].0);
+        }
+
+        let target = quantile * total;
+        let mut cumulative = 0.0;
+        let mut previous = (digest.min, 0.0);
+
+        for (mean, weight) in centroids {
+            let center = cumulative + weight / 2.0;
+
+            if target <= center {
+                let span = center - previous.1;
+                let ratio = if span > 0.0 { (target - previous.1) / span } else { 0.0 };
+
+                return Some(previous.0 + (mean - previous.0) * ratio);
+            }
+
+            previous = (*mean, center);
+            cumulative += weight;
+        }
+
+        let span = total - previous.1;
+        let ratio = if span > 0.0 { (target - previous.1) / span } else { 1.0 };
+
+        Some(previous.0 + (digest.max - previous.0) * ratio)
+    }
+}
+
+impl Accumulator for TDigest {
+    fn get_name(&self) -> String {
+        "Running Quantiles".to_string()
+    }
+
+    fn update(&mut self, value: &ConnectorType) {
+        if let Some(value) = get_number(value) {
+            self.push(value);
+        }
+    }
+
+    fn merge(&mut self, other: &Self) {
+        let mut points = std::mem::take(&mut self.centroids);
+
+        points.extend(self.buffer.drain(..).map(|value| (value, 1.0)));
+        points.extend(other.centroids.iter().copied());
+        points.extend(other.buffer.iter().map(|value| (*value, 1.0)));
+
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.centroids = self.merge_points(points);
+    }
+
+    fn get_result_names(&self) -> Vec<String> {
+        self.quantiles.iter().map(|quantile| format!("P{}", quantile * 100.0)).collect()
+    }
+
+    fn get_results(&self) -> Vec<ConnectorType> {
+        self.quantiles.iter().map(|quantile| from_number(self.get_quantile(*quantile))).collect()
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut digest = self.clone();
+        let mut writer = ByteWriter::new();
+
+        digest.compress();
+        writer.write_f64(digest.compression);
+        writer.write_f64(digest.min);
+        writer.write_f64(digest.max);
+        writer.write_u32(digest.quantiles.len() as u32);
+
+        for quantile in &digest.quantiles {
+            writer.write_f64(*quantile);
+        }
+
+        writer.write_u32(digest.centroids.len() as u32);
+
+        for (mean, weight) in &digest.centroids {
+            writer.write_f64(*mean);
+            writer.write_f64(*weight);
+        }
+
+        writer.into_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut reader = ByteReader::new(bytes);
+        let compression = reader.read_f64()?;
+        let min = reader.read_f64()?;
+        let max = reader.read_f64()?;
+        let quantiles = (0..reader.read_u32()?).map(|_| reader.read_f64()).collect::<Option<Vec<f64>>>()?;
+        let centroids = (0..reader.read_u32()?).map(|_| Some((reader.read_f64()?, reader.read_f64()?))).collect::<Option<Vec<(f64, f64)>>>()?;
+
+        Some(TDigest {
+            compression,
+            quantiles,
+            centroids,
+            buffer: Vec::new(),
+            min,
+            max,
+        })
+    }
+}
+
+/// Hashes a value to 64 bits, the same on every platform so saved sketches stay valid.\
+/// FNV-1a over a tagged encoding of the value, finished with the splitmix64 mixer.
+pub fn hash_value(value: &ConnectorType) -> u64 {
+    fn write(hash: &mut u64, bytes: &[u8]) {
+        for byte in bytes {
+            *hash ^= *byte as u64;
+            *hash = hash.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    fn encode(hash: &mut u64, value: &ConnectorType) {
+        write(hash, &value.get_type().to_le_bytes());
+
+        match value {
+            ConnectorType::Integer(value) => write(hash, &value.to_le_bytes()),
+            ConnectorType::Float(value) => write(hash, &(value + 0.0).to_bits().to_le_bytes()),
+            ConnectorType::String(value) => write(hash, value.as_bytes()),
+            ConnectorType::Boolean(value) => write(hash, &[*value as u8]),
+            ConnectorType::Null(_) => (),
+            ConnectorType::List(values) => {
+                write(hash, &(values.len() as u64).to_le_bytes());
+
+                for value in values {
+                    encode(hash, value);
+                }
+            },
+            ConnectorType::Table(table) => {
+                for column in table.get_columns() {
+                    write(hash, column.get_name().as_bytes());
+                    encode(hash, &ConnectorType::List(column.get_values().to_vec()));
+                }
+            },
+            ConnectorType::Record(record) => {
+                for (name, value) in record.get_fields() {
+                    write(hash, name.as_bytes());
+                    encode(hash, value);
+                }
+            },
+            ConnectorType::DateTime(value) => write(hash, &value.get_milliseconds().to_le_bytes()),
+        }
+    }
+
+    let mut hash = 0xcbf29ce484222325;
+
+    encode(&mut hash, value);
+
+    hash ^= hash >> 30;
+    hash = hash.wrapping_mul(0xbf58476d1ce4e5b9);
+    hash ^= hash >> 27;
+    hash = hash.wrapping_mul(0x94d049bb133111eb);
+    hash ^ (hash >> 31)
+}
+
+/// Approximate count of distinct values with HyperLogLog (Flajolet et al., 2007).\
+/// Uses `2^precision` one byte registers, the standard error is about `1.04 / sqrt(2^precision)`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HyperLogLog {
+    precision: u8,
+    registers: Vec<u8>,
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self::new(12)
+    }
+}
+
+impl HyperLogLog {
+    /// Precision is clamped to `4..=16`.
+    pub fn new(precision: u8) -> HyperLogLog {
+        let precision = precision.clamp(4, 16);
+
+        HyperLogLog {
+            precision,
+            registers: vec![0; 1 << precision],
+        }
+    }
+
+    pub fn insert_hash(&mut self, hash: u64) {
+        let index = (hash >> (64 - self.precision)) as usize;
+        let rest = (hash << self.precision) | (1 << (self.precision - 1));
+        let rank = rest.leading_zeros() as u8 + 1;
+
+        self.registers[index] = self.registers[index].max(rank);
+    }
+
+    pub fn get_estimate(&self) -> f64 {
+        let registers = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / registers),
+        };
+
+        let sum: f64 = self.registers.iter().map(|register| 2f64.powi(-(*register as i32))).sum();
+        let estimate = alpha * registers * registers / sum;
+        let zeros = self.registers.iter().filter(|register| **register == 0).count();
+
+        // Linear counting is more accurate while few registers are set
+        if estimate <= 2.5 * registers && zeros > 0 {
+            registers * (registers / zeros as f64).ln()
+        } else {
+            estimate
+        }
+    }
+}
+
+impl Accumulator for HyperLogLog {
+    fn get_name(&self) -> String {
+        "Running Distinct Count".to_string()
+    }
+
+    fn update(&mut self, value: &ConnectorType) {
+        if !value.is_null() {
+            self.insert_hash(hash_value(value));
+        }
+    }
+
+    /// Sketches of different precision can't be merged, `other` is ignored in that case.
+    fn merge(&mut self, other: &Self) {
+        if self.precision == other.precision {
+            for (register, other) in self.registers.iter_mut().zip(&other.registers) {
+                *register = (*register).max(*other);
+            }
+        }
+    }
+
+    fn get_result_names(&self) -> Vec<String> {
+        vec!["Distinct Count".to_string()]
+    }
+
+    fn get_results(&self) -> Vec<ConnectorType> {
+        vec![ConnectorType::Integer(self.get_estimate().round() as i32)]
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut writer = ByteWriter::new();
+
+        writer.write_u8(self.precision);
+        writer.write_bytes(&self.registers);
+        writer.into_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut reader = ByteReader::new(bytes);
+        let precision = reader.read_u8()?;
+
+        if !(4..=16).contains(&precision) {
+            return None;
+        }
+
+        let registers = reader.read_bytes(1 << precision)?.to_vec();
+
+        Some(HyperLogLog {
+            precision,
+            registers,
+        })
+    }
+}
+
+/// Hashes of the first and last values of a list, `0` for an empty list.
+fn hash_ends(values: &[ConnectorType]) -> (u64, u64) {
+    (values.first().map_or(0, hash_value), values.last().map_or(0, hash_value))
+}
+
+struct AccumulatorState<A> {
+    accumulator: A,
+    consumed: usize,
+    /// Hashes of the first and last consumed values, to tell whether the list only grew.
+    ends: (u64, u64),
+}
+
+/// Keeps an accumulator up to date with the `Values` list.\
+/// When the list only grew since the last evaluation just the appended values are added,
+/// anything else starts over from the whole list. The list is taken to have only grown when it is
+/// at least as long and its first and last consumed values are unchanged, so checking costs the same for any length.\
+/// The state of a node over another partition can be merged in, it counts until the list starts over.
+pub struct AccumulatorNode<A>
+where
+    A: Accumulator
+{
+    initial: A,
+    state: RefCell<AccumulatorState<A>>,
+}
+
+impl<A> AccumulatorNode<A>
+where
+    A: Accumulator
+{
+    pub fn new(accumulator: A) -> AccumulatorNode<A> {
+        AccumulatorNode {
+            initial: accumulator.clone(),
+            state: RefCell::new(AccumulatorState {
+                accumulator,
+                consumed: 0,
+                ends: hash_ends(&[]),
+            }),
+        }
+    }
+
+    fn read_state(state: &[u8]) -> Option<AccumulatorState<A>> {
+        let mut reader = ByteReader::new(state);
+        let consumed = reader.read_u64()? as usize;
+        let ends = (reader.read_u64()?, reader.read_u64()?);
+
+        Some(AccumulatorState {
+            accumulator: A::from_bytes(&state[reader.get_position()..])?,
+            consumed,
+            ends,
+        })
+    }
+}
+
+impl<A> Node for AccumulatorNode<A>
+where
+    A: Accumulator
+{
+    fn get_name(&self) -> String {
+        self.initial.get_name()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![Port::new("Values", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST)]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        self.initial.get_result_names().iter().map(|name| Port::new(name, Null::new(), ConnectorTypeId::ANY)).collect()
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let values = match context.get_input(0) {
+            ConnectorType::List(values) => values,
+            value => vec![value],
+        };
+
+        let mut state = self.state.borrow_mut();
+        let appended = state.consumed <= values.len() && hash_ends(&values[..state.consumed]) == state.ends;
+
+        if !appended {
+            state.accumulator = self.initial.clone();
+            state.consumed = 0;
+        }
+
+        for value in &values[state.consumed..] {
+            state.accumulator.update(value);
+        }
+
+        state.ends = hash_ends(&values);
+        state.consumed = values.len();
+        state.accumulator.get_results()
+    }
+
+    fn get_state(&self) -> Vec<u8> {
+        let state = self.state.borrow();
+        let mut writer = ByteWriter::new();
+
+        writer.write_u64(state.consumed as u64);
+        writer.write_u64(state.ends.0);
+        writer.write_u64(state.ends.1);
+        writer.write_bytes(&state.accumulator.to_bytes());
+        writer.into_bytes()
+    }
+
+    fn set_state(&self, state: &[u8]) -> bool {
+        match Self::read_state(state) {
+            Some(state) => {
+                *self.state.borrow_mut() = state;
+                true
+            },
+            None => false,
+        }
+    }
+
+    fn merge_state(&self, state: &[u8]) -> bool {
+        match Self::read_state(state) {
+            Some(other) => {
+                self.state.borrow_mut().accumulator.merge(&other.accumulator);
+                true
+            },
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod online_test {
+    use super::*;
+    use crate::nodes::node_manager::Manager;
+    use crate::nodes::statistics::descriptive::{KahanSum, Moments};
+
+    fn accumulate<A: Accumulator>(mut accumulator: A, values: impl Iterator<Item = ConnectorType>) -> A {
+        for value in values {
+            accumulator.update(&value);
+        }
+
+        accumulator
+    }
+
+    #[test]
+    fn test_merge_moments() {
+        let values: Vec<ConnectorType> = (1..=100).map(|value| ConnectorType::new(value * value % 37)).collect();
+        let whole = accumulate(Moments::new(), values.iter().cloned());
+        let mut lhs = accumulate(Moments::new(), values[..30].iter().cloned());
+        let rhs = accumulate(Moments::new(), values[30..].iter().cloned());
+
+        lhs.merge(&rhs);
+
+        assert_eq!(lhs.get_count(), whole.get_count());
+
+        let close = |lhs: Option<f64>, rhs: Option<f64>| (lhs.unwrap() - rhs.unwrap()).abs() < 1e-9;
+
+        assert!(close(lhs.get_mean(), whole.get_mean()));
+        assert!(close(lhs.get_variance(true), whole.get_variance(true)));
+        assert!(close(lhs.get_skewness(), whole.get_skewness()));
+        assert!(close(lhs.get_kurtosis(), whole.get_kurtosis()));
+    }
+
+    #[test]
+    fn test_sum_and_extrema() {
+        let mut sum = accumulate(KahanSum::new(), [1, 2, 3].into_iter().map(ConnectorType::new));
+        let mut extrema = accumulate(Extrema::new(), [4, -2, 9].into_iter().map(ConnectorType::new));
+
+        sum.merge(&accumulate(KahanSum::new(), [0.5].into_iter().map(ConnectorType::new)));
+        extrema.update(&ConnectorType::Null(Null::new()));
+
+        assert_eq!(sum.get_results(), vec![ConnectorType::new(6.5)]);
+        assert_eq!(extrema.get_results(), vec![ConnectorType::new(-2), ConnectorType::new(9)]);
+
+        extrema.merge(&accumulate(Extrema::new(), [10.5].into_iter().map(ConnectorType::new)));
+
+        assert_eq!(extrema.get_results(), vec![ConnectorType::new(-2.0), ConnectorType::new(10.5)]);
+        assert_eq!(Extrema::new().get_results(), vec![ConnectorType::Null(Null::new()), ConnectorType::Null(Null::new())]);
+    }
+
+    #[test]
+    fn test_tdigest() {
+        let mut lhs = TDigest::new(100.0, vec![0.01, 0.5, 0.99]);
+        let mut rhs = TDigest::new(100.0, vec![0.01, 0.5, 0.99]);
+
+        for value in 0..10000 {
+            match value % 2 {
+                0 => lhs.push(value as f64),
+                _ => rhs.push(value as f64),
+            }
+        }
+
+        lhs.merge(&rhs);
+
+        assert_eq!(lhs.get_count(), 10000.0);
+        assert!((lhs.get_quantile(0.5).unwrap() - 5000.0).abs() < 50.0);
+        assert!((lhs.get_quantile(0.01).unwrap() - 100.0).abs() < 10.0);
+        assert!((lhs.get_quantile(0.99).unwrap() - 9900.0).abs() < 10.0);
+        assert_eq!(lhs.get_quantile(0.0), Some(0.0));
+        assert_eq!(lhs.get_quantile(1.0), Some(9999.0));
+        assert!(lhs.centroids.len() < 500);
+
+        let restored = TDigest::from_bytes(&lhs.to_bytes()).unwrap();
+
+        assert_eq!(restored.get_results(), lhs.get_results());
+        assert_eq!(restored.get_result_names(), vec!["P1", "P50", "P99"]);
+
+        let mut small = TDigest::default();
+
+        for value in [1.0, 2.0, 3.0, 4.0] {
+            small.push(value);
+        }
+
+        assert_eq!(small.get_quantile(0.5), Some(2.5));
+        assert_eq!(TDigest::default().get_quantile(0.5), None);
+    }
+
+    #[test]
+    fn test_hyperloglog() {
+        let mut lhs = HyperLogLog::default();
+        let mut rhs = HyperLogLog::default();
+
+        for value in 0..20000 {
+            lhs.update(&ConnectorType::new(value % 15000));
+            rhs.update(&ConnectorType::new(format!("key {}", value % 5000)));
+        }
+
+        let estimate = lhs.get_estimate();
+
+        assert!((estimate - 15000.0).abs() < 15000.0 * 0.05, "{} should be close to 15000", estimate);
+
+        lhs.merge(&rhs);
+
+        let estimate = lhs.get_estimate();
+
+        assert!((estimate - 20000.0).abs() < 20000.0 * 0.05, "{} should be close to 20000", estimate);
+
+        let mut small = HyperLogLog::default();
+
+        for value in ["a", "b", "a", "c"] {
+            small.update(&ConnectorType::new(value));
+        }
+
+        assert_eq!(small.get_results(), vec![ConnectorType::new(3)]);
+        assert_eq!(HyperLogLog::from_bytes(&lhs.to_bytes()), Some(lhs));
+        assert_eq!(HyperLogLog::from_bytes(&[12, 0]), None);
+    }
+
+    #[test]
+    fn test_hash_value() {
+        assert_eq!(hash_value(&ConnectorType::new(1)), hash_value(&ConnectorType::new(1)));
+        assert_ne!(hash_value(&ConnectorType::new(1)), hash_value(&ConnectorType::new(1.0)));
+        assert_ne!(hash_value(&ConnectorType::new("1")), hash_value(&ConnectorType::new(1)));
+        assert_eq!(hash_value(&ConnectorType::new(0.0)), hash_value(&ConnectorType::new(-0.0)));
+    }
+
+    #[test]
+    fn test_accumulator_node() {
+        let mut manager = Manager::new();
+        let node = manager.add_node(AccumulatorNode::new(Moments::new()));
+
+        manager.set_input_value(node, 0, vec![1, 2, 3]);
+
+        assert_eq!(manager.evaluate(node)[0], 3);
+
+        manager.set_input_value(node, 0, vec![1, 2, 3, 4]);
+
+        let outputs = manager.evaluate(node);
+
+        assert_eq!(outputs[0], 4, "Only the appended value should be added");
+        assert_eq!(outputs[1], 2.5);
+
+        manager.set_input_value(node, 0, vec![10, 20]);
+
+        assert_eq!(manager.evaluate(node)[1], 15.0, "A replaced list should start over");
+
+        manager.set_input_value(node, 0, vec![50, 20, 30]);
+
+        assert_eq!(manager.evaluate(node)[1], 100.0 / 3.0, "A changed first value should start over");
+
+        manager.set_input_value(node, 0, vec![50, 20, 60, 70]);
+
+        assert_eq!(manager.evaluate(node)[1], 50.0, "A changed last value should start over");
+
+        manager.set_input_value(node, 0, vec![10, 20]);
+        manager.evaluate(node);
+
+        let state = manager.get_node_state(node);
+        let restored = manager.add_node(AccumulatorNode::new(Moments::new()));
+
+        assert!(manager.set_node_state(restored, &state));
+
+        manager.set_input_value(restored, 0, vec![10, 20, 30]);
+
+        assert_eq!(manager.evaluate(restored)[1], 20.0, "Restored state should continue accumulating");
+
+        let other = manager.add_node(AccumulatorNode::new(Moments::new()));
+
+        assert!(manager.set_node_state(other, &state));
+
+        manager.set_input_value(other, 0, vec![1, 2, 3]);
+
+        assert_eq!(manager.evaluate(other)[1], 2.0, "Restored state shouldn't be continued with another list");
+        assert!(!manager.set_node_state(restored, &[1, 2, 3]));
+    }
+
+    #[test]
+    fn test_merge_state() {
+        let mut manager = Manager::new();
+        let node = manager.add_node(AccumulatorNode::new(Moments::new()));
+        let partition = manager.add_node(AccumulatorNode::new(Moments::new()));
+
+        manager.set_input_value(node, 0, vec![1, 2]);
+        manager.set_input_value(partition, 0, vec![3, 4]);
+        manager.evaluate(node);
+        manager.evaluate(partition);
+
+        let state = manager.get_node_state(partition);
+
+        assert!(manager.merge_node_state(node, &state));
+
+        let outputs = manager.evaluate(node);
+
+        assert_eq!(outputs[0], 4);
+        assert_eq!(outputs[1], 2.5);
+        assert!(!manager.merge_node_state(node, &[1, 2, 3]));
+
+        manager.set_input_value(node, 0, vec![5]);
+
+        assert_eq!(manager.evaluate(node)[0], 1, "A replaced list should start over without the merged partition");
+    }
+}
@@ -0,0 +1,422 @@
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::table::Table;
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+use super::{from_number, get_number};
+
+/// Columns whose remaining norm falls below this fraction of their original norm are treated as linearly dependent.
+const RANK_TOLERANCE: f64 = 1e-10;
+
+/// Result of a least squares fit.\
+/// The first coefficient is the intercept, followed by one coefficient per term.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Regression {
+    coefficients: Vec<f64>,
+    standard_errors: Vec<f64>,
+    residuals: Vec<f64>,
+    predictions: Vec<f64>,
+    r_squared: f64,
+    adjusted_r_squared: f64,
+}
+
+impl Regression {
+    /// Fits `y = b0 + b1 * x1 + ... + bp * xp` by (weighted) least squares.\
+    /// `terms` holds one value per term for every observation.
+    /// Returns `None` when there are fewer observations than coefficients or the terms are linearly dependent.
+    ///
+    /// Solved with a Householder QR decomposition of the design matrix, which stays accurate
+    /// when the normal equations would be badly conditioned, e.g. for polynomials.
+    pub fn fit(terms: &[Vec<f64>], y: &[f64], weights: Option<&[f64]>) -> Option<Regression> {
+        let count = y.len();
+        let size = terms.first().map(|row| row.len()).unwrap_or(0) + 1;
+
+        if count < size || terms.len() != count || weights.is_some_and(|weights| weights.len() != count) {
+            return None;
+        }
+
+        let scales: Vec<f64> = (0..count).map(|row| weights.map(|weights| weights[row].sqrt()).unwrap_or(1.0)).collect();
+
+        // Column major design matrix, scaled by the square root of the weights
+        let mut columns: Vec<Vec<f64>> = (0..size).map(|column| (0..count).map(|row| match column {
+            0 => scales[row],
+            column => terms[row][column - 1] * scales[row],
+        }).collect()).collect();
+
+        let mut response: Vec<f64> = y.iter().zip(&scales).map(|(y, scale)| y * scale).collect();
+
+        for column in 0..size {
+            let norm = columns[column].iter().map(|value| value * value).sum::<f64>().sqrt();
+            let remaining = columns[column][column..].iter().map(|value| value * value).sum::<f64>().sqrt();
+
+            if remaining <= RANK_TOLERANCE * norm || remaining == 0.0 {
+                return None;
+            }
+
+            let alpha = -remaining.copysign(columns[column][column]);
+            let mut reflector = columns[column][column..].to_vec();
+
+            reflector[0] -= alpha;
+
+            let length: f64 = reflector.iter().map(|value| value * value).sum();
+
+            for target in columns[column..].iter_mut().chain(std::iter::once(&mut response)) {
+                let scale = 2.0 * reflector.iter().zip(&target[column..]).map(|(v, a)| v * a).sum::<f64>() / length;
+
+                target[column..].iter_mut().zip(&reflector).for_each(|(a, v)| *a -= scale * v);
+            }
+        }
+
+        // Back substitution of R * b = Q'y, and of R * inverse = I for the covariance of the coefficients
+        let upper = |row: usize, column: usize| columns[column][row];
+        let mut coefficients = vec![0.0; size];
+        let mut inverse = vec![vec![0.0; size]; size];
+
+        for row in (0..size).rev() {
+            let sum: f64 = (row + 1..size).map(|column| upper(row, column) * coefficients[column]).sum();
+
+            coefficients[row] = (response[row] - sum) / upper(row, row);
+
+            let values: Vec<f64> = (row..size).map(|column| {
+                let identity = if row == column { 1.0 } else { 0.0 };
+                let sum: f64 = (row + 1..=column).map(|index| upper(row, index) * inverse[index][column]).sum();
+
+                (identity - sum) / upper(row, row)
+            }).collect();
+
+            inverse[row][row..].copy_from_slice(&values);
+        }
+
+        let predictions: Vec<f64> = terms.iter().map(|row| predict(&coefficients, row)).collect();
+        let residuals: Vec<f64> = y.iter().zip(&predictions).map(|(y, prediction)| y - prediction).collect();
+        let weight = |row: usize| scales[row] * scales[row];
+        let total_weight: f64 = (0..count).map(weight).sum();
+        let mean = (0..count).map(|row| weight(row) * y[row]).sum::<f64>() / total_weight;
+        let residual_squares: f64 = (0..count).map(|row| weight(row) * residuals[row] * residuals[row]).sum();
+        let total_squares: f64 = (0..count).map(|row| weight(row) * (y[row] - mean) * (y[row] - mean)).sum();
+
+        let degrees_of_freedom = (count - size) as f64;
+        let variance = residual_squares / degrees_of_freedom;
+        let standard_errors = inverse.iter().map(|row| (variance * row.iter().map(|value| value * value).sum::<f64>()).sqrt()).collect();
+        let r_squared = match total_squares > 0.0 {
+            true => 1.0 - residual_squares / total_squares,
+            false => f64::NAN,
+        };
+
+        Some(Regression {
+            coefficients,
+            standard_errors,
+            residuals,
+            predictions,
+            r_squared,
+            adjusted_r_squared: 1.0 - (1.0 - r_squared) * (count - 1) as f64 / degrees_of_freedom,
+        })
+    }
+
+    /// Intercept followed by the coefficient of every term.
+    pub fn get_coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+
+    pub fn get_intercept(&self) -> f64 {
+        self.coefficients[0]
+    }
+
+    /// Standard errors of the coefficients, in the same order. `NaN` for an exact fit without residual degrees of freedom.
+    pub fn get_standard_errors(&self) -> &[f64] {
+        &self.standard_errors
+    }
+
+    pub fn get_residuals(&self) -> &[f64] {
+        &self.residuals
+    }
+
+    pub fn get_predictions(&self) -> &[f64] {
+        &self.predictions
+    }
+
+    pub fn get_r_squared(&self) -> f64 {
+        self.r_squared
+    }
+
+    pub fn get_adjusted_r_squared(&self) -> f64 {
+        self.adjusted_r_squared
+    }
+}
+
+fn predict(coefficients: &[f64], terms: &[f64]) -> f64 {
+    coefficients[0] + coefficients[1..].iter().zip(terms).map(|(coefficient, term)| coefficient * term).sum::<f64>()
+}
+
+/// Terms, response and weight of an observation.
+type Observation = (Vec<f64>, f64, f64);
+
+/// Terms of a regression model, computed from the predictor columns.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RegressionModel {
+    /// One term per predictor.
+    Linear,
+    /// Powers `x, x², ..., xⁿ` of the first predictor.
+    Polynomial(usize),
+}
+
+impl RegressionModel {
+    pub fn get_terms(&self, predictors: &[f64]) -> Vec<f64> {
+        match self {
+            RegressionModel::Linear => predictors.to_vec(),
+            RegressionModel::Polynomial(degree) => match predictors.first() {
+                Some(x) => (1..=*degree as i32).map(|power| x.powi(power)).collect(),
+                None => Vec::new(),
+            },
+        }
+    }
+}
+
+/// Fits a regression of the `Response` column on the `Predictors` columns of a table.\
+/// Weighted nodes read the weight of every row from the `Weights` column.
+/// Rows with a missing or non-numeric value, or a weight that isn't positive, are left out of the fit,
+/// their residual and predicted value are `Null`.
+///
+/// `Coefficients` holds one value per term, `Standard Errors` starts with the error of the intercept followed by one per term.
+pub struct RegressionNode {
+    model: RegressionModel,
+    weighted: bool,
+}
+
+impl RegressionNode {
+    pub fn new(model: RegressionModel, weighted: bool) -> RegressionNode {
+        RegressionNode {
+            model,
+            weighted,
+        }
+    }
+
+    /// Terms, response and weight of every row, `None` for rows left out of the fit.
+    fn get_rows(&self, table: &Table, response: &str, predictors: &[String], weights: Option<&str>) -> Option<Vec<Option<Observation>>> {
+        let response = table.get_column(response)?;
+        let predictors = predictors.iter().map(|name| table.get_column(name)).collect::<Option<Vec<_>>>()?;
+        let weights = match weights {
+            Some(name) => Some(table.get_column(name)?),
+            None => None,
+        };
+
+        if predictors.is_empty() {
+            return None;
+        }
+
+        Some((0..table.get_row_count()).map(|row| {
+            let y = get_number(response.get_value(row)?)?;
+            let values = predictors.iter().map(|column| get_number(column.get_value(row)?)).collect::<Option<Vec<_>>>()?;
+            let weight = match weights {
+                Some(column) => get_number(column.get_value(row)?).filter(|weight| *weight > 0.0)?,
+                None => 1.0,
+            };
+
+            Some((self.model.get_terms(&values), y, weight))
+        }).collect())
+    }
+}
+
+impl Node for RegressionNode {
+    fn get_name(&self) -> String {
+        let name = match self.model {
+            RegressionModel::Linear => "Linear Regression".to_string(),
+            RegressionModel::Polynomial(degree) => format!("Polynomial Regression ({})", degree),
+        };
+
+        match self.weighted {
+            true => format!("Weighted {}", name),
+            false => name,
+        }
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        let mut inputs = vec![
+            Port::new("Table", Table::new(), ConnectorTypeId::TABLE),
+            Port::new("Response", "Y", ConnectorTypeId::STRING),
+            Port::new("Predictors", vec!["X"], ConnectorTypeId::LIST),
+        ];
+
+        if self.weighted {
+            inputs.push(Port::new("Weights", "Weight", ConnectorTypeId::STRING));
+        }
+
+        inputs
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Coefficients", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+            Port::new("Intercept", Null::new(), ConnectorTypeId::ANY),
+            Port::new("R Squared", Null::new(), ConnectorTypeId::ANY),
+            Port::new("Adjusted R Squared", Null::new(), ConnectorTypeId::ANY),
+            Port::new("Standard Errors", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+            Port::new("Residuals", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+            Port::new("Predicted", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+        ]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let null = vec![ConnectorType::Null(Null::new()); 7];
+
+        let table = match context.get_input(0) {
+            ConnectorType::Table(table) => table,
+            _ => return null,
+        };
+
+        let response = context.get_input(1).to_string();
+        let predictors: Vec<String> = match context.get_input(2) {
+            ConnectorType::List(predictors) => predictors.iter().map(|name| name.to_string()).collect(),
+            _ => return null,
+        };
+
+        let weights = match self.weighted {
+            true => Some(context.get_input(3).to_string()),
+            false => None,
+        };
+
+        let rows = match self.get_rows(&table, &response, &predictors, weights.as_deref()) {
+            Some(rows) => rows,
+            None => return null,
+        };
+
+        let fitted: Vec<&Observation> = rows.iter().flatten().collect();
+        let terms: Vec<Vec<f64>> = fitted.iter().map(|row| row.0.clone()).collect();
+        let y: Vec<f64> = fitted.iter().map(|row| row.1).collect();
+        let row_weights: Vec<f64> = fitted.iter().map(|row| row.2).collect();
+
+        let regression = match Regression::fit(&terms, &y, self.weighted.then_some(row_weights.as_slice())) {
+            Some(regression) => regression,
+            None => return null,
+        };
+
+        // Spread the residuals and predictions back over the rows of the table
+        let mut index = 0;
+        let (residuals, predictions): (Vec<ConnectorType>, Vec<ConnectorType>) = rows.iter().map(|row| match row {
+            Some(_) => {
+                index += 1;
+
+                (from_number(Some(regression.get_residuals()[index - 1])), from_number(Some(regression.get_predictions()[index - 1])))
+            },
+            None => (ConnectorType::Null(Null::new()), ConnectorType::Null(Null::new())),
+        }).unzip();
+
+        let to_list = |values: &[f64]| ConnectorType::List(values.iter().map(|value| from_number(Some(*value))).collect());
+
+        vec![
+            to_list(&regression.get_coefficients()[1..]),
+            from_number(Some(regression.get_intercept())),
+            from_number(Some(regression.get_r_squared())),
+            from_number(Some(regression.get_adjusted_r_squared())),
+            to_list(regression.get_standard_errors()),
+            ConnectorType::List(residuals),
+            ConnectorType::List(predictions),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod regression_test {
+    use super::*;
+    use crate::nodes::connector_manager::table::Column;
+    use crate::nodes::node_manager::Manager;
+
+    fn assert_close(value: f64, expected: f64) {
+        assert!((value - expected).abs() < 1e-6, "{} should be close to {}", value, expected);
+    }
+
+    fn rows(values: &[f64]) -> Vec<Vec<f64>> {
+        values.iter().map(|value| vec![*value]).collect()
+    }
+
+    #[test]
+    fn test_simple() {
+        let regression = Regression::fit(&rows(&[1.0, 2.0, 3.0, 4.0, 5.0]), &[2.0, 4.0, 5.0, 4.0, 5.0], None).unwrap();
+
+        assert_close(regression.get_intercept(), 2.2);
+        assert_close(regression.get_coefficients()[1], 0.6);
+        assert_close(regression.get_r_squared(), 0.6);
+        assert_close(regression.get_adjusted_r_squared(), 0.466_666_667);
+        assert_close(regression.get_standard_errors()[0], 0.938_083_152);
+        assert_close(regression.get_standard_errors()[1], 0.282_842_712);
+        assert_close(regression.get_predictions()[0], 2.8);
+        assert_close(regression.get_residuals()[0], -0.8);
+        assert_close(predict(regression.get_coefficients(), &[6.0]), 5.8);
+    }
+
+    #[test]
+    fn test_multiple() {
+        // y = 1 + 2 * a - 3 * b exactly
+        let terms = vec![vec![0.0, 1.0], vec![1.0, 0.0], vec![2.0, 2.0], vec![3.0, 1.0], vec![4.0, 5.0]];
+        let y: Vec<f64> = terms.iter().map(|row| 1.0 + 2.0 * row[0] - 3.0 * row[1]).collect();
+        let regression = Regression::fit(&terms, &y, None).unwrap();
+
+        assert_close(regression.get_coefficients()[0], 1.0);
+        assert_close(regression.get_coefficients()[1], 2.0);
+        assert_close(regression.get_coefficients()[2], -3.0);
+        assert_close(regression.get_r_squared(), 1.0);
+    }
+
+    #[test]
+    fn test_polynomial() {
+        let model = RegressionModel::Polynomial(2);
+        let x = [-2.0, -1.0, 0.0, 1.0, 2.0, 3.0];
+        let terms: Vec<Vec<f64>> = x.iter().map(|x| model.get_terms(&[*x])).collect();
+        let y: Vec<f64> = x.iter().map(|x| 0.5 * x * x - x + 3.0).collect();
+        let regression = Regression::fit(&terms, &y, None).unwrap();
+
+        assert_eq!(model.get_terms(&[3.0]), vec![3.0, 9.0]);
+        assert_close(regression.get_coefficients()[0], 3.0);
+        assert_close(regression.get_coefficients()[1], -1.0);
+        assert_close(regression.get_coefficients()[2], 0.5);
+    }
+
+    #[test]
+    fn test_weighted() {
+        let x = rows(&[1.0, 2.0, 3.0, 4.0]);
+        let y = [1.0, 2.0, 3.0, 10.0];
+        let regression = Regression::fit(&x, &y, Some(&[1.0, 1.0, 1.0, 1e-9])).unwrap();
+
+        // The outlier has almost no weight
+        assert!((regression.get_coefficients()[1] - 1.0).abs() < 1e-6);
+
+        let duplicated = Regression::fit(&rows(&[1.0, 2.0, 2.0, 3.0]), &[1.0, 3.0, 3.0, 2.0], None).unwrap();
+        let weighted = Regression::fit(&rows(&[1.0, 2.0, 3.0]), &[1.0, 3.0, 2.0], Some(&[1.0, 2.0, 1.0])).unwrap();
+
+        assert_close(weighted.get_intercept(), duplicated.get_intercept());
+        assert_close(weighted.get_coefficients()[1], duplicated.get_coefficients()[1]);
+    }
+
+    #[test]
+    fn test_degenerate() {
+        assert_eq!(Regression::fit(&rows(&[1.0]), &[1.0], None), None);
+        assert_eq!(Regression::fit(&rows(&[2.0, 2.0, 2.0]), &[1.0, 2.0, 3.0], None), None);
+        assert_eq!(Regression::fit(&[vec![1.0, 2.0], vec![2.0, 4.0], vec![3.0, 6.0]], &[1.0, 2.0, 3.0], None), None);
+    }
+
+    #[test]
+    fn test_node() {
+        let mut manager = Manager::new();
+        let node = manager.add_node(RegressionNode::new(RegressionModel::Linear, false));
+        let null = ConnectorType::Null(Null::new());
+
+        manager.set_input_value(node, 0, Table::from_columns(vec![
+            Column::new("X", ConnectorTypeId::FLOAT, vec![ConnectorType::new(1.0), ConnectorType::new(2.0), null.clone(), ConnectorType::new(3.0)]),
+            Column::new("Y", ConnectorTypeId::FLOAT, vec![3.0, 5.0, 100.0, 7.0]),
+        ]).unwrap());
+
+        let outputs = manager.evaluate(node);
+
+        assert_eq!(outputs[0], ConnectorType::new(vec![2.0]));
+        assert_eq!(outputs[1], ConnectorType::new(1.0));
+        assert_eq!(outputs[2], ConnectorType::new(1.0));
+        assert_eq!(outputs[6], ConnectorType::new(vec![ConnectorType::new(3.0), ConnectorType::new(5.0), null, ConnectorType::new(7.0)]));
+
+        manager.set_input_value(node, 2, "Missing");
+
+        let outputs = manager.evaluate(node);
+
+        assert!(outputs[1].is_null() && outputs[2].is_null());
+        assert_eq!(outputs[0], ConnectorType::new(Vec::<ConnectorType>::new()));
+    }
+}
@@ -0,0 +1,407 @@
+use std::cell::RefCell;
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::record::Record;
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::node_manager::Manager;
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+use super::descriptive::quantile;
+use super::sampling::{get_row_count, sample_indices, shuffle, take_rows};
+use super::special::{normal_cdf, normal_quantile};
+use super::{from_number, get_number};
+
+/// A statistic computed by a sub-graph of nodes.\
+/// Samples are written to the `inputs` of the graph, given as `(node, slot)`,
+/// and the statistic is read from the `output` slot of a node.
+///
+/// # Example
+/// ```
+/// let mut manager = Manager::new();
+/// let median = manager.add_node(StatisticNode::new(Statistic::Median, NullHandling::Skip));
+///
+/// let statistic = StatisticGraph::new(manager, vec![(median, 0)], (median, 0));
+/// ```
+pub struct StatisticGraph {
+    manager: RefCell<Manager>,
+    inputs: Vec<(usize, usize)>,
+    output: (usize, usize),
+}
+
+impl StatisticGraph {
+    pub fn new(manager: Manager, inputs: Vec<(usize, usize)>, output: (usize, usize)) -> StatisticGraph {
+        StatisticGraph {
+            manager: RefCell::new(manager),
+            inputs,
+            output,
+        }
+    }
+
+    pub fn get_input_count(&self) -> usize {
+        self.inputs.len()
+    }
+
+    /// Output of the graph for the values, one per input of the graph. `Null` when the output slot doesn't exist.
+    pub fn get_output(&self, values: &[ConnectorType]) -> ConnectorType {
+        let mut manager = self.manager.borrow_mut();
+
+        for ((node, slot), value) in self.inputs.iter().zip(values) {
+            manager.set_input_value(*node, *slot, value.clone());
+        }
+
+        let (node, slot) = self.output;
+
+        manager.evaluate(node).get(slot).cloned().unwrap_or(ConnectorType::Null(Null::new()))
+    }
+
+    /// Statistic of the samples, one per input of the graph. `None` when it isn't a finite number.
+    pub fn evaluate(&self, samples: &[ConnectorType]) -> Option<f64> {
+        get_number(&self.get_output(samples)).filter(|value| value.is_finite())
+    }
+}
+
+/// Percentile confidence interval, the quantiles of the sorted replicates around the `confidence` level.
+pub fn percentile_interval(sorted: &[f64], confidence: f64) -> Option<(f64, f64)> {
+    let alpha = (1.0 - confidence) / 2.0;
+
+    Some((quantile(sorted, alpha)?, quantile(sorted, 1.0 - alpha)?))
+}
+
+/// Bias-corrected and accelerated confidence interval.\
+/// The bias is measured by the share of replicates below the estimate,
+/// the acceleration by the skewness of the jackknife (leave one out) estimates.
+pub fn bca_interval(sorted: &[f64], estimate: f64, jackknife: &[f64], confidence: f64) -> Option<(f64, f64)> {
+    if sorted.is_empty() || jackknife.is_empty() || !(0.0..1.0).contains(&confidence) {
+        return None;
+    }
+
+    // Ties count as half below the estimate
+    let below = sorted.partition_point(|value| *value < estimate);
+    let equal = sorted.partition_point(|value| *value <= estimate) - below;
+    let bias = normal_quantile((below as f64 + equal as f64 / 2.0) / sorted.len() as f64);
+
+    if !bias.is_finite() {
+        return None;
+    }
+
+    let mean = jackknife.iter().sum::<f64>() / jackknife.len() as f64;
+    let squares: f64 = jackknife.iter().map(|value| (mean - value).powi(2)).sum();
+    let cubes: f64 = jackknife.iter().map(|value| (mean - value).powi(3)).sum();
+    let acceleration = if squares > 0.0 { cubes / (6.0 * squares.powf(1.5)) } else { 0.0 };
+
+    let adjust = |alpha: f64| {
+        let z = bias + normal_quantile(alpha);
+
+        normal_cdf(bias + z / (1.0 - acceleration * z))
+    };
+
+    let alpha = (1.0 - confidence) / 2.0;
+
+    Some((quantile(sorted, adjust(alpha))?, quantile(sorted, adjust(1.0 - alpha))?))
+}
+
+/// Alternative hypothesis of a permutation test, in terms of the statistic of the observed samples.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Alternative {
+    /// The statistic is further from zero than under the null hypothesis.
+    TwoSided,
+    Less,
+    Greater,
+}
+
+impl Alternative {
+    /// Whether a permuted statistic is at least as extreme as the observed one.
+    pub fn is_extreme(&self, permuted: f64, observed: f64) -> bool {
+        match self {
+            Alternative::TwoSided => permuted.abs() >= observed.abs(),
+            Alternative::Less => permuted <= observed,
+            Alternative::Greater => permuted >= observed,
+        }
+    }
+}
+
+fn interval_record(interval: Option<(f64, f64)>) -> ConnectorType {
+    let mut record = Record::new();
+
+    if let Some((lower, upper)) = interval {
+        record.set_field("Lower", from_number(Some(lower)));
+        record.set_field("Upper", from_number(Some(upper)));
+    }
+
+    ConnectorType::Record(record)
+}
+
+/// Bootstraps a statistic of a list or table.\
+/// The statistic graph is evaluated on `Resamples` resamples drawn with replacement from `Values`,
+/// resamples where it isn't a number are left out of the `Distribution`.\
+/// Intervals are records with `Lower` and `Upper` fields, empty when they can't be computed.
+pub struct BootstrapNode {
+    statistic: StatisticGraph,
+}
+
+impl BootstrapNode {
+    pub fn new(statistic: StatisticGraph) -> BootstrapNode {
+        BootstrapNode {
+            statistic,
+        }
+    }
+}
+
+impl Node for BootstrapNode {
+    fn get_name(&self) -> String {
+        "Bootstrap".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Values", Vec::<ConnectorType>::new(), ConnectorTypeId::ANY),
+            Port::new("Resamples", 1000, ConnectorTypeId::INT),
+            Port::new("Confidence", 0.95, ConnectorTypeId::FLOAT),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Estimate", Null::new(), ConnectorTypeId::ANY),
+            Port::new("Distribution", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+            Port::new("Standard Error", Null::new(), ConnectorTypeId::ANY),
+            Port::new("Percentile Interval", Record::new(), ConnectorTypeId::RECORD),
+            Port::new("BCa Interval", Record::new(), ConnectorTypeId::RECORD),
+        ]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let values = context.get_input(0);
+        let resamples = get_number(&context.get_input(1)).unwrap_or(0.0).max(0.0) as usize;
+        let confidence = get_number(&context.get_input(2)).unwrap_or(f64::NAN);
+        let count = get_row_count(&values);
+
+        let estimate = match self.statistic.evaluate(std::slice::from_ref(&values)) {
+            Some(estimate) if count > 0 => estimate,
+            _ => return vec![ConnectorType::Null(Null::new()); 5],
+        };
+
+        let mut random = context.get_random();
+        let replicates: Vec<f64> = (0..resamples)
+            .filter_map(|_| self.statistic.evaluate(&[take_rows(&values, &sample_indices(&mut random, count, count, true))]))
+            .collect();
+
+        let mut sorted = replicates.clone();
+
+        sorted.sort_by(|a, b| a.total_cmp(b));
+
+        let jackknife: Vec<f64> = (0..count)
+            .filter_map(|skip| {
+                let rows: Vec<usize> = (0..count).filter(|row| *row != skip).collect();
+
+                self.statistic.evaluate(&[take_rows(&values, &rows)])
+            })
+            .collect();
+
+        let standard_error = match replicates.len() {
+            0 | 1 => None,
+            length => {
+                let mean = replicates.iter().sum::<f64>() / length as f64;
+
+                Some((replicates.iter().map(|value| (value - mean).powi(2)).sum::<f64>() / (length - 1) as f64).sqrt())
+            },
+        };
+
+        vec![
+            from_number(Some(estimate)),
+            ConnectorType::List(replicates.iter().map(|value| from_number(Some(*value))).collect()),
+            from_number(standard_error),
+            interval_record(percentile_interval(&sorted, confidence)),
+            interval_record(bca_interval(&sorted, estimate, &jackknife, confidence)),
+        ]
+    }
+}
+
+/// Permutation test of a two sample statistic, such as the difference of the means.\
+/// The observed statistic is compared to its value on `Permutations` random reassignments of the pooled values
+/// to groups of the original sizes. The p-value counts the observed samples as one of the permutations.
+pub struct PermutationTestNode {
+    statistic: StatisticGraph,
+    alternative: Alternative,
+}
+
+impl PermutationTestNode {
+    /// The statistic graph takes the two samples as its two inputs.
+    pub fn new(statistic: StatisticGraph, alternative: Alternative) -> PermutationTestNode {
+        PermutationTestNode {
+            statistic,
+            alternative,
+        }
+    }
+}
+
+impl Node for PermutationTestNode {
+    fn get_name(&self) -> String {
+        "Permutation Test".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("A", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+            Port::new("B", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+            Port::new("Permutations", 1000, ConnectorTypeId::INT),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Statistic", Null::new(), ConnectorTypeId::ANY),
+            Port::new("Distribution", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+            Port::new("P-Value", Null::new(), ConnectorTypeId::ANY),
+        ]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let (a, b) = match (context.get_input(0), context.get_input(1)) {
+            (ConnectorType::List(a), ConnectorType::List(b)) => (a, b),
+            _ => return vec![ConnectorType::Null(Null::new()); 3],
+        };
+
+        let permutations = get_number(&context.get_input(2)).unwrap_or(0.0).max(0.0) as usize;
+
+        let observed = match self.statistic.evaluate(&[ConnectorType::List(a.clone()), ConnectorType::List(b.clone())]) {
+            Some(observed) => observed,
+            None => return vec![ConnectorType::Null(Null::new()); 3],
+        };
+
+        let pooled: Vec<ConnectorType> = a.iter().chain(b.iter()).cloned().collect();
+        let mut random = context.get_random();
+
+        let replicates: Vec<f64> = (0..permutations)
+            .filter_map(|_| {
+                let mut rows: Vec<ConnectorType> = shuffle(&mut random, pooled.len()).into_iter().map(|row| pooled[row].clone()).collect();
+                let second = rows.split_off(a.len());
+
+                self.statistic.evaluate(&[ConnectorType::List(rows), ConnectorType::List(second)])
+            })
+            .collect();
+
+        let extreme = replicates.iter().filter(|value| self.alternative.is_extreme(**value, observed)).count();
+
+        vec![
+            from_number(Some(observed)),
+            ConnectorType::List(replicates.iter().map(|value| from_number(Some(*value))).collect()),
+            from_number(Some((extreme + 1) as f64 / (replicates.len() + 1) as f64)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod resampling_test {
+    use super::*;
+    use crate::nodes::math::{BinaryNode, BinaryOperation};
+    use crate::nodes::statistics::NullHandling;
+    use crate::nodes::statistics::descriptive::{Statistic, StatisticNode};
+
+    fn statistic(statistic: Statistic) -> StatisticGraph {
+        let mut manager = Manager::new();
+        let node = manager.add_node(StatisticNode::new(statistic, NullHandling::Skip));
+
+        StatisticGraph::new(manager, vec![(node, 0)], (node, 0))
+    }
+
+    fn mean_difference() -> StatisticGraph {
+        let mut manager = Manager::new();
+        let a = manager.add_node(StatisticNode::new(Statistic::Mean, NullHandling::Skip));
+        let b = manager.add_node(StatisticNode::new(Statistic::Mean, NullHandling::Skip));
+        let difference = manager.add_node(BinaryNode::new(BinaryOperation::Subtract));
+
+        manager.connect(a, 0, difference, 0);
+        manager.connect(b, 0, difference, 1);
+
+        StatisticGraph::new(manager, vec![(a, 0), (b, 0)], (difference, 0))
+    }
+
+    fn get_interval(value: &ConnectorType) -> (f64, f64) {
+        match value {
+            ConnectorType::Record(record) => (get_number(record.get_field("Lower").unwrap()).unwrap(), get_number(record.get_field("Upper").unwrap()).unwrap()),
+            value => panic!("{:?} should be an interval", value),
+        }
+    }
+
+    #[test]
+    fn test_statistic_graph() {
+        let graph = mean_difference();
+
+        assert_eq!(graph.get_input_count(), 2);
+        assert_eq!(graph.evaluate(&[ConnectorType::new(vec![1, 2, 3]), ConnectorType::new(vec![1])]), Some(1.0));
+        assert_eq!(statistic(Statistic::Mean).evaluate(&[ConnectorType::new(Vec::<i32>::new())]), None);
+    }
+
+    #[test]
+    fn test_intervals() {
+        let sorted: Vec<f64> = (0..=100).map(|value| value as f64).collect();
+
+        let (lower, upper) = percentile_interval(&sorted, 0.9).unwrap();
+
+        assert!((lower - 5.0).abs() < 1e-9 && (upper - 95.0).abs() < 1e-9, "({}, {}) should be (5, 95)", lower, upper);
+
+        // Without bias or acceleration the BCa interval is the percentile interval
+        let (lower, upper) = bca_interval(&sorted, 50.0, &[1.0, 1.0, 1.0], 0.9).unwrap();
+
+        assert!((lower - 5.0).abs() < 1e-9 && (upper - 95.0).abs() < 1e-9, "({}, {}) should be (5, 95)", lower, upper);
+
+        // An estimate below the replicates shifts the interval down
+        let (lower, upper) = bca_interval(&sorted, 40.0, &[1.0, 2.0, 4.0], 0.9).unwrap();
+
+        assert!(lower < 5.0 && upper < 95.0);
+        assert_eq!(bca_interval(&sorted, -1.0, &[1.0], 0.9), None);
+    }
+
+    #[test]
+    fn test_bootstrap_node() {
+        let values: Vec<i32> = (1..=30).collect();
+        let mut manager = Manager::new();
+        let node = manager.add_node(BootstrapNode::new(statistic(Statistic::Mean)));
+
+        manager.set_input_value(node, 0, values);
+        manager.set_input_value(node, 1, 500);
+
+        let outputs = manager.evaluate(node);
+        let (lower, upper) = get_interval(&outputs[3]);
+        let (bca_lower, bca_upper) = get_interval(&outputs[4]);
+        // Standard error of the mean, population standard deviation of 1..=30 over the square root of 30
+        let standard_error = (899.0f64 / 12.0 / 30.0).sqrt();
+
+        assert_eq!(get_number(&outputs[0]), Some(15.5));
+        assert_eq!(get_row_count(&outputs[1]), 500);
+        assert!((get_number(&outputs[2]).unwrap() - standard_error).abs() < 0.2);
+        assert!(lower < 15.5 && 15.5 < upper && bca_lower < 15.5 && 15.5 < bca_upper);
+        assert!((upper - lower - 2.0 * 1.96 * standard_error).abs() < 0.7);
+        assert_eq!(manager.evaluate(node), outputs, "Bootstrap should be reproducible");
+
+        manager.set_input_value(node, 0, Vec::<i32>::new());
+
+        assert_eq!(manager.evaluate(node)[3], ConnectorType::Record(Record::new()));
+    }
+
+    #[test]
+    fn test_permutation_node() {
+        let mut manager = Manager::new();
+        let node = manager.add_node(PermutationTestNode::new(mean_difference(), Alternative::TwoSided));
+        let greater = manager.add_node(PermutationTestNode::new(mean_difference(), Alternative::Greater));
+
+        for node in [node, greater] {
+            manager.set_input_value(node, 0, vec![6.2, 6.8, 6.1, 7.0, 6.6, 6.9]);
+            manager.set_input_value(node, 1, vec![5.1, 4.9, 5.6, 5.8, 6.0, 5.2]);
+            manager.set_input_value(node, 2, 500);
+        }
+
+        let outputs = manager.evaluate(node);
+        let p_value = get_number(&outputs[2]).unwrap();
+
+        assert!((get_number(&outputs[0]).unwrap() - 1.1666667).abs() < 1e-5);
+        assert_eq!(get_row_count(&outputs[1]), 500);
+        // Only 2 of the 924 splits are as extreme as the observed one
+        assert!(p_value < 0.02, "{} should be small", p_value);
+        assert!(get_number(&manager.evaluate(greater)[2]).unwrap() <= p_value);
+
+        manager.set_input_value(node, 1, vec![6.2, 6.8, 6.1, 7.0, 6.6, 6.9]);
+
+        assert!(get_number(&manager.evaluate(node)[2]).unwrap() > 0.9);
+    }
+}
@@ -0,0 +1,329 @@
+use crate::helper::random::Random;
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+use super::distribution::MAX_SAMPLES;
+use super::get_number;
+
+/// Random permutation of `0..count`, with the Fisher–Yates shuffle.
+pub fn shuffle(random: &mut Random, count: usize) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..count).collect();
+
+    for index in (1..count).rev() {
+        indices.swap(index, random.next_below(index as u64 + 1) as usize);
+    }
+
+    indices
+}
+
+/// `count` random indices below `population`.\
+/// Without replacement the indices are distinct and at most `population` are drawn.
+pub fn sample_indices(random: &mut Random, population: usize, count: usize, replace: bool) -> Vec<usize> {
+    if population == 0 {
+        return Vec::new();
+    }
+
+    match replace {
+        true => (0..count).map(|_| random.next_below(population as u64) as usize).collect(),
+        false => {
+            // Partial Fisher–Yates, only the first `count` positions are shuffled
+            let count = count.min(population);
+            let mut indices: Vec<usize> = (0..population).collect();
+
+            for index in 0..count {
+                let other = index + random.next_below((population - index) as u64) as usize;
+
+                indices.swap(index, other);
+            }
+
+            indices.truncate(count);
+            indices
+        },
+    }
+}
+
+/// Number of rows of a list or table, other values are a single row.
+pub fn get_row_count(value: &ConnectorType) -> usize {
+    match value {
+        ConnectorType::List(values) => values.len(),
+        ConnectorType::Table(table) => table.get_row_count(),
+        ConnectorType::Null(_) => 0,
+        _ => 1,
+    }
+}
+
+/// Rows of a list or table at the indices, other values are read as a list of one value.
+pub fn take_rows(value: &ConnectorType, rows: &[usize]) -> ConnectorType {
+    match value {
+        ConnectorType::Table(table) => ConnectorType::Table(table.take_rows(rows)),
+        ConnectorType::List(values) => ConnectorType::List(rows.iter().filter_map(|row| values.get(*row).cloned()).collect()),
+        value => take_rows(&value.get_variant(ConnectorTypeId::LIST), rows),
+    }
+}
+
+fn get_values_port() -> Port {
+    Port::new("Values", Vec::<ConnectorType>::new(), ConnectorTypeId::ANY)
+}
+
+/// Draws `Count` random rows of a list or table.\
+/// A count above `MAX_SAMPLES` gives null.
+pub struct RandomSampleNode {
+    replace: bool,
+}
+
+impl RandomSampleNode {
+    /// With `replace` a row can be drawn several times.
+    pub fn new(replace: bool) -> RandomSampleNode {
+        RandomSampleNode {
+            replace,
+        }
+    }
+}
+
+impl Node for RandomSampleNode {
+    fn get_name(&self) -> String {
+        "Random Sample".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![get_values_port(), Port::new("Count", 1, ConnectorTypeId::INT)]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Sample", Vec::<ConnectorType>::new(), ConnectorTypeId::ANY)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let values = context.get_input(0);
+        let count = match get_number(&context.get_input(1)).unwrap_or(0.0) {
+            count if count <= MAX_SAMPLES as f64 => count.max(0.0) as usize,
+            _ => return vec![ConnectorType::Null(Null::new())],
+        };
+        let rows = sample_indices(&mut context.get_random(), get_row_count(&values), count, self.replace);
+
+        vec![take_rows(&values, &rows)]
+    }
+}
+
+/// Rows of a list or table in random order.
+pub struct ShuffleNode {}
+
+impl ShuffleNode {
+    pub fn new() -> ShuffleNode {
+        ShuffleNode {}
+    }
+}
+
+impl Default for ShuffleNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for ShuffleNode {
+    fn get_name(&self) -> String {
+        "Shuffle".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![get_values_port()]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Shuffled", Vec::<ConnectorType>::new(), ConnectorTypeId::ANY)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let values = context.get_input(0);
+        let rows = shuffle(&mut context.get_random(), get_row_count(&values));
+
+        vec![take_rows(&values, &rows)]
+    }
+}
+
+/// Bootstrap resample, as many rows as the input drawn with replacement.
+pub struct BootstrapResampleNode {}
+
+impl BootstrapResampleNode {
+    pub fn new() -> BootstrapResampleNode {
+        BootstrapResampleNode {}
+    }
+}
+
+impl Default for BootstrapResampleNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for BootstrapResampleNode {
+    fn get_name(&self) -> String {
+        "Bootstrap Resample".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![get_values_port()]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Resample", Vec::<ConnectorType>::new(), ConnectorTypeId::ANY)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let values = context.get_input(0);
+        let count = get_row_count(&values);
+        let rows = sample_indices(&mut context.get_random(), count, count, true);
+
+        vec![take_rows(&values, &rows)]
+    }
+}
+
+/// Splits the rows of a list or table in a train and a test set.\
+/// `Test Fraction` of the rows, rounded, go to the test set.
+/// Rows keep their order when `Shuffle` is off, with the test rows taken from the end.
+pub struct TrainTestSplitNode {}
+
+impl TrainTestSplitNode {
+    pub fn new() -> TrainTestSplitNode {
+        TrainTestSplitNode {}
+    }
+}
+
+impl Default for TrainTestSplitNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for TrainTestSplitNode {
+    fn get_name(&self) -> String {
+        "Train Test Split".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            get_values_port(),
+            Port::new("Test Fraction", 0.25, ConnectorTypeId::FLOAT),
+            Port::new("Shuffle", true, ConnectorTypeId::BOOL),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Train", Vec::<ConnectorType>::new(), ConnectorTypeId::ANY),
+            Port::new("Test", Vec::<ConnectorType>::new(), ConnectorTypeId::ANY),
+        ]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let values = context.get_input(0);
+        let count = get_row_count(&values);
+
+        let fraction = match get_number(&context.get_input(1)) {
+            Some(fraction) if (0.0..=1.0).contains(&fraction) => fraction,
+            _ => return vec![ConnectorType::Null(Null::new()); 2],
+        };
+
+        let rows = match context.get_input(2) {
+            ConnectorType::Boolean(false) => (0..count).collect(),
+            _ => shuffle(&mut context.get_random(), count),
+        };
+
+        let (train, test) = rows.split_at(count - (count as f64 * fraction).round() as usize);
+
+        vec![take_rows(&values, train), take_rows(&values, test)]
+    }
+}
+
+#[cfg(test)]
+mod sampling_test {
+    use super::*;
+    use crate::nodes::connector_manager::table::{Column, Table};
+    use crate::nodes::node_manager::Manager;
+
+    fn sorted(value: &ConnectorType) -> Vec<ConnectorType> {
+        let mut values = match value.get_variant(ConnectorTypeId::LIST) {
+            ConnectorType::List(values) => values,
+            _ => Vec::new(),
+        };
+
+        values.sort();
+        values
+    }
+
+    #[test]
+    fn test_indices() {
+        let mut random = Random::new(3);
+        let permutation = shuffle(&mut random, 10);
+        let mut check = permutation.clone();
+
+        check.sort();
+
+        assert_eq!(check, (0..10).collect::<Vec<usize>>());
+        assert_ne!(permutation, (0..10).collect::<Vec<usize>>());
+
+        let mut distinct = sample_indices(&mut random, 5, 10, false);
+
+        distinct.sort();
+
+        assert_eq!(distinct, vec![0, 1, 2, 3, 4]);
+        assert_eq!(sample_indices(&mut random, 5, 10, true).len(), 10);
+        assert!(sample_indices(&mut random, 0, 10, true).is_empty());
+    }
+
+    #[test]
+    fn test_take_rows() {
+        let table = Table::from_columns(vec![Column::new("A", ConnectorTypeId::INT, vec![1, 2, 3])]).unwrap();
+
+        assert_eq!(take_rows(&ConnectorType::new(vec![1, 2, 3]), &[2, 2, 5]), ConnectorType::new(vec![3, 3]));
+        assert_eq!(take_rows(&ConnectorType::new(table), &[1]), Table::from_columns(vec![Column::new("A", ConnectorTypeId::INT, vec![2])]).unwrap());
+        assert_eq!(take_rows(&ConnectorType::new(7), &[0, 0]), ConnectorType::new(vec![7, 7]));
+    }
+
+    #[test]
+    fn test_nodes() {
+        let values: Vec<i32> = (0..20).collect();
+        let mut manager = Manager::new();
+        let sample = manager.add_node(RandomSampleNode::new(false));
+        let replaced = manager.add_node(RandomSampleNode::new(true));
+        let shuffled = manager.add_node(ShuffleNode::new());
+        let bootstrap = manager.add_node(BootstrapResampleNode::new());
+        let split = manager.add_node(TrainTestSplitNode::new());
+
+        for node in [sample, replaced, shuffled, bootstrap, split] {
+            manager.set_input_value(node, 0, values.clone());
+        }
+
+        manager.set_input_value(sample, 1, 5);
+
+        let drawn = sorted(&manager.evaluate(sample)[0]);
+
+        assert_eq!(drawn.len(), 5);
+        assert!(drawn.windows(2).all(|pair| pair[0] != pair[1]), "Rows should be drawn once");
+
+        manager.set_input_value(replaced, 1, 30);
+
+        assert_eq!(get_row_count(&manager.evaluate(replaced)[0]), 30);
+
+        manager.set_input_value(replaced, 1, 2000000);
+
+        assert_eq!(manager.evaluate(replaced)[0], ConnectorType::Null(Null::new()));
+        assert_eq!(sorted(&manager.evaluate(shuffled)[0]), ConnectorType::new(values.clone()).get_variant(ConnectorTypeId::LIST));
+        assert_eq!(get_row_count(&manager.evaluate(bootstrap)[0]), 20);
+
+        let outputs = manager.evaluate(split);
+        let mut rows = sorted(&outputs[0]);
+
+        rows.extend(sorted(&outputs[1]));
+        rows.sort();
+
+        assert_eq!(get_row_count(&outputs[1]), 5);
+        assert_eq!(ConnectorType::List(rows), ConnectorType::new(values.clone()));
+        assert_eq!(manager.evaluate(split), outputs, "Splits should be reproducible");
+
+        manager.set_input_value(split, 2, false);
+
+        assert_eq!(manager.evaluate(split)[1], ConnectorType::new(vec![15, 16, 17, 18, 19]));
+    }
+}
@@ -0,0 +1,321 @@
+const EPSILON: f64 = 1e-15;
+const MAX_ITERATIONS: usize = 500;
+
+const LANCZOS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+/// Natural logarithm of the gamma function, using the Lanczos approximation.
+pub fn ln_gamma(x: f64) -> f64 {
+    if x < 0.5 {
+        // Reflection formula
+        return (std::f64::consts::PI / (std::f64::consts::PI * x).sin()).abs().ln() - ln_gamma(1.0 - x);
+    }
+
+    let x = x - 1.0;
+    let t = x + 7.5;
+    let sum = LANCZOS[1..].iter().enumerate().fold(LANCZOS[0], |sum, (index, coefficient)| sum + coefficient / (x + index as f64 + 1.0));
+
+    0.5 * (2.0 * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+}
+
+/// Natural logarithm of the beta function.
+pub fn ln_beta(a: f64, b: f64) -> f64 {
+    ln_gamma(a) + ln_gamma(b) - ln_gamma(a + b)
+}
+
+/// Regularized lower incomplete gamma function `P(a, x)`.
+pub fn gamma_p(a: f64, x: f64) -> f64 {
+    if x <= 0.0 || a <= 0.0 {
+        return if a > 0.0 { 0.0 } else { f64::NAN };
+    }
+
+    match x < a + 1.0 {
+        true => gamma_series(a, x),
+        false => 1.0 - gamma_fraction(a, x),
+    }
+}
+
+/// Regularized upper incomplete gamma function `Q(a, x) = 1 - P(a, x)`.
+pub fn gamma_q(a: f64, x: f64) -> f64 {
+    if x <= 0.0 || a <= 0.0 {
+        return if a > 0.0 { 1.0 } else { f64::NAN };
+    }
+
+    match x < a + 1.0 {
+        true => 1.0 - gamma_series(a, x),
+        false => gamma_fraction(a, x),
+    }
+}
+
+fn gamma_series(a: f64, x: f64) -> f64 {
+    let mut term = 1.0 / a;
+    let mut sum = term;
+
+    for index in 1..MAX_ITERATIONS {
+        term *= x / (a + index as f64);
+        sum += term;
+
+        if term.abs() < sum.abs() * EPSILON {
+            break;
+        }
+    }
+
+    sum * (-x + a * x.ln() - ln_gamma(a)).exp()
+}
+
+/// Continued fraction of `Q(a, x)`, evaluated with the modified Lentz method.
+fn gamma_fraction(a: f64, x: f64) -> f64 {
+    let tiny = f64::MIN_POSITIVE / EPSILON;
+    let mut b = x + 1.0 - a;
+    let mut c = 1.0 / tiny;
+    let mut d = 1.0 / b;
+    let mut h = d;
+
+    for index in 1..MAX_ITERATIONS {
+        let an = -(index as f64) * (index as f64 - a);
+
+        b += 2.0;
+        d = an * d + b;
+        d = if d.abs() < tiny { tiny } else { d };
+        c = b + an / c;
+        c = if c.abs() < tiny { tiny } else { c };
+        d = 1.0 / d;
+
+        let delta = d * c;
+
+        h *= delta;
+
+        if (delta - 1.0).abs() < EPSILON {
+            break;
+        }
+    }
+
+    (-x + a * x.ln() - ln_gamma(a)).exp() * h
+}
+
+/// Regularized incomplete beta function `I_x(a, b)`.
+pub fn beta_regularized(a: f64, b: f64, x: f64) -> f64 {
+    if a <= 0.0 || b <= 0.0 || x.is_nan() {
+        return f64::NAN;
+    }
+
+    if x <= 0.0 {
+        return 0.0;
+    }
+
+    if x >= 1.0 {
+        return 1.0;
+    }
+
+    let front = (a * x.ln() + b * (1.0 - x).ln() - ln_beta(a, b)).exp();
+
+    // The continued fraction converges quickly below the mean, use the symmetry above it
+    match x < (a + 1.0) / (a + b + 2.0) {
+        true => front * beta_fraction(a, b, x) / a,
+        false => 1.0 - front * beta_fraction(b, a, 1.0 - x) / b,
+    }
+}
+
+fn beta_fraction(a: f64, b: f64, x: f64) -> f64 {
+    let tiny = f64::MIN_POSITIVE / EPSILON;
+    let clamp = |value: f64| if value.abs() < tiny { tiny } else { value };
+    let mut c = 1.0;
+    let mut d = 1.0 / clamp(1.0 - (a + b) * x / (a + 1.0));
+    let mut h = d;
+
+    for index in 1..MAX_ITERATIONS {
+        let m = index as f64;
+        let even = m * (b - m) * x / ((a + 2.0 * m - 1.0) * (a + 2.0 * m));
+
+        d = 1.0 / clamp(1.0 + even * d);
+        c = clamp(1.0 + even / c);
+        h *= d * c;
+
+        let odd = -(a + m) * (a + b + m) * x / ((a + 2.0 * m) * (a + 2.0 * m + 1.0));
+
+        d = 1.0 / clamp(1.0 + odd * d);
+        c = clamp(1.0 + odd / c);
+
+        let delta = d * c;
+
+        h *= delta;
+
+        if (delta - 1.0).abs() < EPSILON {
+            break;
+        }
+    }
+
+    h
+}
+
+/// Complementary error function `1 - erf(x)`, without cancellation for large `x`.
+pub fn erfc(x: f64) -> f64 {
+    match x < 0.0 {
+        true => 1.0 + gamma_p(0.5, x * x),
+        false => gamma_q(0.5, x * x),
+    }
+}
+
+/// Cumulative distribution function of the standard normal distribution.
+pub fn normal_cdf(x: f64) -> f64 {
+    0.5 * erfc(-x / std::f64::consts::SQRT_2)
+}
+
+/// Cumulative distribution function of Student's t distribution.
+pub fn student_t_cdf(t: f64, degrees_of_freedom: f64) -> f64 {
+    if t.is_infinite() {
+        return if t > 0.0 { 1.0 } else { 0.0 };
+    }
+
+    let tail = 0.5 * beta_regularized(degrees_of_freedom / 2.0, 0.5, degrees_of_freedom / (degrees_of_freedom + t * t));
+
+    match t > 0.0 {
+        true => 1.0 - tail,
+        false => tail,
+    }
+}
+
+/// Two-sided p-value of a t statistic.
+pub fn student_t_p_value(t: f64, degrees_of_freedom: f64) -> f64 {
+    (2.0 * student_t_cdf(-t.abs(), degrees_of_freedom)).min(1.0)
+}
+
+/// Quantile function of the standard normal distribution, NaN outside `(0, 1)`.\
+/// Acklam's rational approximation, refined with a Halley step.
+pub fn normal_quantile(probability: f64) -> f64 {
+    const A: [f64; 6] = [-39.696_830_286_653_76, 220.946_098_424_520_5, -275.928_510_446_968_7, 138.357_751_867_269, -30.664_798_066_147_16, 2.506_628_277_459_239];
+    const B: [f64; 5] = [-54.476_098_798_224_06, 161.585_836_858_040_9, -155.698_979_859_886_6, 66.801_311_887_719_72, -13.280_681_552_885_72];
+    const C: [f64; 6] = [-0.007_784_894_002_430_293, -0.322_396_458_041_136_5, -2.400_758_277_161_838, -2.549_732_539_343_734, 4.374_664_141_464_968, 2.938_163_982_698_783];
+    const D: [f64; 4] = [0.007_784_695_709_041_462, 0.322_467_129_070_039_8, 2.445_134_137_142_996, 3.754_408_661_907_416];
+
+    if !(probability > 0.0 && probability < 1.0) {
+        return if probability == 0.0 { f64::NEG_INFINITY } else if probability == 1.0 { f64::INFINITY } else { f64::NAN };
+    }
+
+    let tail = |q: f64| (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5]) / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0);
+
+    let x = match probability {
+        p if p < 0.02425 => tail((-2.0 * p.ln()).sqrt()),
+        p if p > 1.0 - 0.02425 => -tail((-2.0 * (1.0 - p).ln()).sqrt()),
+        p => {
+            let q = p - 0.5;
+            let r = q * q;
+
+            (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+        },
+    };
+
+    let error = normal_cdf(x) - probability;
+    let step = error * (2.0 * std::f64::consts::PI).sqrt() * (x * x / 2.0).exp();
+
+    x - step / (1.0 + x * step / 2.0)
+}
+
+/// Two-sided p-value of a standard normal statistic.
+pub fn normal_p_value(z: f64) -> f64 {
+    erfc(z.abs() / std::f64::consts::SQRT_2).min(1.0)
+}
+
+/// Probability of a chi-square variable exceeding `x`.
+pub fn chi_squared_survival(x: f64, degrees_of_freedom: f64) -> f64 {
+    gamma_q(degrees_of_freedom / 2.0, x.max(0.0) / 2.0)
+}
+
+/// Probability of an F variable exceeding `x`.
+pub fn f_survival(x: f64, numerator: f64, denominator: f64) -> f64 {
+    if x <= 0.0 {
+        return 1.0;
+    }
+
+    beta_regularized(denominator / 2.0, numerator / 2.0, denominator / (denominator + numerator * x))
+}
+
+/// Probability of the Kolmogorov distribution exceeding `lambda`, `2 * sum (-1)^(k-1) * exp(-2 k² lambda²)`.
+pub fn kolmogorov_survival(lambda: f64) -> f64 {
+    if lambda < 0.2 {
+        return 1.0;
+    }
+
+    let mut sum = 0.0;
+    let mut sign = 1.0;
+
+    for k in 1..=100 {
+        let term = (-2.0 * (k * k) as f64 * lambda * lambda).exp();
+
+        sum += sign * term;
+        sign = -sign;
+
+        if term < EPSILON * sum.abs() {
+            break;
+        }
+    }
+
+    (2.0 * sum).clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod special_test {
+    use super::*;
+
+    fn assert_close(value: f64, expected: f64) {
+        assert!((value - expected).abs() < 1e-9, "{} should be close to {}", value, expected);
+    }
+
+    #[test]
+    fn test_gamma() {
+        assert_close(ln_gamma(1.0), 0.0);
+        assert_close(ln_gamma(5.0), 24f64.ln());
+        assert_close(ln_gamma(0.5), std::f64::consts::PI.sqrt().ln());
+        assert_close(ln_gamma(0.1), 2.252_712_651_734_206);
+        assert_close(gamma_p(1.0, 2.0), 1.0 - (-2f64).exp());
+        assert_close(gamma_p(3.0, 10.0) + gamma_q(3.0, 10.0), 1.0);
+    }
+
+    #[test]
+    fn test_beta() {
+        assert_close(beta_regularized(1.0, 1.0, 0.3), 0.3);
+        assert_close(beta_regularized(2.0, 3.0, 0.4), 0.5248);
+        assert_close(beta_regularized(2.0, 3.0, 0.9), 0.9963);
+    }
+
+    #[test]
+    fn test_normal() {
+        assert_close(erfc(1.0), 0.157_299_207_050_285_1);
+        assert_close(erfc(3.0), 2.209_049_699_858_544e-5);
+        assert_close(normal_cdf(1.959_963_984_540_054), 0.975);
+        assert_close(normal_p_value(-1.959_963_984_540_054), 0.05);
+        assert_close(normal_quantile(0.975), 1.959_963_984_540_054);
+        assert_close(normal_quantile(0.001), -3.090_232_306_167_813);
+        assert_close(normal_quantile(0.5), 0.0);
+        assert!(normal_quantile(1.5).is_nan());
+    }
+
+    #[test]
+    fn test_student_t() {
+        assert_close(student_t_cdf(0.0, 5.0), 0.5);
+        // t distribution with one degree of freedom is the Cauchy distribution
+        assert_close(student_t_cdf(1.0, 1.0), 0.75);
+        assert_close(student_t_p_value(2.570_581_835_636_314, 5.0), 0.05);
+    }
+
+    #[test]
+    fn test_tails() {
+        assert_close(chi_squared_survival(3.841_458_820_694_124, 1.0), 0.05);
+        // Chi-square with two degrees of freedom is exponential with mean 2
+        assert_close(chi_squared_survival(4.0, 2.0), (-2f64).exp());
+        assert_close(f_survival(0.0, 3.0, 10.0), 1.0);
+        // F(1, d) is the square of t(d)
+        assert_close(f_survival(4.0, 1.0, 7.0), student_t_p_value(2.0, 7.0));
+        assert_close(kolmogorov_survival(1.358_098_639_322_55), 0.05);
+        assert_close(kolmogorov_survival(0.1), 1.0);
+    }
+}
@@ -0,0 +1,85 @@
+pub mod basic;
+pub mod format;
+pub mod pattern;
+use super::connector_manager::table::{Column, Table};
+use super::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use super::math::broadcast;
+use super::statistics::get_number;
+
+/// Type shared by the values that aren't `Null`, `ANY` when they differ.
+pub fn get_common_type(values: &[ConnectorType]) -> i32 {
+    let mut types = values.iter().filter(|value| !value.is_null()).map(|value| value.get_type());
+
+    match types.next() {
+        Some(first) if types.all(|other| other == first) => first,
+        _ => ConnectorTypeId::ANY,
+    }
+}
+
+/// Applies an operation like `broadcast`, element-wise on lists.\
+/// When the first operand is a table, the operation is applied to each value of the columns of the `column_types`,
+/// or to the values of those types in columns of any type, with the other operands. Other columns are kept as they are.
+pub fn broadcast_columns<F>(operands: &[ConnectorType], column_types: &[i32], operation: &F) -> ConnectorType
+where
+    F: Fn(&[ConnectorType]) -> ConnectorType
+{
+    let table = match &operands[0] {
+        ConnectorType::Table(table) => table,
+        _ => return broadcast(operands, operation),
+    };
+
+    let columns = table.get_columns().iter().map(|column| {
+        let any = column.get_type() == ConnectorTypeId::ANY;
+
+        if !any && !column_types.contains(&column.get_type()) {
+            return column.clone();
+        }
+
+        let values: Vec<ConnectorType> = column.get_values().iter().map(|value| match any && !column_types.contains(&value.get_type()) {
+            true => value.clone(),
+            false => {
+                let mut cell = operands.to_vec();
+
+                cell[0] = value.clone();
+                broadcast(&cell, operation)
+            },
+        }).collect();
+
+        Column::new(&column.get_name(), get_common_type(&values), values)
+    }).collect();
+
+    ConnectorType::Table(Table::from_columns(columns).unwrap_or_default())
+}
+
+/// Reads a whole number such as a position or a count, `None` when it isn't a finite number.
+pub fn get_integer(value: &ConnectorType) -> Option<i64> {
+    get_number(value).filter(|value| value.is_finite()).map(|value| value as i64)
+}
+
+#[cfg(test)]
+mod text_test {
+    use super::*;
+    use crate::helper::testing::as_table;
+    use crate::helper::types::Null;
+
+    #[test]
+    fn test_broadcast_columns() {
+        let table = Table::from_columns(vec![
+            Column::new("Name", ConnectorTypeId::STRING, vec!["a", "b"]),
+            Column::new("Count", ConnectorTypeId::INT, vec![1, 2]),
+            Column::new("Mixed", ConnectorTypeId::ANY, vec![ConnectorType::new("c"), ConnectorType::new(3)]),
+        ]).unwrap();
+        let twice = |operands: &[ConnectorType]| match &operands[0] {
+            ConnectorType::String(value) => ConnectorType::String(value.repeat(2)),
+            _ => ConnectorType::Null(Null::new()),
+        };
+
+        let result = as_table(&broadcast_columns(&[ConnectorType::Table(table)], &[ConnectorTypeId::STRING], &twice));
+
+        assert_eq!(result.get_column("Name").unwrap().get_values(), [ConnectorType::new("aa"), ConnectorType::new("bb")]);
+        assert_eq!(result.get_column("Name").unwrap().get_type(), ConnectorTypeId::STRING);
+        assert_eq!(result.get_column("Count").unwrap().get_values(), [ConnectorType::new(1), ConnectorType::new(2)]);
+        assert_eq!(result.get_column("Mixed").unwrap().get_values(), [ConnectorType::new("cc"), ConnectorType::new(3)]);
+        assert_eq!(broadcast_columns(&[ConnectorType::new(vec!["x"])], &[], &twice), ConnectorType::new(vec!["xx"]));
+    }
+}
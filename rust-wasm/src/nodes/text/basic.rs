@@ -0,0 +1,537 @@
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+use super::{broadcast_columns, get_integer};
+
+const STRING: [i32; 1] = [ConnectorTypeId::STRING];
+
+/// Widest text a pad can produce, in characters.
+pub const MAX_PAD_WIDTH: i64 = 1_000_000;
+
+/// Operations on a single string.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TextOperation {
+    Upper,
+    Lower,
+    Trim,
+    TrimStart,
+    TrimEnd,
+    /// Number of characters.
+    Length,
+}
+
+impl TextOperation {
+    /// Applies the operation to a string, other values give `Null`.
+    pub fn apply(&self, value: &ConnectorType) -> ConnectorType {
+        let text = match value {
+            ConnectorType::String(text) => text,
+            _ => return ConnectorType::Null(Null::new()),
+        };
+
+        match self {
+            TextOperation::Upper => ConnectorType::String(text.to_uppercase()),
+            TextOperation::Lower => ConnectorType::String(text.to_lowercase()),
+            TextOperation::Trim => ConnectorType::String(text.trim().to_string()),
+            TextOperation::TrimStart => ConnectorType::String(text.trim_start().to_string()),
+            TextOperation::TrimEnd => ConnectorType::String(text.trim_end().to_string()),
+            TextOperation::Length => ConnectorType::Integer(text.chars().count() as i32),
+        }
+    }
+}
+
+/// Where a string is tested for a pattern.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Search {
+    Contains,
+    StartsWith,
+    EndsWith,
+}
+
+impl Search {
+    pub fn apply(&self, text: &str, pattern: &str, ignore_case: bool) -> bool {
+        let (text, pattern) = match ignore_case {
+            true => (text.to_lowercase(), pattern.to_lowercase()),
+            false => (text.to_string(), pattern.to_string()),
+        };
+
+        match self {
+            Search::Contains => text.contains(&pattern),
+            Search::StartsWith => text.starts_with(&pattern),
+            Search::EndsWith => text.ends_with(&pattern),
+        }
+    }
+}
+
+/// Side of a string padding is added to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Side {
+    Start,
+    End,
+}
+
+/// Characters from a 0-based `start`, to the end without `length`. A negative start counts from the end.
+pub fn substring(text: &str, start: i64, length: Option<i64>) -> String {
+    let characters: Vec<char> = text.chars().collect();
+    let count = characters.len() as i64;
+    let start = if start < 0 { (count + start).max(0) } else { start.min(count) };
+    let end = match length {
+        Some(length) => start.saturating_add(length.max(0)).min(count),
+        None => count,
+    };
+
+    characters[start as usize..end as usize].iter().collect()
+}
+
+/// Pads a string to `width` characters by repeating `fill`, longer strings are kept as they are.
+pub fn pad(text: &str, width: usize, fill: &str, side: Side) -> String {
+    let missing = width.saturating_sub(text.chars().count());
+
+    if fill.is_empty() || missing == 0 {
+        return text.to_string();
+    }
+
+    let padding: String = fill.chars().cycle().take(missing).collect();
+
+    match side {
+        Side::Start => format!("{}{}", padding, text),
+        Side::End => format!("{}{}", text, padding),
+    }
+}
+
+/// Splits a string on a separator, into characters when the separator is empty.
+pub fn split(text: &str, separator: &str) -> Vec<ConnectorType> {
+    match separator.is_empty() {
+        true => text.chars().map(|character| ConnectorType::String(character.to_string())).collect(),
+        false => text.split(separator).map(|part| ConnectorType::String(part.to_string())).collect(),
+    }
+}
+
+fn get_text_port() -> Port {
+    Port::new("Text", "", ConnectorTypeId::ANY)
+}
+
+fn get_text(value: &ConnectorType) -> Option<&str> {
+    match value {
+        ConnectorType::String(text) => Some(text),
+        _ => None,
+    }
+}
+
+/// Node for a `TextOperation`, applied to each string of lists and of the string columns of tables.
+pub struct TextNode {
+    operation: TextOperation,
+}
+
+impl TextNode {
+    pub fn new(operation: TextOperation) -> TextNode {
+        TextNode {
+            operation,
+        }
+    }
+}
+
+impl Node for TextNode {
+    fn get_name(&self) -> String {
+        match self.operation {
+            TextOperation::TrimStart => "Trim Start".to_string(),
+            TextOperation::TrimEnd => "Trim End".to_string(),
+            operation => format!("{:?}", operation),
+        }
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![get_text_port()]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Result", "", ConnectorTypeId::ANY)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        vec![broadcast_columns(&context.get_inputs(), &STRING, &|operands| self.operation.apply(&operands[0]))]
+    }
+}
+
+/// Joins `A` and `B` with a `Separator` in between, numbers and other values are written as text.
+pub struct ConcatNode {}
+
+impl ConcatNode {
+    pub fn new() -> ConcatNode {
+        ConcatNode {}
+    }
+}
+
+impl Default for ConcatNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for ConcatNode {
+    fn get_name(&self) -> String {
+        "Concat".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("A", "", ConnectorTypeId::ANY),
+            Port::new("B", "", ConnectorTypeId::ANY),
+            Port::new("Separator", "", ConnectorTypeId::STRING),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Result", "", ConnectorTypeId::ANY)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        vec![broadcast_columns(&context.get_inputs(), &STRING, &|operands| match operands {
+            [a, b, _] if a.is_null() || b.is_null() => ConnectorType::Null(Null::new()),
+            [a, b, separator] => ConnectorType::String(format!("{}{}{}", a, separator, b)),
+            _ => ConnectorType::Null(Null::new()),
+        })]
+    }
+}
+
+/// Splits `Text` into a list of strings on a `Separator`, into characters when it is empty.
+pub struct SplitNode {}
+
+impl SplitNode {
+    pub fn new() -> SplitNode {
+        SplitNode {}
+    }
+}
+
+impl Default for SplitNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for SplitNode {
+    fn get_name(&self) -> String {
+        "Split".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            get_text_port(),
+            Port::new("Separator", ",", ConnectorTypeId::STRING),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Parts", Vec::<ConnectorType>::new(), ConnectorTypeId::ANY)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        vec![broadcast_columns(&context.get_inputs(), &STRING, &|operands| match (get_text(&operands[0]), get_text(&operands[1])) {
+            (Some(text), Some(separator)) => ConnectorType::List(split(text, separator)),
+            _ => ConnectorType::Null(Null::new()),
+        })]
+    }
+}
+
+/// Joins the `Values` of a list into a string with a `Separator`, `Null` values are left out.
+pub struct JoinTextNode {}
+
+impl JoinTextNode {
+    pub fn new() -> JoinTextNode {
+        JoinTextNode {}
+    }
+}
+
+impl Default for JoinTextNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for JoinTextNode {
+    fn get_name(&self) -> String {
+        "Join Text".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Values", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+            Port::new("Separator", ", ", ConnectorTypeId::STRING),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Text", "", ConnectorTypeId::STRING)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let values = match context.get_input(0) {
+            ConnectorType::List(values) => values,
+            _ => return vec![ConnectorType::Null(Null::new())],
+        };
+
+        let parts: Vec<String> = values.iter().filter(|value| !value.is_null()).map(|value| value.to_string()).collect();
+
+        vec![ConnectorType::String(parts.join(&context.get_input(1).to_string()))]
+    }
+}
+
+/// Characters of `Text` from `Start`, counted from 0 and from the end when negative, up to `Length` characters.
+pub struct SubstringNode {}
+
+impl SubstringNode {
+    pub fn new() -> SubstringNode {
+        SubstringNode {}
+    }
+}
+
+impl Default for SubstringNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for SubstringNode {
+    fn get_name(&self) -> String {
+        "Substring".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            get_text_port(),
+            Port::new("Start", 0, ConnectorTypeId::INT),
+            Port::new("Length", Null::new(), ConnectorTypeId::ANY),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Result", "", ConnectorTypeId::ANY)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        vec![broadcast_columns(&context.get_inputs(), &STRING, &|operands| {
+            let length = match &operands[2] {
+                ConnectorType::Null(_) => None,
+                length => match get_integer(length) {
+                    Some(length) => Some(length),
+                    None => return ConnectorType::Null(Null::new()),
+                },
+            };
+
+            match (get_text(&operands[0]), get_integer(&operands[1])) {
+                (Some(text), Some(start)) => ConnectorType::String(substring(text, start, length)),
+                _ => ConnectorType::Null(Null::new()),
+            }
+        })]
+    }
+}
+
+/// Replaces every occurrence of `Pattern` in `Text` with `Replacement`.
+pub struct ReplaceNode {}
+
+impl ReplaceNode {
+    pub fn new() -> ReplaceNode {
+        ReplaceNode {}
+    }
+}
+
+impl Default for ReplaceNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for ReplaceNode {
+    fn get_name(&self) -> String {
+        "Replace".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            get_text_port(),
+            Port::new("Pattern", "", ConnectorTypeId::STRING),
+            Port::new("Replacement", "", ConnectorTypeId::STRING),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Result", "", ConnectorTypeId::ANY)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        vec![broadcast_columns(&context.get_inputs(), &STRING, &|operands| match (get_text(&operands[0]), get_text(&operands[1]), get_text(&operands[2])) {
+            (Some(text), Some(""), Some(_)) => ConnectorType::String(text.to_string()),
+            (Some(text), Some(pattern), Some(replacement)) => ConnectorType::String(text.replace(pattern, replacement)),
+            _ => ConnectorType::Null(Null::new()),
+        })]
+    }
+}
+
+/// Whether `Text` contains, starts or ends with `Pattern`.
+pub struct SearchNode {
+    search: Search,
+}
+
+impl SearchNode {
+    pub fn new(search: Search) -> SearchNode {
+        SearchNode {
+            search,
+        }
+    }
+}
+
+impl Node for SearchNode {
+    fn get_name(&self) -> String {
+        match self.search {
+            Search::Contains => "Contains".to_string(),
+            Search::StartsWith => "Starts With".to_string(),
+            Search::EndsWith => "Ends With".to_string(),
+        }
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            get_text_port(),
+            Port::new("Pattern", "", ConnectorTypeId::STRING),
+            Port::new("Ignore Case", false, ConnectorTypeId::BOOL),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Result", false, ConnectorTypeId::ANY)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        vec![broadcast_columns(&context.get_inputs(), &STRING, &|operands| match (get_text(&operands[0]), get_text(&operands[1])) {
+            (Some(text), Some(pattern)) => ConnectorType::Boolean(self.search.apply(text, pattern, operands[2] == ConnectorType::Boolean(true))),
+            _ => ConnectorType::Null(Null::new()),
+        })]
+    }
+}
+
+/// Pads `Text` to `Width` characters with `Fill`, at the start or the end.\
+/// Widths above `MAX_PAD_WIDTH` give `Null`.
+pub struct PadNode {
+    side: Side,
+}
+
+impl PadNode {
+    pub fn new(side: Side) -> PadNode {
+        PadNode {
+            side,
+        }
+    }
+}
+
+impl Node for PadNode {
+    fn get_name(&self) -> String {
+        format!("Pad {:?}", self.side)
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            get_text_port(),
+            Port::new("Width", 10, ConnectorTypeId::INT),
+            Port::new("Fill", " ", ConnectorTypeId::STRING),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Result", "", ConnectorTypeId::ANY)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        vec![broadcast_columns(&context.get_inputs(), &STRING, &|operands| match (get_text(&operands[0]), get_integer(&operands[1]), get_text(&operands[2])) {
+            (Some(text), Some(width), Some(fill)) if width <= MAX_PAD_WIDTH => ConnectorType::String(pad(text, width.max(0) as usize, fill, self.side)),
+            _ => ConnectorType::Null(Null::new()),
+        })]
+    }
+}
+
+#[cfg(test)]
+mod basic_test {
+    use super::*;
+    use crate::helper::testing::null;
+    use crate::nodes::node_manager::Manager;
+
+    #[test]
+    fn test_functions() {
+        assert_eq!(TextOperation::Upper.apply(&ConnectorType::new("straße")), "STRASSE");
+        assert_eq!(TextOperation::TrimEnd.apply(&ConnectorType::new(" a ")), " a");
+        assert_eq!(TextOperation::Length.apply(&ConnectorType::new("héllo")), 5);
+        assert_eq!(TextOperation::Lower.apply(&ConnectorType::new(1)), Null::new());
+        assert_eq!(substring("formula", -3, None), "ula");
+        assert_eq!(substring("formula", 2, Some(100)), "rmula");
+        assert_eq!(substring("abc", 1, Some(i64::MAX)), "bc");
+        assert_eq!(substring("abc", i64::MIN, Some(1)), "a");
+        assert_eq!(pad("7", 3, "0", Side::Start), "007");
+        assert_eq!(pad("ab", 5, "-=", Side::End), "ab-=-");
+        assert_eq!(pad("long", 2, " ", Side::End), "long");
+        assert_eq!(split("a,b,,c", ","), vec!["a", "b", "", "c"]);
+        assert_eq!(split("ab", ""), vec!["a", "b"]);
+        assert!(Search::StartsWith.apply("Hello", "he", true));
+        assert!(!Search::EndsWith.apply("Hello", "LO", false));
+    }
+
+    #[test]
+    fn test_nodes() {
+        let mut manager = Manager::new();
+        let trim = manager.add_node(TextNode::new(TextOperation::Trim));
+        let concat = manager.add_node(ConcatNode::new());
+        let split = manager.add_node(SplitNode::new());
+        let join = manager.add_node(JoinTextNode::new());
+
+        manager.set_input_value(trim, 0, vec![" a ", "b "]);
+        manager.connect(trim, 0, concat, 0);
+        manager.set_input_value(concat, 1, vec![1, 2]);
+        manager.set_input_value(concat, 2, "-");
+
+        assert_eq!(manager.evaluate(concat)[0], ConnectorType::new(vec!["a-1", "b-2"]));
+
+        manager.set_input_value(split, 0, "x;y");
+        manager.set_input_value(split, 1, ";");
+        manager.connect(split, 0, join, 0);
+        manager.set_input_value(join, 1, "+");
+
+        assert_eq!(manager.evaluate(join)[0], "x+y");
+
+        let pad = manager.add_node(PadNode::new(Side::Start));
+
+        manager.set_input_value(pad, 0, vec!["7", "42"]);
+        manager.set_input_value(pad, 1, 3);
+        manager.set_input_value(pad, 2, "0");
+
+        assert_eq!(manager.evaluate(pad)[0], ConnectorType::new(vec!["007", "042"]));
+
+        manager.set_input_value(pad, 1, i32::MAX);
+
+        assert_eq!(manager.evaluate(pad)[0], ConnectorType::List(vec![null(), null()]));
+    }
+
+    #[test]
+    fn test_search_nodes() {
+        let mut manager = Manager::new();
+        let contains = manager.add_node(SearchNode::new(Search::Contains));
+        let replace = manager.add_node(ReplaceNode::new());
+        let substring = manager.add_node(SubstringNode::new());
+
+        manager.set_input_value(contains, 0, vec!["Apple", "banana", "Cherry"]);
+        manager.set_input_value(contains, 1, "AN");
+        manager.set_input_value(contains, 2, true);
+
+        assert_eq!(manager.evaluate(contains)[0], ConnectorType::new(vec![false, true, false]));
+
+        manager.set_input_value(replace, 0, "a-b-c");
+        manager.set_input_value(replace, 1, "-");
+        manager.set_input_value(replace, 2, "/");
+        manager.connect(replace, 0, substring, 0);
+        manager.set_input_value(substring, 1, 2);
+        manager.set_input_value(substring, 2, 2);
+
+        assert_eq!(manager.evaluate(substring)[0], "b/");
+
+        manager.set_input_value(substring, 2, 1e30);
+
+        assert_eq!(manager.evaluate(substring)[0], "b/c");
+    }
+}
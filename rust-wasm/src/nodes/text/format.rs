@@ -0,0 +1,186 @@
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::datetime::DateTime;
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+use crate::nodes::statistics::get_number;
+use crate::nodes::timeseries::get_timestamp;
+use super::broadcast_columns;
+
+/// Formats a number with a spreadsheet style pattern such as `#,##0.00`, `0.0%` or `$#,##0`.\
+/// In the digits, `0` is a digit always written and `#` a digit written when needed, a `,` before the decimal point groups thousands.
+/// Text around the digits is copied, and a `%` in it multiplies the number by 100.
+/// Returns `None` when the pattern has no digits or the number isn't finite.
+pub fn format_number(value: f64, pattern: &str) -> Option<String> {
+    let start = pattern.find(['0', '#'])?;
+    let end = pattern.rfind(['0', '#'])? + 1;
+    let (prefix, digits, suffix) = (&pattern[..start], &pattern[start..end], &pattern[end..]);
+
+    if !value.is_finite() {
+        return None;
+    }
+
+    let value = if prefix.contains('%') || suffix.contains('%') { value * 100.0 } else { value };
+    let (integer_pattern, fraction_pattern) = digits.split_once('.').unwrap_or((digits, ""));
+    let minimum_integer = integer_pattern.matches('0').count();
+    let minimum_fraction = fraction_pattern.matches('0').count();
+    let maximum_fraction = minimum_fraction + fraction_pattern.matches('#').count();
+
+    // Halves round away from zero, as in spreadsheets, where formatting alone would round them to even
+    let factor = 10f64.powi(maximum_fraction as i32);
+    let rounded = format!("{:.*}", maximum_fraction, (value.abs() * factor).round() / factor);
+    let (integer, fraction) = rounded.split_once('.').unwrap_or((&rounded, ""));
+    let fraction = fraction.trim_end_matches('0');
+    let fraction = format!("{:0<width$}", fraction, width = minimum_fraction);
+    let integer = match integer.trim_start_matches('0') {
+        trimmed if trimmed.len() < minimum_integer => format!("{:0>width$}", trimmed, width = minimum_integer),
+        trimmed => trimmed.to_string(),
+    };
+
+    // Groups of three digits from the end, the first group can be shorter
+    let integer = match integer_pattern.contains(',') {
+        true => {
+            let head = integer.len() % 3;
+            let mut groups: Vec<&str> = Vec::new();
+
+            if head > 0 {
+                groups.push(&integer[..head]);
+            }
+
+            groups.extend((head..integer.len()).step_by(3).map(|index| &integer[index..index + 3]));
+            groups.join(",")
+        },
+        false => integer,
+    };
+
+    let negative = value < 0.0 && integer.chars().chain(fraction.chars()).any(|digit| digit.is_ascii_digit() && digit != '0');
+    let sign = if negative { "-" } else { "" };
+
+    match fraction.is_empty() {
+        true => Some(format!("{}{}{}{}", sign, prefix, integer, suffix)),
+        false => Some(format!("{}{}{}.{}{}", sign, prefix, integer, fraction, suffix)),
+    }
+}
+
+/// Writes each number of `Value` as text with a `Format` pattern such as `#,##0.00` or `0.0%`.
+pub struct FormatNumberNode {}
+
+impl FormatNumberNode {
+    pub fn new() -> FormatNumberNode {
+        FormatNumberNode {}
+    }
+}
+
+impl Default for FormatNumberNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for FormatNumberNode {
+    fn get_name(&self) -> String {
+        "Format Number".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Value", 0, ConnectorTypeId::ANY),
+            Port::new("Format", "#,##0.00", ConnectorTypeId::STRING),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Text", "", ConnectorTypeId::ANY)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        vec![broadcast_columns(&context.get_inputs(), &[ConnectorTypeId::INT, ConnectorTypeId::FLOAT], &|operands| {
+            let formatted = match (&operands[0], &operands[1]) {
+                (ConnectorType::Integer(_) | ConnectorType::Float(_), ConnectorType::String(pattern)) => get_number(&operands[0]).and_then(|value| format_number(value, pattern)),
+                _ => None,
+            };
+
+            match formatted {
+                Some(text) => ConnectorType::String(text),
+                None => ConnectorType::Null(Null::new()),
+            }
+        })]
+    }
+}
+
+/// Writes each date of `Value` as text with a `strftime` style `Format` such as `%Y-%m-%d %H:%M`.\
+/// Strings are parsed as ISO 8601 and numbers read as seconds since the epoch.
+pub struct FormatDateNode {}
+
+impl FormatDateNode {
+    pub fn new() -> FormatDateNode {
+        FormatDateNode {}
+    }
+}
+
+impl Default for FormatDateNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for FormatDateNode {
+    fn get_name(&self) -> String {
+        "Format Date".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Value", DateTime::default(), ConnectorTypeId::ANY),
+            Port::new("Format", "%Y-%m-%d", ConnectorTypeId::STRING),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Text", "", ConnectorTypeId::ANY)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        vec![broadcast_columns(&context.get_inputs(), &[ConnectorTypeId::DATETIME], &|operands| match (get_timestamp(&operands[0]), &operands[1]) {
+            (Some(timestamp), ConnectorType::String(pattern)) => ConnectorType::String(DateTime::new(timestamp).format(pattern)),
+            _ => ConnectorType::Null(Null::new()),
+        })]
+    }
+}
+
+#[cfg(test)]
+mod format_test {
+    use super::*;
+    use crate::nodes::node_manager::Manager;
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number(1234567.891, "#,##0.00"), Some("1,234,567.89".to_string()));
+        assert_eq!(format_number(-1234.5, "$#,##0"), Some("-$1,235".to_string()));
+        assert_eq!(format_number(0.1234, "0.0%"), Some("12.3%".to_string()));
+        assert_eq!(format_number(7.0, "000"), Some("007".to_string()));
+        assert_eq!(format_number(2.5, "0.##"), Some("2.5".to_string()));
+        assert_eq!(format_number(2.0, "0.##"), Some("2".to_string()));
+        assert_eq!(format_number(0.25, "#.00"), Some(".25".to_string()));
+        assert_eq!(format_number(-0.001, "0.00"), Some("0.00".to_string()));
+        assert_eq!(format_number(123.0, "#,##0"), Some("123".to_string()));
+        assert_eq!(format_number(1.0, "text"), None);
+        assert_eq!(format_number(f64::NAN, "0"), None);
+    }
+
+    #[test]
+    fn test_nodes() {
+        let mut manager = Manager::new();
+        let number = manager.add_node(FormatNumberNode::new());
+        let date = manager.add_node(FormatDateNode::new());
+
+        manager.set_input_value(number, 0, vec![ConnectorType::new(1500), ConnectorType::new("x")]);
+
+        assert_eq!(manager.evaluate(number)[0], ConnectorType::new(vec![ConnectorType::new("1,500.00"), ConnectorType::Null(Null::new())]));
+
+        manager.set_input_value(date, 0, "2024-03-01T15:04:05Z");
+        manager.set_input_value(date, 1, "%d %b %Y");
+
+        assert_eq!(manager.evaluate(date)[0], "01 Mar 2024");
+    }
+}
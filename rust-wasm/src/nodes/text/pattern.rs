@@ -0,0 +1,144 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use regex::Regex;
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+use super::{broadcast_columns, get_integer};
+
+const STRING: [i32; 1] = [ConnectorTypeId::STRING];
+
+/// Operations with a regular expression, in the syntax of the `regex` crate.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RegexOperation {
+    /// Whether the pattern matches somewhere in the text.
+    Match,
+    /// Text of a group of the first match, by index or by name, 0 for the whole match. `Null` without match.
+    Extract,
+    /// Replaces every match, the replacement can refer to groups as `$1` or `${name}`.
+    Replace,
+}
+
+impl RegexOperation {
+    /// Applies the operation to a text, with the group or replacement as `argument`.
+    pub fn apply(&self, regex: &Regex, text: &str, argument: &ConnectorType) -> ConnectorType {
+        match self {
+            RegexOperation::Match => ConnectorType::Boolean(regex.is_match(text)),
+            RegexOperation::Extract => {
+                let captures = regex.captures(text);
+                let group = match (&captures, argument) {
+                    (Some(captures), ConnectorType::String(name)) => captures.name(name),
+                    (Some(captures), index) => get_integer(index).and_then(|index| captures.get(index.max(0) as usize)),
+                    (None, _) => None,
+                };
+
+                match group {
+                    Some(group) => ConnectorType::String(group.as_str().to_string()),
+                    None => ConnectorType::Null(Null::new()),
+                }
+            },
+            RegexOperation::Replace => ConnectorType::String(regex.replace_all(text, argument.to_string().as_str()).into_owned()),
+        }
+    }
+}
+
+/// Node for a `RegexOperation` on `Text`, applied to each string of lists and of the string columns of tables.\
+/// Invalid patterns give `Null`.
+pub struct RegexNode {
+    operation: RegexOperation,
+}
+
+impl RegexNode {
+    pub fn new(operation: RegexOperation) -> RegexNode {
+        RegexNode {
+            operation,
+        }
+    }
+}
+
+impl Node for RegexNode {
+    fn get_name(&self) -> String {
+        format!("Regex {:?}", self.operation)
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        let mut inputs = vec![
+            Port::new("Text", "", ConnectorTypeId::ANY),
+            Port::new("Pattern", "", ConnectorTypeId::STRING),
+        ];
+
+        match self.operation {
+            RegexOperation::Match => (),
+            RegexOperation::Extract => inputs.push(Port::new("Group", 0, ConnectorTypeId::ANY)),
+            RegexOperation::Replace => inputs.push(Port::new("Replacement", "", ConnectorTypeId::STRING)),
+        }
+
+        inputs
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Result", Null::new(), ConnectorTypeId::ANY)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let mut operands = context.get_inputs();
+
+        if operands.len() < 3 {
+            operands.push(ConnectorType::Null(Null::new()));
+        }
+
+        // Patterns are compiled once, even when applied to every value of a list
+        let compiled: RefCell<HashMap<String, Option<Regex>>> = RefCell::new(HashMap::new());
+
+        vec![broadcast_columns(&operands, &STRING, &|operands| {
+            let (text, pattern) = match (&operands[0], &operands[1]) {
+                (ConnectorType::String(text), ConnectorType::String(pattern)) => (text, pattern),
+                _ => return ConnectorType::Null(Null::new()),
+            };
+
+            let mut compiled = compiled.borrow_mut();
+
+            match compiled.entry(pattern.clone()).or_insert_with(|| Regex::new(pattern).ok()) {
+                Some(regex) => self.operation.apply(regex, text, &operands[2]),
+                None => ConnectorType::Null(Null::new()),
+            }
+        })]
+    }
+}
+
+#[cfg(test)]
+mod pattern_test {
+    use super::*;
+    use crate::nodes::node_manager::Manager;
+
+    #[test]
+    fn test_apply() {
+        let regex = Regex::new(r"(?P<user>\w+)@(\w+)\.com").unwrap();
+
+        assert_eq!(RegexOperation::Match.apply(&regex, "mail ada@example.com", &Null::new().into()), true);
+        assert_eq!(RegexOperation::Extract.apply(&regex, "mail ada@example.com", &ConnectorType::new(2)), "example");
+        assert_eq!(RegexOperation::Extract.apply(&regex, "mail ada@example.com", &ConnectorType::new("user")), "ada");
+        assert_eq!(RegexOperation::Extract.apply(&regex, "mail ada@example.com", &ConnectorType::new(5)), Null::new());
+        assert_eq!(RegexOperation::Extract.apply(&regex, "no mail", &ConnectorType::new(0)), Null::new());
+        assert_eq!(RegexOperation::Replace.apply(&regex, "ada@example.com", &ConnectorType::new("$2/${user}")), "example/ada");
+    }
+
+    #[test]
+    fn test_node() {
+        let mut manager = Manager::new();
+        let replace = manager.add_node(RegexNode::new(RegexOperation::Replace));
+        let matches = manager.add_node(RegexNode::new(RegexOperation::Match));
+
+        manager.set_input_value(replace, 0, vec!["a1b22", "c333"]);
+        manager.set_input_value(replace, 1, r"\d+");
+        manager.set_input_value(replace, 2, "#");
+
+        assert_eq!(manager.evaluate(replace)[0], ConnectorType::new(vec!["a#b#", "c#"]));
+
+        manager.set_input_value(matches, 0, "abc");
+        manager.set_input_value(matches, 1, "(unclosed");
+
+        assert_eq!(manager.evaluate(matches)[0], Null::new());
+    }
+}
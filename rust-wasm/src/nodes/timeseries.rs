@@ -0,0 +1,44 @@
+pub mod resample;
+pub mod rolling;
+pub mod smoothing;
+pub mod transform;
+use super::connector_manager::datetime::{DateTime, parse_duration};
+use super::connector_manager::value::ConnectorType;
+use super::statistics::get_number;
+
+/// Reads a timestamp as milliseconds since the epoch.\
+/// Numbers are seconds since the epoch and strings are parsed as ISO 8601, anything else is `None`.
+pub fn get_timestamp(value: &ConnectorType) -> Option<i64> {
+    match value {
+        ConnectorType::DateTime(value) => Some(value.get_milliseconds()),
+        ConnectorType::String(value) => DateTime::parse(value).map(|value| value.get_milliseconds()),
+        ConnectorType::Integer(value) => Some(*value as i64 * 1000),
+        ConnectorType::Float(value) if value.is_finite() => Some((*value as f64 * 1000.0).round() as i64),
+        _ => None,
+    }
+}
+
+/// Reads a positive duration in milliseconds, from a string such as `1h30m` or from a number of seconds.
+pub fn get_duration(value: &ConnectorType) -> Option<i64> {
+    let duration = match value {
+        ConnectorType::String(value) => parse_duration(value)?,
+        ConnectorType::Integer(value) => *value as i64 * 1000,
+        ConnectorType::Float(value) if value.is_finite() => (*value as f64 * 1000.0).round() as i64,
+        _ => return None,
+    };
+
+    match duration > 0 {
+        true => Some(duration),
+        false => None,
+    }
+}
+
+/// Reads the values of a series, entries that are not numeric are `None`.
+pub fn get_series(values: &ConnectorType) -> Vec<Option<f64>> {
+    let values = match values {
+        ConnectorType::List(values) => values.as_slice(),
+        value => std::slice::from_ref(value),
+    };
+
+    values.iter().map(|value| get_number(value).filter(|value| !value.is_nan())).collect()
+}
@@ -0,0 +1,143 @@
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::datetime::DateTime;
+use crate::nodes::connector_manager::table::{Column, Table};
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+use crate::nodes::statistics::NullHandling;
+use crate::nodes::statistics::correlation::is_numeric;
+use crate::nodes::statistics::descriptive::Statistic;
+use super::{get_duration, get_timestamp};
+
+/// Most periods a resampled table can have.
+pub const MAX_PERIODS: i64 = 100_000;
+
+/// Groups the rows of a table into periods of `period` milliseconds and aggregates every numeric column.\
+/// Periods start at multiples of `period` from the epoch, every period from the first to the last row is kept,
+/// with the aggregate of no values when no row falls in it. Rows without a valid timestamp are left out.\
+/// Returns `None` when the time column is missing or there would be more than `MAX_PERIODS` periods.
+pub fn resample(table: &Table, time_column: &str, period: i64, statistic: Statistic, null_handling: NullHandling) -> Option<Table> {
+    let timestamps: Vec<Option<i64>> = table.get_column(time_column)?.get_values().iter().map(get_timestamp).collect();
+    let first = timestamps.iter().flatten().min().map(|timestamp| timestamp.div_euclid(period))?;
+    let last = timestamps.iter().flatten().max().map(|timestamp| timestamp.div_euclid(period))?;
+
+    let periods = match last.checked_sub(first) {
+        Some(span) if span < MAX_PERIODS => (span + 1) as usize,
+        _ => return None,
+    };
+    let starts: Vec<DateTime> = (first..=last).map(|index| DateTime::new(index.saturating_mul(period))).collect();
+    let mut columns = vec![Column::new(time_column, ConnectorTypeId::DATETIME, starts)];
+
+    for column in table.get_columns().iter().filter(|column| column.get_name() != time_column && is_numeric(column)) {
+        let mut groups: Vec<Vec<ConnectorType>> = vec![Vec::new(); periods];
+
+        for (timestamp, value) in timestamps.iter().zip(column.get_values()) {
+            if let Some(timestamp) = timestamp {
+                groups[(timestamp.div_euclid(period) - first) as usize].push(value.clone());
+            }
+        }
+
+        let values = groups.into_iter().map(|group| statistic.compute(&ConnectorType::List(group), null_handling)).collect();
+
+        columns.push(Column::new(&column.get_name(), ConnectorTypeId::ANY, values));
+    }
+
+    Table::from_columns(columns)
+}
+
+/// Resamples a timestamped table to a coarser `Frequency`, such as `1h` or `1d`, aggregating each numeric column with a `Statistic`.\
+/// The time column of the result holds the start of each period.
+pub struct ResampleNode {
+    statistic: Statistic,
+    null_handling: NullHandling,
+}
+
+impl ResampleNode {
+    pub fn new(statistic: Statistic, null_handling: NullHandling) -> ResampleNode {
+        ResampleNode {
+            statistic,
+            null_handling,
+        }
+    }
+}
+
+impl Node for ResampleNode {
+    fn get_name(&self) -> String {
+        "Resample".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Table", Table::new(), ConnectorTypeId::TABLE),
+            Port::new("Time Column", "Time", ConnectorTypeId::STRING),
+            Port::new("Frequency", "1d", ConnectorTypeId::ANY),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Resampled", Table::new(), ConnectorTypeId::TABLE)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let table = match context.get_input(0) {
+            ConnectorType::Table(table) => table,
+            _ => return vec![ConnectorType::Null(Null::new())],
+        };
+
+        let time_column = context.get_input(1).to_string();
+
+        let resampled = get_duration(&context.get_input(2))
+            .and_then(|period| resample(&table, &time_column, period, self.statistic, self.null_handling));
+
+        match resampled {
+            Some(resampled) => vec![ConnectorType::Table(resampled)],
+            None => vec![ConnectorType::Null(Null::new())],
+        }
+    }
+}
+
+#[cfg(test)]
+mod resample_test {
+    use super::*;
+    use crate::helper::testing::evaluate_table;
+    use crate::nodes::node_manager::Manager;
+
+    fn get_table() -> Table {
+        Table::from_columns(vec![
+            Column::new("Time", ConnectorTypeId::STRING, vec!["2024-01-01T00:10:00Z", "2024-01-01T00:50:00Z", "2024-01-01T02:30:00Z", "Unknown"]),
+            Column::new("Value", ConnectorTypeId::INT, vec![1, 3, 10, 100]),
+            Column::new("Label", ConnectorTypeId::STRING, vec!["a", "b", "c", "d"]),
+        ]).unwrap()
+    }
+
+    #[test]
+    fn test_resample() {
+        let table = resample(&get_table(), "Time", 3_600_000, Statistic::Sum, NullHandling::Skip).unwrap();
+        let hour = |text: &str| ConnectorType::new(DateTime::parse(text).unwrap());
+
+        assert_eq!(table.get_column_names(), vec!["Time", "Value"]);
+        assert_eq!(table.get_column("Time").unwrap().get_values(), [hour("2024-01-01T00:00:00Z"), hour("2024-01-01T01:00:00Z"), hour("2024-01-01T02:00:00Z")]);
+        assert_eq!(table.get_column("Value").unwrap().get_values()[0], Statistic::Sum.compute(&ConnectorType::new(vec![1, 3]), NullHandling::Skip));
+        assert_eq!(table.get_column("Value").unwrap().get_values()[2], Statistic::Sum.compute(&ConnectorType::new(vec![10]), NullHandling::Skip));
+        assert_eq!(resample(&get_table(), "Date", 3_600_000, Statistic::Sum, NullHandling::Skip), None);
+        assert_eq!(resample(&get_table(), "Time", 1, Statistic::Sum, NullHandling::Skip), None);
+
+        let extremes = Table::from_columns(vec![Column::new("Time", ConnectorTypeId::DATETIME, vec![DateTime::new(i64::MIN), DateTime::new(i64::MAX)])]).unwrap();
+
+        assert_eq!(resample(&extremes, "Time", 1, Statistic::Sum, NullHandling::Skip), None);
+    }
+
+    #[test]
+    fn test_node() {
+        let mut manager = Manager::new();
+        let node = manager.add_node(ResampleNode::new(Statistic::Mean, NullHandling::Skip));
+
+        manager.set_input_value(node, 0, get_table());
+        manager.set_input_value(node, 2, "1d");
+
+        let table = evaluate_table(&mut manager, node);
+
+        assert_eq!(table.get_row_count(), 1);
+        assert_eq!(table.get_column("Value").unwrap().get_values(), [ConnectorType::new(14.0 / 3.0)]);
+    }
+}
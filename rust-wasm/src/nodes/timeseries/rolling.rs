@@ -0,0 +1,280 @@
+use std::collections::VecDeque;
+use std::ops::Range;
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+use crate::nodes::statistics::{from_number, get_number};
+use super::{get_duration, get_series, get_timestamp};
+
+/// Aggregations computed over a rolling window.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RollingAggregation {
+    Count,
+    Sum,
+    Mean,
+    Min,
+    Max,
+    /// Sample standard deviation, with Bessel's correction.
+    StandardDeviation,
+}
+
+impl RollingAggregation {
+    pub fn get_name(&self) -> String {
+        match self {
+            RollingAggregation::StandardDeviation => "Standard Deviation".to_string(),
+            aggregation => format!("{:?}", aggregation),
+        }
+    }
+}
+
+/// How the rows of a rolling window are chosen.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Window {
+    /// The row and the rows before it, up to a number of rows.
+    Count,
+    /// The rows with a timestamp in the duration up to and including the timestamp of the row.
+    Time,
+}
+
+/// Trailing windows of `size` rows.
+pub fn get_count_windows(length: usize, size: usize) -> Vec<Range<usize>> {
+    (0..length).map(|end| (end + 1).saturating_sub(size)..end + 1).collect()
+}
+
+/// Windows of the rows in `(timestamp - duration, timestamp]`, `None` when the timestamps are not sorted.\
+/// A window starting before the earliest timestamp that can be held keeps all the rows before it.
+pub fn get_time_windows(timestamps: &[i64], duration: i64) -> Option<Vec<Range<usize>>> {
+    if timestamps.windows(2).any(|pair| pair[0] > pair[1]) {
+        return None;
+    }
+
+    let mut start = 0;
+
+    Some(timestamps.iter().enumerate().map(|(end, timestamp)| {
+        while timestamp.checked_sub(duration).is_some_and(|first| timestamps[start] <= first) {
+            start += 1;
+        }
+
+        start..end + 1
+    }).collect())
+}
+
+/// Aggregates the values in each window, windows must move forward.\
+/// Missing values are left out, windows with fewer than `min_periods` values are `None`.\
+/// Sums are kept while the window slides, and a monotonic queue tracks the extremes.
+pub fn rolling(values: &[Option<f64>], windows: &[Range<usize>], aggregation: RollingAggregation, min_periods: usize) -> Vec<Option<f64>> {
+    // Values are shifted by the first one, so the sum of squares doesn't lose the variance to cancellation
+    let shift = values.iter().flatten().next().copied().unwrap_or(0.0);
+    let (mut sum, mut squares, mut count) = (0.0, 0.0, 0);
+    let mut extremes: VecDeque<usize> = VecDeque::new();
+    let mut current = 0..0;
+
+    let keeps = |kept: f64, new: f64| match aggregation {
+        RollingAggregation::Min => kept < new,
+        _ => kept > new,
+    };
+
+    windows.iter().map(|window| {
+        while current.end < window.end {
+            if let Some(value) = values[current.end] {
+                sum += value - shift;
+                squares += (value - shift).powi(2);
+                count += 1;
+
+                while extremes.back().is_some_and(|last| !keeps(values[*last].unwrap(), value)) {
+                    extremes.pop_back();
+                }
+
+                extremes.push_back(current.end);
+            }
+
+            current.end += 1;
+        }
+
+        while current.start < window.start {
+            if let Some(value) = values[current.start] {
+                sum -= value - shift;
+                squares -= (value - shift).powi(2);
+                count -= 1;
+            }
+
+            if extremes.front() == Some(&current.start) {
+                extremes.pop_front();
+            }
+
+            current.start += 1;
+        }
+
+        if count < min_periods.max(1) {
+            return match aggregation {
+                RollingAggregation::Count if count >= min_periods => Some(0.0),
+                _ => None,
+            };
+        }
+
+        let n = count as f64;
+
+        match aggregation {
+            RollingAggregation::Count => Some(n),
+            RollingAggregation::Sum => Some(sum + shift * n),
+            RollingAggregation::Mean => Some(sum / n + shift),
+            RollingAggregation::Min | RollingAggregation::Max => extremes.front().and_then(|index| values[*index]),
+            RollingAggregation::StandardDeviation if count > 1 => Some(((squares - sum * sum / n) / (n - 1.0)).max(0.0).sqrt()),
+            RollingAggregation::StandardDeviation => None,
+        }
+    }).collect()
+}
+
+/// Aggregates `Values` over a rolling window ending at each row.\
+/// Count windows take `Window` rows, time windows take the rows of the `Timestamps` within the `Window` duration,
+/// such as `1h` or a number of seconds. Timestamps must be sorted.\
+/// Windows with fewer than `Min Periods` values are `Null`, zero requires full count windows and a single value in time windows.
+pub struct RollingNode {
+    aggregation: RollingAggregation,
+    window: Window,
+}
+
+impl RollingNode {
+    pub fn new(aggregation: RollingAggregation, window: Window) -> RollingNode {
+        RollingNode {
+            aggregation,
+            window,
+        }
+    }
+}
+
+impl Node for RollingNode {
+    fn get_name(&self) -> String {
+        format!("Rolling {}", self.aggregation.get_name())
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        let mut inputs = vec![Port::new("Values", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST)];
+
+        match self.window {
+            Window::Count => inputs.push(Port::new("Window", 3, ConnectorTypeId::INT)),
+            Window::Time => {
+                inputs.push(Port::new("Window", "1d", ConnectorTypeId::ANY));
+                inputs.push(Port::new("Timestamps", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST));
+            },
+        }
+
+        inputs.push(Port::new("Min Periods", 0, ConnectorTypeId::INT));
+        inputs
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Result", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let values = get_series(&context.get_input(0));
+        let min_periods = get_number(&context.get_input(context.get_input_count() - 1)).unwrap_or(0.0).max(0.0) as usize;
+
+        let (windows, min_periods) = match self.window {
+            Window::Count => {
+                let size = match get_number(&context.get_input(1)) {
+                    Some(size) if size >= 1.0 => size as usize,
+                    _ => return vec![ConnectorType::Null(Null::new())],
+                };
+
+                (get_count_windows(values.len(), size), if min_periods == 0 { size } else { min_periods })
+            },
+            Window::Time => {
+                let duration = get_duration(&context.get_input(1));
+                let timestamps: Option<Vec<i64>> = match context.get_input(2) {
+                    ConnectorType::List(timestamps) => timestamps.iter().map(get_timestamp).collect(),
+                    _ => None,
+                };
+
+                match (duration, timestamps) {
+                    (Some(duration), Some(timestamps)) if timestamps.len() == values.len() => match get_time_windows(&timestamps, duration) {
+                        Some(windows) => (windows, min_periods.max(1)),
+                        None => return vec![ConnectorType::Null(Null::new())],
+                    },
+                    _ => return vec![ConnectorType::Null(Null::new())],
+                }
+            },
+        };
+
+        let result = rolling(&values, &windows, self.aggregation, min_periods);
+
+        vec![ConnectorType::List(result.into_iter().map(from_number).collect())]
+    }
+}
+
+#[cfg(test)]
+mod rolling_test {
+    use super::*;
+    use crate::nodes::connector_manager::datetime::DateTime;
+    use crate::nodes::node_manager::Manager;
+
+    const VALUES: [Option<f64>; 6] = [Some(1.0), Some(3.0), None, Some(2.0), Some(8.0), Some(5.0)];
+
+    #[test]
+    fn test_windows() {
+        assert_eq!(get_count_windows(4, 2), vec![0..1, 0..2, 1..3, 2..4]);
+        assert_eq!(get_time_windows(&[0, 10, 15, 30], 15), Some(vec![0..1, 0..2, 1..3, 3..4]));
+        assert_eq!(get_time_windows(&[10, 0], 15), None);
+        assert_eq!(get_time_windows(&[-100, -50], i64::MAX), Some(vec![0..1, 0..2]));
+    }
+
+    #[test]
+    fn test_rolling() {
+        let windows = get_count_windows(VALUES.len(), 3);
+
+        assert_eq!(rolling(&VALUES, &windows, RollingAggregation::Sum, 2), vec![None, Some(4.0), Some(4.0), Some(5.0), Some(10.0), Some(15.0)]);
+        assert_eq!(rolling(&VALUES, &windows, RollingAggregation::Count, 0), vec![Some(1.0), Some(2.0), Some(2.0), Some(2.0), Some(2.0), Some(3.0)]);
+        assert_eq!(rolling(&VALUES, &windows, RollingAggregation::Min, 1), vec![Some(1.0), Some(1.0), Some(1.0), Some(2.0), Some(2.0), Some(2.0)]);
+        assert_eq!(rolling(&VALUES, &windows, RollingAggregation::Max, 1), vec![Some(1.0), Some(3.0), Some(3.0), Some(3.0), Some(8.0), Some(8.0)]);
+        assert_eq!(rolling(&VALUES, &windows, RollingAggregation::Mean, 3), vec![None, None, None, None, None, Some(5.0)]);
+
+        let deviations = rolling(&VALUES, &windows, RollingAggregation::StandardDeviation, 1);
+
+        assert_eq!(deviations[0], None);
+        assert!((deviations[5].unwrap() - 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_count_node() {
+        let mut manager = Manager::new();
+        let node = manager.add_node(RollingNode::new(RollingAggregation::Mean, Window::Count));
+
+        manager.set_input_value(node, 0, vec![1, 2, 3, 4]);
+        manager.set_input_value(node, 1, 2);
+
+        assert_eq!(manager.evaluate(node)[0], ConnectorType::new(vec![ConnectorType::Null(Null::new()), ConnectorType::new(1.5), ConnectorType::new(2.5), ConnectorType::new(3.5)]));
+
+        manager.set_input_value(node, 2, 1);
+
+        assert_eq!(manager.evaluate(node)[0], ConnectorType::new(vec![1.0, 1.5, 2.5, 3.5]));
+    }
+
+    #[test]
+    fn test_time_node() {
+        let mut manager = Manager::new();
+        let node = manager.add_node(RollingNode::new(RollingAggregation::Sum, Window::Time));
+        let timestamps = vec![
+            DateTime::parse("2024-01-01T00:00:00Z").unwrap(),
+            DateTime::parse("2024-01-01T00:30:00Z").unwrap(),
+            DateTime::parse("2024-01-01T01:00:00Z").unwrap(),
+            DateTime::parse("2024-01-01T03:00:00Z").unwrap(),
+        ];
+
+        manager.set_input_value(node, 0, vec![1, 2, 3, 4]);
+        manager.set_input_value(node, 1, "1h");
+        manager.set_input_value(node, 2, timestamps.clone());
+
+        assert_eq!(manager.evaluate(node)[0], ConnectorType::new(vec![1.0, 3.0, 5.0, 4.0]));
+
+        manager.set_input_value(node, 2, timestamps.into_iter().rev().collect::<Vec<DateTime>>());
+
+        assert_eq!(manager.evaluate(node)[0], ConnectorType::List(Vec::new()));
+
+        manager.set_input_value(node, 1, "1e30");
+        manager.set_input_value(node, 2, vec![-100, -50, 0, 50]);
+
+        assert_eq!(manager.evaluate(node)[0], ConnectorType::new(vec![1.0, 3.0, 6.0, 10.0]));
+    }
+}
@@ -0,0 +1,454 @@
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::record::Record;
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+use crate::nodes::statistics::special::normal_quantile;
+use crate::nodes::statistics::{from_number, get_number};
+use super::get_series;
+
+/// How seasonal effects combine with the level and trend.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Seasonality {
+    Additive,
+    /// Seasonal effects scale with the level, the series must be positive.
+    Multiplicative,
+}
+
+/// Exponential smoothing models, by the components they track.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SmoothingModel {
+    /// Level only.
+    Simple,
+    /// Level and trend (double exponential smoothing).
+    Holt,
+    /// Level, trend and season (triple exponential smoothing).
+    HoltWinters(Seasonality),
+}
+
+impl SmoothingModel {
+    pub fn get_name(&self) -> String {
+        match self {
+            SmoothingModel::Simple => "Simple Exponential Smoothing".to_string(),
+            SmoothingModel::Holt => "Holt Smoothing".to_string(),
+            SmoothingModel::HoltWinters(seasonality) => format!("Holt-Winters {:?} Smoothing", seasonality),
+        }
+    }
+
+    /// Number of smoothing parameters: alpha, then beta for the trend and gamma for the season.
+    pub fn get_parameter_count(&self) -> usize {
+        match self {
+            SmoothingModel::Simple => 1,
+            SmoothingModel::Holt => 2,
+            SmoothingModel::HoltWinters(_) => 3,
+        }
+    }
+}
+
+/// Minimizes `function` with the Nelder–Mead simplex method, starting around `start`.
+fn minimize<F>(function: &F, start: &[f64]) -> Vec<f64>
+where
+    F: Fn(&[f64]) -> f64
+{
+    let dimensions = start.len();
+    let mut simplex: Vec<(Vec<f64>, f64)> = (0..=dimensions).map(|vertex| {
+        let mut point = start.to_vec();
+
+        if vertex > 0 {
+            point[vertex - 1] += if point[vertex - 1] > 0.0 { -0.5 } else { 0.5 };
+        }
+
+        let value = function(&point);
+
+        (point, value)
+    }).collect();
+
+    for _ in 0..200 * dimensions {
+        simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+        if (simplex[dimensions].1 - simplex[0].1).abs() <= 1e-10 * (1.0 + simplex[0].1.abs()) {
+            break;
+        }
+
+        let centroid: Vec<f64> = (0..dimensions).map(|axis| simplex[..dimensions].iter().map(|vertex| vertex.0[axis]).sum::<f64>() / dimensions as f64).collect();
+        let toward = |factor: f64| -> Vec<f64> { centroid.iter().zip(&simplex[dimensions].0).map(|(center, worst)| center + factor * (worst - center)).collect() };
+
+        let reflected = toward(-1.0);
+        let reflected_value = function(&reflected);
+
+        if reflected_value < simplex[0].1 {
+            let expanded = toward(-2.0);
+            let expanded_value = function(&expanded);
+
+            simplex[dimensions] = if expanded_value < reflected_value { (expanded, expanded_value) } else { (reflected, reflected_value) };
+        } else if reflected_value < simplex[dimensions - 1].1 {
+            simplex[dimensions] = (reflected, reflected_value);
+        } else {
+            let contracted = toward(0.5);
+            let contracted_value = function(&contracted);
+
+            if contracted_value < simplex[dimensions].1 {
+                simplex[dimensions] = (contracted, contracted_value);
+            } else {
+                // Shrink toward the best vertex
+                let best = simplex[0].0.clone();
+
+                for vertex in simplex.iter_mut().skip(1) {
+                    vertex.0 = best.iter().zip(&vertex.0).map(|(best, point)| best + 0.5 * (point - best)).collect();
+                    vertex.1 = function(&vertex.0);
+                }
+            }
+        }
+    }
+
+    simplex.into_iter().min_by(|a, b| a.1.total_cmp(&b.1)).map(|vertex| vertex.0).unwrap_or_default()
+}
+
+fn logistic(value: f64) -> f64 {
+    1.0 / (1.0 + (-value).exp())
+}
+
+/// An exponential smoothing model fitted to a series.\
+/// Holt–Winters models start from the first two seasons: the trend is the change of their means,
+/// and the season the deviations of the first season from the trend line through its mean.
+/// Fitted values are one step ahead predictions, `None` for the rows used to start the model.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Smoothing {
+    model: SmoothingModel,
+    period: usize,
+    parameters: Vec<f64>,
+    level: f64,
+    trend: f64,
+    season: Vec<f64>,
+    fitted: Vec<Option<f64>>,
+    sse: f64,
+    length: usize,
+}
+
+impl Smoothing {
+    /// Runs the model with fixed `parameters`, alpha first then beta and gamma as needed, all within `[0, 1]`.\
+    /// `None` when the series is too short, or not positive for multiplicative seasons.
+    pub fn new(model: SmoothingModel, values: &[f64], period: usize, parameters: &[f64]) -> Option<Smoothing> {
+        let parameter = |index: usize| parameters.get(index).copied().unwrap_or(0.0);
+        let (alpha, beta, gamma) = (parameter(0), parameter(1), parameter(2));
+
+        if parameters.len() < model.get_parameter_count() || parameters.iter().any(|value| !(0.0..=1.0).contains(value)) {
+            return None;
+        }
+
+        let (mut level, mut trend, mut season, start) = match model {
+            SmoothingModel::Simple => (*values.first()?, 0.0, Vec::new(), 1),
+            SmoothingModel::Holt if values.len() >= 2 => (values[0], values[1] - values[0], Vec::new(), 1),
+            SmoothingModel::Holt => return None,
+            SmoothingModel::HoltWinters(seasonality) => {
+                if period < 2 || values.len() < 2 * period {
+                    return None;
+                }
+
+                if seasonality == Seasonality::Multiplicative && values.iter().any(|value| *value <= 0.0) {
+                    return None;
+                }
+
+                let first = values[..period].iter().sum::<f64>() / period as f64;
+                let second = values[period..2 * period].iter().sum::<f64>() / period as f64;
+                let trend = (second - first) / period as f64;
+                // The mean of a season is the level at its middle
+                let line = |time: usize| first + trend * (time as f64 - (period - 1) as f64 / 2.0);
+                let season = values[..period].iter().enumerate().map(|(time, value)| match seasonality {
+                    Seasonality::Additive => value - line(time),
+                    Seasonality::Multiplicative => value / line(time),
+                }).collect();
+
+                (line(period - 1), trend, season, period)
+            },
+        };
+
+        let mut fitted = vec![None; start.min(values.len())];
+        let mut sse = 0.0;
+
+        for (time, value) in values.iter().enumerate().skip(start) {
+            let slot = if season.is_empty() { 0 } else { time % period };
+            let (prediction, deseasoned) = match model {
+                SmoothingModel::HoltWinters(Seasonality::Additive) => (level + trend + season[slot], value - season[slot]),
+                SmoothingModel::HoltWinters(Seasonality::Multiplicative) => ((level + trend) * season[slot], value / season[slot]),
+                _ => (level + trend, *value),
+            };
+
+            let previous = level;
+
+            level = alpha * deseasoned + (1.0 - alpha) * (level + trend);
+
+            if model != SmoothingModel::Simple {
+                trend = beta * (level - previous) + (1.0 - beta) * trend;
+            }
+
+            match model {
+                SmoothingModel::HoltWinters(Seasonality::Additive) => season[slot] = gamma * (value - level) + (1.0 - gamma) * season[slot],
+                SmoothingModel::HoltWinters(Seasonality::Multiplicative) => season[slot] = gamma * value / level + (1.0 - gamma) * season[slot],
+                _ => (),
+            }
+
+            sse += (value - prediction).powi(2);
+            fitted.push(Some(prediction));
+        }
+
+        Some(Smoothing {
+            model,
+            period,
+            parameters: parameters[..model.get_parameter_count()].to_vec(),
+            level,
+            trend,
+            season,
+            fitted,
+            sse,
+            length: values.len(),
+        })
+    }
+
+    /// Fits the parameters that are not `fixed` by minimizing the sum of squared one step ahead errors.\
+    /// Parameters are searched on a logistic scale with the Nelder–Mead method, from a few starting points.
+    pub fn fit(model: SmoothingModel, values: &[f64], period: usize, fixed: &[Option<f64>]) -> Option<Smoothing> {
+        let count = model.get_parameter_count();
+        let free: Vec<usize> = (0..count).filter(|index| fixed.get(*index).copied().flatten().is_none()).collect();
+
+        let parameters = |point: &[f64]| -> Vec<f64> {
+            (0..count).map(|index| match free.iter().position(|free| *free == index) {
+                Some(position) => logistic(point[position]),
+                None => fixed[index].unwrap(),
+            }).collect()
+        };
+
+        let sse = |point: &[f64]| Smoothing::new(model, values, period, &parameters(point)).map(|smoothing| smoothing.sse).filter(|sse| sse.is_finite()).unwrap_or(f64::INFINITY);
+
+        // Logistic scale points of 0.1, 0.5 and 0.9
+        let best = [-2.2, 0.0, 2.2].iter()
+            .map(|start| minimize(&sse, &vec![*start; free.len()]))
+            .min_by(|a, b| sse(a).total_cmp(&sse(b)))?;
+
+        Smoothing::new(model, values, period, &parameters(&best))
+    }
+
+    /// Alpha, then beta and gamma when the model has a trend and a season.
+    pub fn get_parameters(&self) -> &[f64] {
+        &self.parameters
+    }
+
+    pub fn get_fitted(&self) -> &[Option<f64>] {
+        &self.fitted
+    }
+
+    /// Sum of squared one step ahead errors.
+    pub fn get_sse(&self) -> f64 {
+        self.sse
+    }
+
+    /// Forecasts for the `horizon` steps after the series.
+    pub fn forecast(&self, horizon: usize) -> Vec<f64> {
+        (1..=horizon).map(|step| {
+            let trend = self.level + step as f64 * self.trend;
+
+            match self.model {
+                SmoothingModel::HoltWinters(Seasonality::Additive) => trend + self.season[(self.length + step - 1) % self.period],
+                SmoothingModel::HoltWinters(Seasonality::Multiplicative) => trend * self.season[(self.length + step - 1) % self.period],
+                _ => trend,
+            }
+        }).collect()
+    }
+
+    /// Prediction intervals of the forecasts, from the variance of the one step ahead errors.\
+    /// Exact for additive models with normal errors, an approximation for multiplicative seasons.
+    pub fn get_intervals(&self, horizon: usize, confidence: f64) -> Option<Vec<(f64, f64)>> {
+        let errors = self.fitted.iter().flatten().count();
+        let degrees_of_freedom = errors.checked_sub(self.parameters.len()).filter(|value| *value > 0)?;
+        let variance = self.sse / degrees_of_freedom as f64;
+        let z = normal_quantile((1.0 + confidence) / 2.0);
+
+        if !z.is_finite() || confidence <= 0.0 {
+            return None;
+        }
+
+        let parameter = |index: usize| self.parameters.get(index).copied().unwrap_or(0.0);
+        let (alpha, beta, gamma) = (parameter(0), parameter(1), parameter(2));
+        let mut multiplier = 1.0;
+
+        Some(self.forecast(horizon).into_iter().enumerate().map(|(step, forecast)| {
+            // Error of step h adds the propagated errors c_j of the steps before it
+            if step > 0 {
+                let seasonal = if self.period > 0 && step % self.period.max(1) == 0 { gamma * (1.0 - alpha) } else { 0.0 };
+
+                multiplier += (alpha * (1.0 + beta * step as f64) + seasonal).powi(2);
+            }
+
+            let width = z * (variance * multiplier).sqrt();
+
+            (forecast - width, forecast + width)
+        }).collect())
+    }
+}
+
+/// Fits an exponential smoothing model to `Values` and forecasts `Horizon` steps.\
+/// `Alpha`, `Beta` and `Gamma` are fitted unless they are set to a number in `[0, 1]`, missing values are left out of the series.\
+/// `Parameters` is a record of the smoothing parameters and the sum of squared errors.
+pub struct ExponentialSmoothingNode {
+    model: SmoothingModel,
+}
+
+impl ExponentialSmoothingNode {
+    pub fn new(model: SmoothingModel) -> ExponentialSmoothingNode {
+        ExponentialSmoothingNode {
+            model,
+        }
+    }
+}
+
+impl Node for ExponentialSmoothingNode {
+    fn get_name(&self) -> String {
+        self.model.get_name()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        let mut inputs = vec![
+            Port::new("Values", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+            Port::new("Horizon", 5, ConnectorTypeId::INT),
+            Port::new("Confidence", 0.95, ConnectorTypeId::FLOAT),
+        ];
+
+        if let SmoothingModel::HoltWinters(_) = self.model {
+            inputs.push(Port::new("Period", 12, ConnectorTypeId::INT));
+        }
+
+        for name in ["Alpha", "Beta", "Gamma"].iter().take(self.model.get_parameter_count()) {
+            inputs.push(Port::new(name, Null::new(), ConnectorTypeId::ANY));
+        }
+
+        inputs
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Fitted", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+            Port::new("Forecast", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+            Port::new("Lower", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+            Port::new("Upper", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+            Port::new("Parameters", Record::new(), ConnectorTypeId::RECORD),
+        ]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let values: Vec<f64> = get_series(&context.get_input(0)).into_iter().flatten().collect();
+        let horizon = get_number(&context.get_input(1)).unwrap_or(0.0).clamp(0.0, 10_000.0) as usize;
+        let confidence = get_number(&context.get_input(2)).unwrap_or(f64::NAN);
+        let (period, first_parameter) = match self.model {
+            SmoothingModel::HoltWinters(_) => (get_number(&context.get_input(3)).unwrap_or(0.0).max(0.0) as usize, 4),
+            _ => (0, 3),
+        };
+
+        let fixed: Vec<Option<f64>> = (0..self.model.get_parameter_count())
+            .map(|index| get_number(&context.get_input(first_parameter + index)).filter(|value| (0.0..=1.0).contains(value)))
+            .collect();
+
+        let smoothing = match Smoothing::fit(self.model, &values, period, &fixed) {
+            Some(smoothing) => smoothing,
+            None => return vec![ConnectorType::Null(Null::new()); 5],
+        };
+
+        let numbers = |values: Vec<f64>| ConnectorType::List(values.into_iter().map(|value| from_number(Some(value))).collect());
+        let intervals = smoothing.get_intervals(horizon, confidence).unwrap_or_default();
+        let mut parameters = Record::new();
+
+        for (name, value) in ["Alpha", "Beta", "Gamma"].iter().zip(smoothing.get_parameters()) {
+            parameters.set_field(name, from_number(Some(*value)));
+        }
+
+        parameters.set_field("SSE", from_number(Some(smoothing.get_sse())));
+
+        vec![
+            ConnectorType::List(smoothing.get_fitted().iter().map(|value| from_number(*value)).collect()),
+            numbers(smoothing.forecast(horizon)),
+            numbers(intervals.iter().map(|interval| interval.0).collect()),
+            numbers(intervals.iter().map(|interval| interval.1).collect()),
+            ConnectorType::Record(parameters),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod smoothing_test {
+    use super::*;
+    use crate::nodes::node_manager::Manager;
+
+    fn assert_close(value: f64, expected: f64, tolerance: f64) {
+        assert!((value - expected).abs() < tolerance, "{} should be close to {}", value, expected);
+    }
+
+    #[test]
+    fn test_simple() {
+        let smoothing = Smoothing::new(SmoothingModel::Simple, &[10.0, 12.0, 11.0], 0, &[0.5]).unwrap();
+
+        // Levels 10, 11, 11
+        assert_eq!(smoothing.get_fitted(), [None, Some(10.0), Some(11.0)]);
+        assert_eq!(smoothing.get_sse(), 4.0);
+        assert_eq!(smoothing.forecast(2), vec![11.0, 11.0]);
+
+        // Error variance 4 over two errors less one parameter, the second step adds alpha squared
+        let intervals = smoothing.get_intervals(2, 0.95).unwrap();
+
+        assert_close(intervals[0].1 - 11.0, 1.959_963_984_540_054 * 2.0, 1e-9);
+        assert_close(intervals[1].1 - 11.0, 1.959_963_984_540_054 * (4.0f64 * 1.25).sqrt(), 1e-9);
+        assert_eq!(Smoothing::new(SmoothingModel::Simple, &[1.0], 0, &[1.5]), None);
+    }
+
+    #[test]
+    fn test_holt() {
+        let line: Vec<f64> = (0..10).map(|value| 3.0 + 2.0 * value as f64).collect();
+        let smoothing = Smoothing::fit(SmoothingModel::Holt, &line, 0, &[]).unwrap();
+
+        assert_close(smoothing.get_sse(), 0.0, 1e-9);
+        smoothing.forecast(3).iter().zip([23.0, 25.0, 27.0]).for_each(|(value, expected)| assert_close(*value, expected, 1e-6));
+        assert_eq!(Smoothing::new(SmoothingModel::Holt, &[1.0], 0, &[0.5, 0.5]), None);
+    }
+
+    #[test]
+    fn test_holt_winters() {
+        let season = [5.0, -3.0, 1.0, -3.0];
+        let additive: Vec<f64> = (0..24).map(|time| 50.0 + 0.5 * time as f64 + season[time % 4]).collect();
+        let smoothing = Smoothing::fit(SmoothingModel::HoltWinters(Seasonality::Additive), &additive, 4, &[]).unwrap();
+
+        assert_eq!(smoothing.get_fitted()[..4], [None; 4]);
+        assert!(smoothing.get_sse() < 1e-3, "{} should be close to zero", smoothing.get_sse());
+        smoothing.forecast(4).iter().enumerate().for_each(|(step, value)| assert_close(*value, 50.0 + 0.5 * (24 + step) as f64 + season[step], 1e-2));
+
+        let multiplicative: Vec<f64> = (0..24).map(|time| (20.0 + time as f64) * [1.2, 0.8, 1.1, 0.9][time % 4]).collect();
+        let smoothing = Smoothing::fit(SmoothingModel::HoltWinters(Seasonality::Multiplicative), &multiplicative, 4, &[]).unwrap();
+
+        assert_close(smoothing.forecast(1)[0], 44.0 * 1.2, 0.5);
+        assert_eq!(Smoothing::new(SmoothingModel::HoltWinters(Seasonality::Multiplicative), &[1.0, -1.0, 1.0, 1.0], 2, &[0.5, 0.5, 0.5]), None);
+        assert_eq!(Smoothing::new(SmoothingModel::HoltWinters(Seasonality::Additive), &[1.0, 2.0, 3.0], 2, &[0.5, 0.5, 0.5]), None);
+    }
+
+    #[test]
+    fn test_node() {
+        let mut manager = Manager::new();
+        let node = manager.add_node(ExponentialSmoothingNode::new(SmoothingModel::Simple));
+
+        manager.set_input_value(node, 0, vec![10, 12, 11, 13, 12]);
+        manager.set_input_value(node, 1, 3);
+        manager.set_input_value(node, 3, 0.5);
+
+        let outputs = manager.evaluate(node);
+        let forecast = match &outputs[1] {
+            ConnectorType::List(values) => values.clone(),
+            value => panic!("{:?} should be a list", value),
+        };
+
+        assert_eq!(forecast.len(), 3);
+        assert_eq!(outputs[0].get_variant(ConnectorTypeId::LIST).to_string(), "[Null, 10, 11, 11, 12]");
+        assert_eq!(forecast[0], ConnectorType::new(12.0));
+        assert!(get_number(&outputs[2].get_variant(ConnectorTypeId::LIST).get_variant(ConnectorTypeId::FLOAT)).unwrap() < 12.0);
+
+        match &outputs[4] {
+            ConnectorType::Record(record) => assert_eq!(record.get_field_names(), vec!["Alpha", "SSE"]),
+            value => panic!("{:?} should be a record", value),
+        }
+    }
+}
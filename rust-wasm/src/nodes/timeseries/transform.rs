@@ -0,0 +1,307 @@
+use crate::helper::types::Null;
+use crate::nodes::connector_manager::value::{ConnectorType, ConnectorTypeId};
+use crate::nodes::node_manager::context::Context;
+use crate::nodes::node_manager::node::{Node, Port};
+use crate::nodes::statistics::{from_number, get_number};
+use super::get_series;
+
+/// Direction values are moved in by a `ShiftNode`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Shift {
+    /// Each row gets the value of an earlier row.
+    Lag,
+    /// Each row gets the value of a later row.
+    Lead,
+}
+
+/// Moves values by `periods` rows, later rows for positive periods. Rows shifted in from outside are `Null`.
+pub fn shift(values: &[ConnectorType], periods: i64) -> Vec<ConnectorType> {
+    (0..values.len() as i64).map(|row| match row - periods {
+        source if (0..values.len() as i64).contains(&source) => values[source as usize].clone(),
+        _ => ConnectorType::Null(Null::new()),
+    }).collect()
+}
+
+/// Difference between a value and the value `periods` rows earlier.\
+/// Dates give the difference in seconds. `None` when either value is missing.
+pub fn difference(values: &[ConnectorType], periods: usize) -> Vec<Option<f64>> {
+    (0..values.len()).map(|row| {
+        let earlier = values.get(row.checked_sub(periods)?)?;
+
+        match (&values[row], earlier) {
+            (ConnectorType::DateTime(value), ConnectorType::DateTime(earlier)) => Some((value.get_milliseconds() - earlier.get_milliseconds()) as f64 / 1000.0),
+            (value, earlier) => Some(get_number(value)? - get_number(earlier)?),
+        }
+    }).collect()
+}
+
+/// Relative change from the value `periods` rows earlier, `None` when that value is missing or zero.
+pub fn percent_change(values: &[Option<f64>], periods: usize) -> Vec<Option<f64>> {
+    (0..values.len()).map(|row| {
+        let earlier = (*values.get(row.checked_sub(periods)?)?)?;
+
+        match earlier != 0.0 {
+            true => Some((values[row]? - earlier) / earlier),
+            false => None,
+        }
+    }).collect()
+}
+
+/// Running aggregations of a series.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Cumulative {
+    Sum,
+    Product,
+    Min,
+    Max,
+}
+
+impl Cumulative {
+    /// Missing values stay missing and are left out of the following rows.
+    pub fn apply(&self, values: &[Option<f64>]) -> Vec<Option<f64>> {
+        let mut state: Option<f64> = None;
+
+        values.iter().map(|value| {
+            let value = (*value)?;
+
+            let next = match (self, state) {
+                (_, None) => value,
+                (Cumulative::Sum, Some(state)) => state + value,
+                (Cumulative::Product, Some(state)) => state * value,
+                (Cumulative::Min, Some(state)) => state.min(value),
+                (Cumulative::Max, Some(state)) => state.max(value),
+            };
+
+            state = Some(next);
+            state
+        }).collect()
+    }
+}
+
+fn get_periods(value: &ConnectorType) -> Option<i64> {
+    get_number(value).filter(|value| value.is_finite()).map(|value| value as i64)
+}
+
+/// Moves `Values` by `Periods` rows, filling the rows shifted in from outside with `Null`.
+pub struct ShiftNode {
+    direction: Shift,
+}
+
+impl ShiftNode {
+    pub fn new(direction: Shift) -> ShiftNode {
+        ShiftNode {
+            direction,
+        }
+    }
+}
+
+impl Node for ShiftNode {
+    fn get_name(&self) -> String {
+        format!("{:?}", self.direction)
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Values", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+            Port::new("Periods", 1, ConnectorTypeId::INT),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Result", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let values = match context.get_input(0) {
+            ConnectorType::List(values) => values,
+            _ => return vec![ConnectorType::Null(Null::new())],
+        };
+
+        let periods = match (get_periods(&context.get_input(1)), self.direction) {
+            (Some(periods), Shift::Lag) => periods,
+            (Some(periods), Shift::Lead) => -periods,
+            (None, _) => return vec![ConnectorType::Null(Null::new())],
+        };
+
+        vec![ConnectorType::List(shift(&values, periods))]
+    }
+}
+
+/// Difference of each value of `Values` with the value `Periods` rows earlier.\
+/// Differences of dates are in seconds.
+pub struct DifferenceNode {}
+
+impl DifferenceNode {
+    pub fn new() -> DifferenceNode {
+        DifferenceNode {}
+    }
+}
+
+impl Default for DifferenceNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for DifferenceNode {
+    fn get_name(&self) -> String {
+        "Difference".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Values", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+            Port::new("Periods", 1, ConnectorTypeId::INT),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Result", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let values = match context.get_input(0) {
+            ConnectorType::List(values) => values,
+            _ => return vec![ConnectorType::Null(Null::new())],
+        };
+
+        match get_periods(&context.get_input(1)) {
+            Some(periods) if periods >= 1 => vec![ConnectorType::List(difference(&values, periods as usize).into_iter().map(from_number).collect())],
+            _ => vec![ConnectorType::Null(Null::new())],
+        }
+    }
+}
+
+/// Relative change of each value of `Values` from the value `Periods` rows earlier, `0.1` for an increase of 10%.
+pub struct PercentChangeNode {}
+
+impl PercentChangeNode {
+    pub fn new() -> PercentChangeNode {
+        PercentChangeNode {}
+    }
+}
+
+impl Default for PercentChangeNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Node for PercentChangeNode {
+    fn get_name(&self) -> String {
+        "Percent Change".to_string()
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![
+            Port::new("Values", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST),
+            Port::new("Periods", 1, ConnectorTypeId::INT),
+        ]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Result", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let values = get_series(&context.get_input(0));
+
+        match get_periods(&context.get_input(1)) {
+            Some(periods) if periods >= 1 => vec![ConnectorType::List(percent_change(&values, periods as usize).into_iter().map(from_number).collect())],
+            _ => vec![ConnectorType::Null(Null::new())],
+        }
+    }
+}
+
+/// Running sum, product, minimum or maximum of `Values`.
+pub struct CumulativeNode {
+    cumulative: Cumulative,
+}
+
+impl CumulativeNode {
+    pub fn new(cumulative: Cumulative) -> CumulativeNode {
+        CumulativeNode {
+            cumulative,
+        }
+    }
+}
+
+impl Node for CumulativeNode {
+    fn get_name(&self) -> String {
+        format!("Cumulative {:?}", self.cumulative)
+    }
+
+    fn get_inputs(&self) -> Vec<Port> {
+        vec![Port::new("Values", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST)]
+    }
+
+    fn get_outputs(&self) -> Vec<Port> {
+        vec![Port::new("Result", Vec::<ConnectorType>::new(), ConnectorTypeId::LIST)]
+    }
+
+    fn compute(&self, context: &mut Context) -> Vec<ConnectorType> {
+        let values = get_series(&context.get_input(0));
+
+        vec![ConnectorType::List(self.cumulative.apply(&values).into_iter().map(from_number).collect())]
+    }
+}
+
+#[cfg(test)]
+mod transform_test {
+    use super::*;
+    use crate::helper::testing::null;
+    use crate::nodes::connector_manager::datetime::DateTime;
+    use crate::nodes::node_manager::Manager;
+
+    #[test]
+    fn test_shift() {
+        let values = vec![ConnectorType::new(1), ConnectorType::new("a"), ConnectorType::new(3)];
+
+        assert_eq!(shift(&values, 1), vec![null(), ConnectorType::new(1), ConnectorType::new("a")]);
+        assert_eq!(shift(&values, -2), vec![ConnectorType::new(3), null(), null()]);
+        assert_eq!(shift(&values, 5), vec![null(), null(), null()]);
+    }
+
+    #[test]
+    fn test_difference() {
+        let values = vec![ConnectorType::new(1), ConnectorType::new(4), null(), ConnectorType::new(10.0)];
+        let dates = vec![ConnectorType::new(DateTime::new(0)), ConnectorType::new(DateTime::new(90_000))];
+
+        assert_eq!(difference(&values, 1), vec![None, Some(3.0), None, None]);
+        assert_eq!(difference(&values, 2), vec![None, None, None, Some(6.0)]);
+        assert_eq!(difference(&dates, 1), vec![None, Some(90.0)]);
+        assert_eq!(percent_change(&[Some(2.0), Some(3.0), Some(0.0), Some(1.0)], 1), vec![None, Some(0.5), Some(-1.0), None]);
+    }
+
+    #[test]
+    fn test_cumulative() {
+        let values = [Some(2.0), None, Some(3.0), Some(-1.0)];
+
+        assert_eq!(Cumulative::Sum.apply(&values), vec![Some(2.0), None, Some(5.0), Some(4.0)]);
+        assert_eq!(Cumulative::Product.apply(&values), vec![Some(2.0), None, Some(6.0), Some(-6.0)]);
+        assert_eq!(Cumulative::Min.apply(&values), vec![Some(2.0), None, Some(2.0), Some(-1.0)]);
+        assert_eq!(Cumulative::Max.apply(&values), vec![Some(2.0), None, Some(3.0), Some(3.0)]);
+    }
+
+    #[test]
+    fn test_nodes() {
+        let mut manager = Manager::new();
+        let lead = manager.add_node(ShiftNode::new(Shift::Lead));
+        let difference = manager.add_node(DifferenceNode::new());
+        let change = manager.add_node(PercentChangeNode::new());
+        let sum = manager.add_node(CumulativeNode::new(Cumulative::Sum));
+
+        for node in [lead, difference, change, sum] {
+            manager.set_input_value(node, 0, vec![1, 2, 4]);
+        }
+
+        assert_eq!(manager.evaluate(lead)[0], ConnectorType::new(vec![ConnectorType::new(2), ConnectorType::new(4), null()]));
+        assert_eq!(manager.evaluate(difference)[0], ConnectorType::new(vec![null(), ConnectorType::new(1.0), ConnectorType::new(2.0)]));
+        assert_eq!(manager.evaluate(change)[0], ConnectorType::new(vec![null(), ConnectorType::new(1.0), ConnectorType::new(1.0)]));
+        assert_eq!(manager.evaluate(sum)[0], ConnectorType::new(vec![1.0, 3.0, 7.0]));
+
+        manager.set_input_value(difference, 1, 0);
+
+        assert_eq!(manager.evaluate(difference)[0], ConnectorType::List(Vec::new()));
+    }
+}